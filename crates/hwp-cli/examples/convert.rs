@@ -83,8 +83,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let format_str = matches.get_one::<String>("format").unwrap();
 
     // Parse output format
-    let output_format = OutputFormat::from_str(format_str)
-        .ok_or_else(|| format!("Unknown format: {}", format_str))?;
+    let output_format: OutputFormat = format_str.parse()?;
 
     // Build format options
     let mut options = FormatOptions::default();
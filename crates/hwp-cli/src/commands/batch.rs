@@ -3,6 +3,8 @@ use crate::commands::{ConvertCommand, ExtractCommand, InfoCommand};
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 use colored::*;
+use hwp_core::models::section::SectionPart;
+use hwp_parser::OutputFormat;
 use std::fs;
 use std::path::PathBuf;
 
@@ -40,6 +42,10 @@ pub struct BatchCommand {
     #[arg(long)]
     pub overwrite: bool,
 
+    /// Report which files would be processed or skipped without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
     #[command(subcommand)]
     pub operation: BatchOperation,
 }
@@ -107,8 +113,21 @@ pub enum BatchOperation {
     },
 }
 
+/// One input file and what a batch run would do with it
+#[derive(Debug)]
+pub struct PlannedFile {
+    pub input: PathBuf,
+    pub output: PathBuf,
+    /// Whether the file would be skipped because its output already exists
+    pub skip: bool,
+}
+
 impl BatchCommand {
     pub fn execute(&self) -> Result<()> {
+        if self.dry_run {
+            return self.dry_run();
+        }
+
         // Ensure output directory exists
         if !self.output_dir.exists() {
             fs::create_dir_all(&self.output_dir).context("Failed to create output directory")?;
@@ -122,14 +141,7 @@ impl BatchCommand {
         };
 
         let batch_processor = BatchProcessor::new(self.parallel, error_strategy);
-
-        // Discover files
-        let files = if self.input.contains('*') || self.input.contains('?') {
-            batch_processor.discover_glob(&self.input)?
-        } else {
-            let path = PathBuf::from(&self.input);
-            batch_processor.discover_files(&path, self.recursive)?
-        };
+        let files = self.discover(&batch_processor)?;
 
         if files.is_empty() {
             eprintln!(
@@ -186,6 +198,68 @@ impl BatchCommand {
         Ok(())
     }
 
+    /// Discover the input files matching the input directory or glob pattern
+    fn discover(&self, batch_processor: &BatchProcessor) -> Result<Vec<PathBuf>> {
+        if self.input.contains('*') || self.input.contains('?') {
+            batch_processor.discover_glob(&self.input)
+        } else {
+            let path = PathBuf::from(&self.input);
+            batch_processor.discover_files(&path, self.recursive)
+        }
+    }
+
+    /// Output path for each discovered file and whether it would be skipped
+    pub fn plan(&self) -> Result<Vec<PlannedFile>> {
+        let batch_processor = BatchProcessor::new(self.parallel, ErrorStrategy::Skip);
+        self.discover(&batch_processor)?
+            .into_iter()
+            .map(|input| {
+                let output = self.get_output_path(&input)?;
+                let skip = output.exists() && !self.overwrite;
+                Ok(PlannedFile {
+                    input,
+                    output,
+                    skip,
+                })
+            })
+            .collect()
+    }
+
+    /// Print the plan for this run without creating or writing any files
+    fn dry_run(&self) -> Result<()> {
+        let plan = self.plan()?;
+        if plan.is_empty() {
+            eprintln!(
+                "{}: No HWP files found in '{}'",
+                "Warning".yellow(),
+                self.input
+            );
+            return Ok(());
+        }
+
+        for file in &plan {
+            let action = if file.skip {
+                "skip (exists)".yellow()
+            } else {
+                "write".green()
+            };
+            println!(
+                "{} -> {} [{}]",
+                file.input.display(),
+                file.output.display(),
+                action
+            );
+        }
+
+        let skipped = plan.iter().filter(|f| f.skip).count();
+        println!(
+            "\nDry run: {} files would be processed, {} skipped; nothing was written",
+            plan.len() - skipped,
+            skipped
+        );
+        Ok(())
+    }
+
     fn process_single_file(&self, file: &std::path::Path) -> Result<String> {
         let output_path = self.get_output_path(file)?;
 
@@ -210,13 +284,19 @@ impl BatchCommand {
                     search: None,
                     context: 0,
                     text_width: None,
+                    section_order: SectionPart::DEFAULT_ORDER.to_vec(),
                     markdown_toc: false,
+                    markdown_front_matter: false,
                     json_pretty: false,
                     json_include_styles: false,
+                    csv_delimiter: ',',
                     paragraphs: None,
                     tables_only: false,
                     images_only: false,
                     equations_only: false,
+                    group_by_style: false,
+                    table_markers: false,
+                    lenient: false,
                 };
                 cmd.execute()?;
                 Ok("Extracted".to_string())
@@ -238,8 +318,16 @@ impl BatchCommand {
                     text_width: None,
                     text_page_breaks: false,
                     markdown_flavor: "commonmark".to_string(),
+                    markdown_table_style: "auto".to_string(),
                     markdown_toc: *markdown_toc,
+                    markdown_front_matter: false,
+                    csv_delimiter: ',',
+                    list_required_fonts: false,
+                    html_embed_images: false,
+                    html_css_classes: false,
+                    html_css_prefix: "hwp-".to_string(),
                     overwrite: self.overwrite,
+                    lenient: false,
                 };
                 cmd.execute()?;
                 Ok("Converted".to_string())
@@ -265,6 +353,7 @@ impl BatchCommand {
                     word_frequency: false,
                     paragraph_stats: false,
                     style_analysis: false,
+                    lenient: false,
                 };
                 cmd.execute()?;
                 Ok("Info generated".to_string())
@@ -283,6 +372,9 @@ impl BatchCommand {
                     verify_structure: false,
                     performance: false,
                     verbose: false,
+                    fail_on_warning: false,
+                    report: None,
+                    output: None,
                 };
                 cmd.execute()?;
                 Ok("Validated".to_string())
@@ -299,13 +391,9 @@ impl BatchCommand {
         let extension = match &self.operation {
             BatchOperation::Extract { format, .. }
             | BatchOperation::Convert { format, .. }
-            | BatchOperation::Info { format, .. } => match format.as_str() {
-                "json" => "json",
-                "markdown" | "md" => "md",
-                "html" => "html",
-                "yaml" | "yml" => "yaml",
-                "csv" => "csv",
-                _ => "txt",
+            | BatchOperation::Info { format, .. } => match format.parse::<OutputFormat>() {
+                Ok(format) => format.file_extension(),
+                Err(_) => "txt",
             },
             BatchOperation::Validate { .. } => "validation.json",
         };
@@ -346,3 +434,59 @@ impl BatchCommand {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_run_writes_nothing() {
+        let root = tempfile::tempdir().unwrap();
+        let input_dir = root.path().join("in");
+        let output_dir = root.path().join("out");
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("a.hwp"), b"").unwrap();
+        fs::write(input_dir.join("b.hwp"), b"").unwrap();
+
+        let mut cmd = BatchCommand {
+            input: input_dir.display().to_string(),
+            output_dir: output_dir.clone(),
+            recursive: false,
+            parallel: 1,
+            continue_on_error: false,
+            report: false,
+            report_file: None,
+            overwrite: false,
+            dry_run: true,
+            operation: BatchOperation::Convert {
+                format: "markdown".to_string(),
+                json_pretty: false,
+                markdown_toc: false,
+            },
+        };
+
+        let result = cmd.execute();
+        let created_output_dir = output_dir.exists();
+
+        // An existing output is planned as skipped unless overwriting
+        fs::create_dir_all(&output_dir).unwrap();
+        fs::write(output_dir.join("a.md"), b"existing").unwrap();
+        let plan = cmd.plan().unwrap();
+        cmd.overwrite = true;
+        let overwrite_plan = cmd.plan().unwrap();
+        let existing = fs::read(output_dir.join("a.md")).unwrap();
+        let outputs = fs::read_dir(&output_dir).unwrap().count();
+
+        assert!(result.is_ok());
+        assert!(!created_output_dir);
+        let mut planned: Vec<_> = plan
+            .iter()
+            .map(|f| (f.output.file_name().unwrap().to_owned(), f.skip))
+            .collect();
+        planned.sort();
+        assert_eq!(planned, vec![("a.md".into(), true), ("b.md".into(), false)]);
+        assert!(overwrite_plan.iter().all(|f| !f.skip));
+        assert_eq!(existing, b"existing");
+        assert_eq!(outputs, 1);
+    }
+}
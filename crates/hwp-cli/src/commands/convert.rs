@@ -1,7 +1,10 @@
+use super::parse_output_format;
 use anyhow::Result;
 use clap::Args;
 use glob::glob;
-use hwp_parser::{parse, FormatOptions, MarkdownFlavor, OutputFormat};
+use hwp_parser::{
+    parse_with_options, FormatOptions, MarkdownFlavor, MarkdownTableStyle, ParseOptions,
+};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -11,7 +14,7 @@ pub struct ConvertCommand {
     /// Input HWP file path or pattern (supports wildcards)
     pub input: String,
 
-    /// Output format (text, json, markdown)
+    /// Output format (text, json, markdown, html, yaml, rtf, csv, docx)
     #[arg(short = 't', long = "to", default_value = "text")]
     pub format: String,
 
@@ -48,13 +51,48 @@ pub struct ConvertCommand {
     #[arg(long, default_value = "commonmark")]
     pub markdown_flavor: String,
 
+    /// Markdown table style (auto, pipe, html); auto picks pipe tables for
+    /// flavors that have them
+    #[arg(long, default_value = "auto")]
+    pub markdown_table_style: String,
+
     /// Generate table of contents for Markdown
     #[arg(long)]
     pub markdown_toc: bool,
 
+    /// Prepend YAML front matter to Markdown output
+    #[arg(long)]
+    pub markdown_front_matter: bool,
+
+    /// List the fonts the document needs in HTML output
+    #[arg(long)]
+    pub list_required_fonts: bool,
+
+    /// Embed pictures in HTML output as base64 data URIs
+    #[arg(long)]
+    pub html_embed_images: bool,
+
+    /// Style HTML text with CSS classes in a `<style>` block instead of
+    /// inline `style` attributes
+    #[arg(long)]
+    pub html_css_classes: bool,
+
+    /// Prefix of the generated HTML CSS classes
+    #[arg(long, default_value = "hwp-")]
+    pub html_css_prefix: String,
+
+    /// Field delimiter for CSV output
+    #[arg(long, default_value = ",")]
+    pub csv_delimiter: char,
+
     /// Overwrite existing files
     #[arg(long)]
     pub overwrite: bool,
+
+    /// If a stream fails to decompress by every method, use its raw bytes
+    /// instead of failing
+    #[arg(long)]
+    pub lenient: bool,
 }
 
 impl ConvertCommand {
@@ -148,7 +186,11 @@ impl ConvertCommand {
 
         // Read and parse the HWP file
         let hwp_data = fs::read(input_path)?;
-        let document = parse(&hwp_data)?;
+        let parse_options = ParseOptions {
+            lenient: self.lenient,
+            ..ParseOptions::default()
+        };
+        let document = parse_with_options(&hwp_data, &parse_options)?;
 
         // Build format options
         let mut options = FormatOptions::default();
@@ -157,25 +199,32 @@ impl ConvertCommand {
         options.text_width = self.text_width;
         options.text_page_breaks = self.text_page_breaks;
         options.markdown_toc = self.markdown_toc;
+        options.markdown_front_matter = self.markdown_front_matter;
+        options.list_required_fonts = self.list_required_fonts;
+        options.html_embed_images = self.html_embed_images;
+        options.html_css_inline = !self.html_css_classes;
+        options.html_css_prefix = self.html_css_prefix.clone();
+        options.csv_delimiter = self.csv_delimiter;
+        options.source_name = input_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned());
         options.markdown_flavor = match self.markdown_flavor.to_lowercase().as_str() {
             "gfm" | "github" => MarkdownFlavor::GitHubFlavored,
             "multimarkdown" | "mmd" => MarkdownFlavor::MultiMarkdown,
             _ => MarkdownFlavor::CommonMark,
         };
+        options.markdown_table_style = match self.markdown_table_style.to_lowercase().as_str() {
+            "pipe" => MarkdownTableStyle::Pipe,
+            "html" => MarkdownTableStyle::Html,
+            _ => MarkdownTableStyle::Auto,
+        };
 
         // Get the output format
-        let format = match self.format.to_lowercase().as_str() {
-            "text" | "txt" => OutputFormat::PlainText,
-            "json" => OutputFormat::Json,
-            "markdown" | "md" => OutputFormat::Markdown,
-            _ => {
-                return Err(anyhow::anyhow!("Unsupported format: {}", self.format));
-            }
-        };
+        let format = parse_output_format(&self.format)?;
 
         // Convert the document
         let formatter = format.create_formatter(options);
-        let output = formatter.format_document(&document)?;
+        let output = formatter.format_bytes(&document)?;
 
         // Write output
         if let Some(out_path) = output_path {
@@ -185,10 +234,10 @@ impl ConvertCommand {
             }
 
             let mut file = fs::File::create(out_path)?;
-            file.write_all(output.as_bytes())?;
+            file.write_all(&output)?;
             eprintln!("  -> {}", out_path.display());
         } else {
-            print!("{}", output);
+            std::io::stdout().write_all(&output)?;
         }
 
         Ok(())
@@ -196,12 +245,7 @@ impl ConvertCommand {
 
     fn get_output_path(&self, input_path: &Path) -> Result<PathBuf> {
         // Determine the output extension
-        let extension = match self.format.to_lowercase().as_str() {
-            "text" | "txt" => "txt",
-            "json" => "json",
-            "markdown" | "md" => "md",
-            _ => "txt",
-        };
+        let extension = parse_output_format(&self.format)?.file_extension();
 
         if let Some(output_dir) = &self.output_dir {
             // Use specified output directory
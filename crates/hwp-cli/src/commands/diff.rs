@@ -0,0 +1,141 @@
+use anyhow::{Context, Result};
+use clap::Args;
+use colored::*;
+use hwp_core::diff::{ChangeKind, DocumentDiff};
+use hwp_core::HwpDocument;
+use hwp_parser::parse;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Diff command arguments
+#[derive(Args, Debug)]
+pub struct DiffCommand {
+    /// Original HWP file
+    pub old: PathBuf,
+
+    /// Changed HWP file
+    pub new: PathBuf,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub format: String,
+}
+
+impl DiffCommand {
+    pub fn execute(&self) -> Result<()> {
+        let old = self.load(&self.old)?;
+        let new = self.load(&self.new)?;
+        let diff = old.diff(&new);
+
+        match self.format.as_str() {
+            "json" => {
+                let lists = serde_json::json!({
+                    "added": diff.hunks_of(ChangeKind::Added),
+                    "removed": diff.hunks_of(ChangeKind::Removed),
+                    "changed": diff.hunks_of(ChangeKind::Modified),
+                });
+                println!("{}", serde_json::to_string_pretty(&lists)?);
+            }
+            _ => print!("{}", self.render(&diff)),
+        }
+
+        Ok(())
+    }
+
+    fn load(&self, path: &Path) -> Result<HwpDocument> {
+        let data = fs::read(path)?;
+        parse(&data).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Render the diff as unified-diff-style text, one hunk per change
+    fn render(&self, diff: &DocumentDiff) -> String {
+        let mut out = format!(
+            "{}\n{}\n",
+            format!("--- {}", self.old.display()).bold(),
+            format!("+++ {}", self.new.display()).bold()
+        );
+
+        for hunk in &diff.hunks {
+            // "-section:paragraph" in the old document, "+..." in the new;
+            // a side the paragraph is missing from is left out
+            let mut positions = Vec::new();
+            if let (Some(section), Some(paragraph)) = (hunk.old_section, hunk.old_paragraph) {
+                positions.push(format!("-{}:{}", section, paragraph));
+            }
+            if let (Some(section), Some(paragraph)) = (hunk.new_section, hunk.new_paragraph) {
+                positions.push(format!("+{}:{}", section, paragraph));
+            }
+            out.push_str(&format!(
+                "{}\n",
+                format!("@@ {} @@", positions.join(" ")).cyan()
+            ));
+            if let Some(text) = &hunk.old_text {
+                for line in text.lines() {
+                    out.push_str(&format!("{}\n", format!("-{}", line).red()));
+                }
+            }
+            if let Some(text) = &hunk.new_text {
+                for line in text.lines() {
+                    out.push_str(&format!("{}\n", format!("+{}", line).green()));
+                }
+            }
+        }
+
+        out.push_str(&format!(
+            "{} added, {} removed, {} modified\n",
+            diff.count(ChangeKind::Added),
+            diff.count(ChangeKind::Removed),
+            diff.count(ChangeKind::Modified)
+        ));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_core::diff::DiffHunk;
+
+    #[test]
+    fn test_render_unified_style() {
+        let command = DiffCommand {
+            old: "a.hwp".into(),
+            new: "b.hwp".into(),
+            format: "text".to_string(),
+        };
+        let diff = DocumentDiff {
+            hunks: vec![
+                DiffHunk {
+                    kind: ChangeKind::Modified,
+                    old_section: Some(0),
+                    new_section: Some(0),
+                    old_paragraph: Some(1),
+                    new_paragraph: Some(1),
+                    old_text: Some("before".to_string()),
+                    new_text: Some("after".to_string()),
+                },
+                DiffHunk {
+                    kind: ChangeKind::Added,
+                    old_section: None,
+                    new_section: Some(1),
+                    old_paragraph: None,
+                    new_paragraph: Some(2),
+                    old_text: None,
+                    new_text: Some("new".to_string()),
+                },
+            ],
+        };
+
+        colored::control::set_override(false);
+        let output = command.render(&diff);
+        colored::control::unset_override();
+
+        assert_eq!(
+            output,
+            "--- a.hwp\n+++ b.hwp\n\
+             @@ -0:1 +0:1 @@\n-before\n+after\n\
+             @@ +1:2 @@\n+new\n\
+             1 added, 0 removed, 1 modified\n"
+        );
+    }
+}
@@ -1,7 +1,9 @@
+use super::parse_output_format;
 use anyhow::Result;
 use clap::Args;
-use hwp_core::HwpDocument;
-use hwp_parser::{parse, FormatOptions, OutputFormat};
+use hwp_core::models::section::{SectionPart, Table};
+use hwp_core::{HwpDocument, ParagraphRange};
+use hwp_parser::{parse_with_options, FormatOptions, OutputFormat, ParseOptions};
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
@@ -11,7 +13,7 @@ pub struct ExtractCommand {
     /// Input HWP file path
     pub input: PathBuf,
 
-    /// Output format (text, markdown, json, html)
+    /// Output format (text, markdown, json, html, yaml, rtf, csv, docx)
     #[arg(short, long, default_value = "text")]
     pub format: String,
 
@@ -39,7 +41,7 @@ pub struct ExtractCommand {
     #[arg(long)]
     pub tables_only: bool,
 
-    /// Extract images only
+    /// Extract images only, writing them into the `--output` directory
     #[arg(long)]
     pub images_only: bool,
 
@@ -47,6 +49,15 @@ pub struct ExtractCommand {
     #[arg(long)]
     pub equations_only: bool,
 
+    /// Group paragraphs under their style names (e.g. for building outlines)
+    #[arg(long)]
+    pub group_by_style: bool,
+
+    /// Delimit tables inline with ASCII separators: 0x1E around each
+    /// table, 0x1D between rows and 0x1F between cells
+    #[arg(long)]
+    pub table_markers: bool,
+
     /// Search and extract matching content
     #[arg(long)]
     pub search: Option<String>,
@@ -59,10 +70,18 @@ pub struct ExtractCommand {
     #[arg(long)]
     pub text_width: Option<usize>,
 
+    /// Parts of each section in plain text, in order (body, notes, headers)
+    #[arg(long, value_delimiter = ',', default_value = "body,notes")]
+    pub section_order: Vec<SectionPart>,
+
     /// Generate table of contents for Markdown
     #[arg(long)]
     pub markdown_toc: bool,
 
+    /// Prepend YAML front matter to Markdown output
+    #[arg(long)]
+    pub markdown_front_matter: bool,
+
     /// Pretty print JSON output
     #[arg(long)]
     pub json_pretty: bool,
@@ -70,114 +89,101 @@ pub struct ExtractCommand {
     /// Include styles in JSON output
     #[arg(long)]
     pub json_include_styles: bool,
+
+    /// Field delimiter for CSV output
+    #[arg(long, default_value = ",")]
+    pub csv_delimiter: char,
+
+    /// If a stream fails to decompress by every method, use its raw bytes
+    /// instead of failing
+    #[arg(long)]
+    pub lenient: bool,
 }
 
 impl ExtractCommand {
     pub fn execute(&self) -> Result<()> {
+        // Reject unknown formats before doing any work
+        let format = parse_output_format(&self.format)?;
+
         // Read and parse the HWP file
         let hwp_data = fs::read(&self.input)?;
-        let document = parse(&hwp_data)?;
+        let options = ParseOptions {
+            lenient: self.lenient,
+            ..ParseOptions::default()
+        };
+        let document = parse_with_options(&hwp_data, &options)?;
+
+        // Images are written as files rather than formatted output
+        if self.images_only {
+            return self.extract_images(&document);
+        }
 
         // Build format options
         let mut options = FormatOptions::default();
         options.text_width = self.text_width;
+        options.text_section_order = self.section_order.clone();
         options.markdown_toc = self.markdown_toc;
+        options.markdown_front_matter = self.markdown_front_matter;
+        options.source_name = self
+            .input
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned());
         options.json_pretty = self.json_pretty;
         options.json_include_styles = self.json_include_styles;
         options.include_metadata = self.include_metadata;
         options.include_styles = self.json_include_styles;
+        options.csv_delimiter = self.csv_delimiter;
 
         // Extract content based on format
-        let output = if self.format == "text" || self.format == "txt" {
+        let output = if format == OutputFormat::PlainText {
             // Handle special extraction modes
             if self.tables_only {
-                self.extract_tables(&document)?
-            } else if self.images_only {
-                self.extract_images(&document)?
+                self.extract_tables(&document)?.into_bytes()
             } else if self.equations_only {
-                self.extract_equations(&document)?
+                self.extract_equations(&document)?.into_bytes()
+            } else if self.group_by_style {
+                self.extract_by_style(&document).into_bytes()
+            } else if self.table_markers {
+                document.marked_text().into_bytes()
             } else if let Some(paragraphs_str) = &self.paragraphs {
                 self.extract_paragraphs(&document, paragraphs_str)?
+                    .into_bytes()
             } else if let Some(sections_str) = &self.sections {
-                self.extract_sections(&document, sections_str)?
+                self.extract_sections(&document, sections_str)?.into_bytes()
             } else if let Some(search_query) = &self.search {
                 self.search_and_extract(&document, search_query)?
+                    .into_bytes()
             } else {
                 // Use the formatter
                 let formatter = OutputFormat::PlainText.create_formatter(options);
-                formatter.format_document(&document)?
+                formatter.format_bytes(&document)?
             }
         } else {
             // Use the appropriate formatter
-            let format = match self.format.as_str() {
-                "json" => OutputFormat::Json,
-                "markdown" | "md" => OutputFormat::Markdown,
-                "html" | "htm" => OutputFormat::Html,
-                "yaml" | "yml" => OutputFormat::Yaml,
-                _ => {
-                    return Err(anyhow::anyhow!("Unsupported format: {}", self.format));
-                }
-            };
-
             let formatter = format.create_formatter(options);
-            formatter.format_document(&document)?
+            formatter.format_bytes(&document)?
         };
 
         // Write output
         if let Some(output_path) = &self.output {
             let mut file = fs::File::create(output_path)?;
-            file.write_all(output.as_bytes())?;
+            file.write_all(&output)?;
             eprintln!("Extracted content written to: {}", output_path.display());
         } else {
-            print!("{}", output);
+            std::io::stdout().write_all(&output)?;
         }
 
         Ok(())
     }
 
-    fn parse_range(&self, range_str: &str) -> Result<(Option<usize>, Option<usize>)> {
-        if range_str.contains('-') {
-            let parts: Vec<&str> = range_str.split('-').collect();
-            if parts.len() != 2 {
-                return Err(anyhow::anyhow!("Invalid range format"));
-            }
-            let start = if parts[0].is_empty() {
-                None
-            } else {
-                Some(parts[0].parse()?)
-            };
-            let end = if parts[1].is_empty() {
-                None
-            } else {
-                Some(parts[1].parse()?)
-            };
-            Ok((start, end))
-        } else {
-            let num = range_str.parse()?;
-            Ok((Some(num), Some(num)))
-        }
-    }
-
     fn extract_paragraphs(&self, document: &HwpDocument, range_str: &str) -> Result<String> {
-        let mut result = String::new();
-        let (start, end) = self.parse_range(range_str)?;
-        let start = start.unwrap_or(0);
+        let range: ParagraphRange = range_str.parse()?;
 
-        let mut para_count = 0;
-        for (section_idx, section) in document.sections.iter().enumerate() {
-            for paragraph in &section.paragraphs {
-                if para_count >= start {
-                    if let Some(end) = end {
-                        if para_count > end {
-                            return Ok(result);
-                        }
-                    }
-                    if !paragraph.text.is_empty() {
-                        result.push_str(&format!("[P{}] {}", para_count, &paragraph.text));
-                        result.push('\n');
-                    }
-                }
-                para_count += 1;
+        let mut result = String::new();
+        for (index, paragraph) in document.paragraphs_in_range(range) {
+            if !paragraph.text.is_empty() {
+                result.push_str(&format!("[P{}] {}", index, &paragraph.text));
+                result.push('\n');
             }
         }
 
@@ -211,6 +217,21 @@ impl ExtractCommand {
         Ok(result)
     }
 
+    fn extract_by_style(&self, document: &HwpDocument) -> String {
+        let mut result = String::new();
+
+        for (style, paragraphs) in document.paragraphs_by_style() {
+            result.push_str(&format!("=== {} ===\n", style));
+            for paragraph in paragraphs {
+                result.push_str(&paragraph.text);
+                result.push('\n');
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+
     fn search_and_extract(&self, document: &HwpDocument, query: &str) -> Result<String> {
         let mut result = String::new();
         let context = self.context;
@@ -269,31 +290,101 @@ impl ExtractCommand {
         let mut result = String::new();
         result.push_str("=== Tables Extraction ===\n\n");
 
-        // TODO: Implement actual table extraction when table parsing is available
-        result.push_str("Table extraction will be available once table parsing is implemented.\n");
+        let mut count = 0;
+        for (section_idx, section) in document.sections.iter().enumerate() {
+            for table in &section.tables {
+                count += 1;
+                let label = format!(
+                    "Table {} (section {}, paragraph {})",
+                    count, section_idx, table.paragraph_index
+                );
+                write_table_tsv(&mut result, table, &label);
+            }
+        }
+
+        if count == 0 {
+            result.push_str("No tables found.\n");
+        }
 
         Ok(result)
     }
 
-    fn extract_images(&self, document: &HwpDocument) -> Result<String> {
-        let mut result = String::new();
-        result.push_str("=== Images Extraction ===\n\n");
+    /// Write each embedded image to `image_NNN.<ext>` in the output directory
+    /// (the current directory if `--output` is not given)
+    ///
+    /// Linked images are listed with their paths, since the document holds
+    /// no data to write for them.
+    fn extract_images(&self, document: &HwpDocument) -> Result<()> {
+        let output_dir = self.output.clone().unwrap_or_else(|| PathBuf::from("."));
+        let images = hwp_parser::bin_data::extract_images(document);
+
+        for entry in &document.doc_info.bin_data_entries {
+            if let Some(path) = hwp_parser::bin_data::linked_path(entry) {
+                println!("Linked image {}: {} (not embedded)", entry.id, path);
+            }
+        }
 
-        // TODO: Implement actual image extraction when image handling is available
-        result.push_str("Image extraction will be available once image handling is implemented.\n");
+        if images.is_empty() {
+            println!("No images found.");
+            return Ok(());
+        }
 
-        Ok(result)
+        fs::create_dir_all(&output_dir)?;
+        for (index, image) in images.iter().enumerate() {
+            let path = output_dir.join(format!("image_{:03}.{}", index + 1, image.extension));
+            fs::write(&path, &image.data)?;
+            println!("{} ({} bytes)", path.display(), image.data.len());
+        }
+        eprintln!(
+            "Extracted {} images to: {}",
+            images.len(),
+            output_dir.display()
+        );
+
+        Ok(())
     }
 
     fn extract_equations(&self, document: &HwpDocument) -> Result<String> {
         let mut result = String::new();
         result.push_str("=== Equations Extraction ===\n\n");
 
-        // TODO: Implement actual equation extraction when equation parsing is available
-        result.push_str(
-            "Equation extraction will be available once equation parsing is implemented.\n",
-        );
+        let mut count = 0;
+        for (section_idx, section) in document.sections.iter().enumerate() {
+            for equation in &section.equations {
+                count += 1;
+                result.push_str(&format!(
+                    "--- Equation {} (section {}, paragraph {}) ---\n",
+                    count, section_idx, equation.paragraph_index
+                ));
+                result.push_str(&format!("Script: {}\n", equation.text_representation));
+                result.push_str(&format!("Text:   {}\n\n", equation.plain_text()));
+            }
+        }
+
+        if count == 0 {
+            result.push_str("No equations found.\n");
+        }
 
         Ok(result)
     }
 }
+
+/// Append a table as tab-separated rows, followed by any tables nested in its cells
+fn write_table_tsv(result: &mut String, table: &Table, label: &str) {
+    result.push_str(&format!("--- {} ---\n", label));
+    result.push_str(&table.to_tsv());
+    result.push('\n');
+
+    for cell in &table.cells {
+        for (idx, nested) in cell.tables.iter().enumerate() {
+            let nested_label = format!(
+                "{} > cell ({}, {}) table {}",
+                label,
+                cell.row,
+                cell.col,
+                idx + 1
+            );
+            write_table_tsv(result, nested, &nested_label);
+        }
+    }
+}
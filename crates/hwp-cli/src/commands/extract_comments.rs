@@ -0,0 +1,47 @@
+use anyhow::Result;
+use clap::Args;
+use hwp_parser::annotations::collect_annotations;
+use hwp_parser::parse;
+use serde_json::json;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub struct ExtractCommentsCommand {
+    /// Input HWP file path
+    pub input: PathBuf,
+
+    /// Output JSON file path (stdout if not specified)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl ExtractCommentsCommand {
+    pub fn execute(&self) -> Result<()> {
+        let hwp_data = fs::read(&self.input)?;
+        let document = parse(&hwp_data)?;
+
+        let annotations = collect_annotations(&document);
+        let result = json!({
+            "file": self.input.display().to_string(),
+            "total": annotations.len(),
+            "annotations": annotations,
+        });
+        let output = serde_json::to_string_pretty(&result)?;
+
+        if let Some(output_path) = &self.output {
+            let mut file = fs::File::create(output_path)?;
+            file.write_all(output.as_bytes())?;
+            eprintln!(
+                "{} comments written to: {}",
+                annotations.len(),
+                output_path.display()
+            );
+        } else {
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use clap::Args;
+use hwp_core::models::section::{PageDef, PageOrientation};
 use hwp_core::HwpDocument;
-use hwp_parser::parse;
+use hwp_parser::integrity::{check_integrity, IntegrityReport, IntegrityStatus};
+use hwp_parser::{parse_with_options, ParseOptions};
 use serde_json::json;
 use std::fs;
 use std::io::Write;
@@ -59,6 +61,11 @@ pub struct InfoCommand {
     /// Show style usage analysis
     #[arg(long)]
     pub style_analysis: bool,
+
+    /// If a stream fails to decompress by every method, use its raw bytes
+    /// instead of failing
+    #[arg(long)]
+    pub lenient: bool,
 }
 
 impl InfoCommand {
@@ -66,12 +73,13 @@ impl InfoCommand {
         // Read and parse the HWP file
         let hwp_data = fs::read(&self.input)?;
         let file_size = hwp_data.len();
-        let document = parse(&hwp_data)?;
+        let document = parse_with_options(&hwp_data, &self.parse_options())?;
+        let integrity = self.check_integrity.then(|| check_integrity(&hwp_data));
 
         // Generate info based on format
         let output = match self.format.as_str() {
-            "json" => self.generate_json_info(&document, file_size)?,
-            _ => self.generate_text_info(&document, file_size)?,
+            "json" => self.generate_json_info(&document, file_size, integrity.as_deref())?,
+            _ => self.generate_text_info(&document, file_size, integrity.as_deref())?,
         };
 
         // Write output
@@ -86,7 +94,29 @@ impl InfoCommand {
         Ok(())
     }
 
-    fn generate_text_info(&self, document: &HwpDocument, file_size: usize) -> Result<String> {
+    /// Parse options for the requested report
+    ///
+    /// Body sections are only parsed when a flag needs their contents;
+    /// header, DocInfo, font and style reports skip them.
+    fn parse_options(&self) -> ParseOptions {
+        let needs_body = self.verbose
+            || self.stats
+            || self.analyze_complexity
+            || self.word_frequency
+            || self.paragraph_stats;
+        ParseOptions {
+            skip_body: !needs_body,
+            lenient: self.lenient,
+            ..ParseOptions::default()
+        }
+    }
+
+    fn generate_text_info(
+        &self,
+        document: &HwpDocument,
+        file_size: usize,
+        integrity: Option<&[IntegrityReport]>,
+    ) -> Result<String> {
         let mut info = String::new();
 
         info.push_str(&format!("=== HWP File Information ===\n"));
@@ -131,6 +161,25 @@ impl InfoCommand {
         ));
         info.push_str(&format!("\n"));
 
+        // Summary properties, when the file carries them
+        if let Some(summary) = &document.summary {
+            info.push_str("=== Summary ===\n");
+            let fields = [
+                ("Title", &summary.title),
+                ("Author", &summary.author),
+                ("Subject", &summary.subject),
+                ("Keywords", &summary.keywords),
+                ("Created", &summary.created),
+                ("Modified", &summary.modified),
+            ];
+            for (label, value) in fields {
+                if let Some(value) = value {
+                    info.push_str(&format!("{}: {}\n", label, value));
+                }
+            }
+            info.push('\n');
+        }
+
         // Document properties
         info.push_str(&format!("=== Document Properties ===\n"));
         info.push_str(&format!(
@@ -184,14 +233,13 @@ impl InfoCommand {
         if self.verbose {
             for (idx, section) in document.sections.iter().enumerate() {
                 let paragraph_count = section.paragraphs.len();
-                let total_chars: usize = section
-                    .paragraphs
-                    .iter()
-                    .map(|p| p.text.chars().count())
-                    .sum();
+                let total_chars: usize = section.paragraphs.iter().map(|p| p.char_len()).sum();
                 info.push_str(&format!(
-                    "  Section {}: {} paragraphs, {} characters\n",
-                    idx, paragraph_count, total_chars
+                    "  Section {}: {} paragraphs, {} characters, {}\n",
+                    idx,
+                    paragraph_count,
+                    total_chars,
+                    paper_size(&section.page_def())
                 ));
             }
         }
@@ -204,26 +252,27 @@ impl InfoCommand {
             let total_paragraphs: usize =
                 document.sections.iter().map(|s| s.paragraphs.len()).sum();
             let total_text_length = document.get_text().len();
-            let total_chars = document.get_text().chars().count();
-
-            // Count Korean characters
-            let text = document.get_text();
-            let korean_chars = text
-                .chars()
-                .filter(|c| {
-                    (*c >= '\u{AC00}' && *c <= '\u{D7AF}')
-                        || (*c >= '\u{1100}' && *c <= '\u{11FF}')
-                        || (*c >= '\u{3130}' && *c <= '\u{318F}')
-                })
-                .count();
+            let total_chars = document.char_count();
+            let korean_chars = document.char_count_hangul();
 
             info.push_str(&format!("Total paragraphs: {}\n", total_paragraphs));
             info.push_str(&format!("Total text length: {} bytes\n", total_text_length));
             info.push_str(&format!("Total characters: {}\n", total_chars));
+            info.push_str(&format!("Total words: {}\n", document.word_count()));
             info.push_str(&format!(
                 "Korean characters: {} ({:.1}%)\n",
                 korean_chars,
-                (korean_chars as f64 / total_chars as f64) * 100.0
+                percentage(korean_chars, total_chars)
+            ));
+            info.push_str(&format!(
+                "Latin letters: {} ({:.1}%)\n",
+                document.char_count_latin(),
+                percentage(document.char_count_latin(), total_chars)
+            ));
+            info.push_str(&format!(
+                "CJK characters: {} ({:.1}%)\n",
+                document.char_count_cjk(),
+                percentage(document.char_count_cjk(), total_chars)
             ));
 
             if total_paragraphs > 0 {
@@ -234,6 +283,10 @@ impl InfoCommand {
             }
         }
 
+        if let Some(integrity) = integrity {
+            info.push_str(&format_integrity(integrity));
+        }
+
         // Analyze document complexity if requested
         if self.analyze_complexity {
             info.push_str(&self.analyze_document_complexity(document));
@@ -257,7 +310,12 @@ impl InfoCommand {
         Ok(info)
     }
 
-    fn generate_json_info(&self, document: &HwpDocument, file_size: usize) -> Result<String> {
+    fn generate_json_info(
+        &self,
+        document: &HwpDocument,
+        file_size: usize,
+        integrity: Option<&[IntegrityReport]>,
+    ) -> Result<String> {
         let mut info = json!({
             "file": {
                 "path": self.input.display().to_string(),
@@ -288,6 +346,10 @@ impl InfoCommand {
             }
         });
 
+        if let Some(summary) = &document.summary {
+            info["summary"] = json!(summary);
+        }
+
         // Add fonts if requested
         if self.fonts || self.verbose {
             let fonts: Vec<_> = document
@@ -312,15 +374,12 @@ impl InfoCommand {
                 .iter()
                 .enumerate()
                 .map(|(idx, section)| {
-                    let total_chars: usize = section
-                        .paragraphs
-                        .iter()
-                        .map(|p| p.text.chars().count())
-                        .sum();
+                    let total_chars: usize = section.paragraphs.iter().map(|p| p.char_len()).sum();
                     json!({
                         "index": idx,
                         "paragraphs": section.paragraphs.len(),
                         "characters": total_chars,
+                        "page": section.page_def(),
                     })
                 })
                 .collect();
@@ -331,23 +390,18 @@ impl InfoCommand {
         if self.stats || self.verbose {
             let total_paragraphs: usize =
                 document.sections.iter().map(|s| s.paragraphs.len()).sum();
-            let text = document.get_text();
-            let total_chars = text.chars().count();
-            let korean_chars = text
-                .chars()
-                .filter(|c| {
-                    (*c >= '\u{AC00}' && *c <= '\u{D7AF}')
-                        || (*c >= '\u{1100}' && *c <= '\u{11FF}')
-                        || (*c >= '\u{3130}' && *c <= '\u{318F}')
-                })
-                .count();
+            let total_chars = document.char_count();
+            let korean_chars = document.char_count_hangul();
 
             info["statistics"] = json!({
                 "total_paragraphs": total_paragraphs,
-                "total_text_bytes": text.len(),
+                "total_text_bytes": document.get_text().len(),
                 "total_characters": total_chars,
+                "total_words": document.word_count(),
                 "korean_characters": korean_chars,
-                "korean_percentage": format!("{:.1}", (korean_chars as f64 / total_chars as f64) * 100.0),
+                "korean_percentage": format!("{:.1}", percentage(korean_chars, total_chars)),
+                "latin_characters": document.char_count_latin(),
+                "cjk_characters": document.char_count_cjk(),
                 "average_paragraph_length": if total_paragraphs > 0 {
                     format!("{:.1}", total_chars as f64 / total_paragraphs as f64)
                 } else {
@@ -356,6 +410,10 @@ impl InfoCommand {
             });
         }
 
+        if let Some(integrity) = integrity {
+            info["integrity"] = json!(integrity);
+        }
+
         if self.verbose {
             Ok(serde_json::to_string_pretty(&info)?)
         } else {
@@ -373,7 +431,7 @@ impl InfoCommand {
             .sections
             .iter()
             .flat_map(|s| &s.paragraphs)
-            .map(|p| p.text.len())
+            .map(|p| p.char_len())
             .sum();
         let avg_para_length = if total_paragraphs > 0 {
             total_chars / total_paragraphs
@@ -450,7 +508,7 @@ impl InfoCommand {
                 if paragraph.text.is_empty() {
                     empty_count += 1;
                 } else {
-                    lengths.push(paragraph.text.len());
+                    lengths.push(paragraph.char_len());
                 }
             }
         }
@@ -518,3 +576,103 @@ impl InfoCommand {
         info
     }
 }
+
+/// `part` as a percentage of `total`, or 0 for an empty document
+fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64 * 100.0
+    }
+}
+
+/// Paper size in millimetres with its orientation, named when it is a
+/// standard size, e.g. `A4 (210×297mm) portrait`
+fn paper_size(page: &PageDef) -> String {
+    let orientation = match page.orientation {
+        PageOrientation::Portrait => "portrait",
+        PageOrientation::Landscape => "landscape",
+    };
+    let size = format!("{:.0}×{:.0}mm", page.width.to_mm(), page.height.to_mm());
+    match page.paper_name() {
+        Some(name) => format!("{} ({}) {}", name, size, orientation),
+        None => format!("{} {}", size, orientation),
+    }
+}
+
+/// Integrity section: one line per stream, with the issue for failures
+fn format_integrity(integrity: &[IntegrityReport]) -> String {
+    let mut info = String::from("=== Integrity ===\n");
+    for entry in integrity {
+        let status = match entry.status {
+            IntegrityStatus::Ok => "ok",
+            IntegrityStatus::Corrupt => "corrupt",
+            IntegrityStatus::Missing => "missing",
+        };
+        match &entry.issue {
+            Some(issue) => {
+                info.push_str(&format!("{}: {} ({})\n", entry.stream_name, status, issue))
+            }
+            None => info.push_str(&format!("{}: {}\n", entry.stream_name, status)),
+        }
+    }
+    info.push('\n');
+    info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use hwp_core::models::document::HwpUnit;
+
+    #[derive(Parser)]
+    struct InfoCli {
+        #[command(flatten)]
+        info: InfoCommand,
+    }
+
+    fn info(args: &[&str]) -> InfoCommand {
+        InfoCli::parse_from(["hwp", "doc.hwp"].iter().chain(args)).info
+    }
+
+    #[test]
+    fn test_fonts_alone_skips_body() {
+        assert!(info(&[]).parse_options().skip_body);
+        assert!(info(&["--fonts"]).parse_options().skip_body);
+        assert!(
+            info(&["--fonts", "--styles", "--style-analysis"])
+                .parse_options()
+                .skip_body
+        );
+
+        for flag in [
+            "--stats",
+            "--verbose",
+            "--word-frequency",
+            "--paragraph-stats",
+        ] {
+            assert!(!info(&[flag]).parse_options().skip_body, "{}", flag);
+        }
+    }
+
+    #[test]
+    fn test_paper_size() {
+        assert_eq!(paper_size(&PageDef::default()), "A4 (210×297mm) portrait");
+
+        let letter = PageDef {
+            width: HwpUnit(61200),
+            height: HwpUnit(79200),
+            orientation: PageOrientation::Landscape,
+            ..PageDef::default()
+        };
+        assert_eq!(paper_size(&letter), "Letter (216×279mm) landscape");
+
+        let custom = PageDef {
+            width: HwpUnit(36000),
+            height: HwpUnit(36000),
+            ..PageDef::default()
+        };
+        assert_eq!(paper_size(&custom), "127×127mm portrait");
+    }
+}
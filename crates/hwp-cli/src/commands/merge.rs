@@ -0,0 +1,226 @@
+use super::parse_output_format;
+use crate::error::CliError;
+use anyhow::Result;
+use clap::Args;
+use colored::*;
+use hwp_core::models::{Paragraph, Section};
+use hwp_core::HwpDocument;
+use hwp_parser::{parse, FormatOptions};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Merge command arguments
+#[derive(Args, Debug)]
+pub struct MergeCommand {
+    /// Input HWP files, merged in the order given
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Output format (text, markdown, json, html, yaml, rtf, csv, docx)
+    #[arg(short, long, default_value = "text")]
+    pub format: String,
+
+    /// Output file path (stdout if not specified)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Start each file's content with a paragraph naming the file
+    #[arg(long)]
+    pub title_from_filename: bool,
+
+    /// Report files that fail to parse and merge the rest
+    #[arg(long)]
+    pub continue_on_error: bool,
+}
+
+impl MergeCommand {
+    pub fn execute(&self) -> Result<()> {
+        let format = parse_output_format(&self.format)?;
+
+        let mut documents = Vec::new();
+        for input in &self.inputs {
+            match self.load(input) {
+                Ok(document) => documents.push((input.as_path(), document)),
+                Err(e) if self.continue_on_error => {
+                    eprintln!("{}: {}", "Skipped".yellow(), e);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        if documents.is_empty() {
+            anyhow::bail!(
+                "None of the {} input files could be parsed",
+                self.inputs.len()
+            );
+        }
+
+        let merged_count = documents.len();
+        let merged = self.merge(documents);
+        let options = FormatOptions {
+            source_name: self
+                .output
+                .as_ref()
+                .and_then(|path| path.file_stem())
+                .map(|stem| stem.to_string_lossy().into_owned()),
+            ..FormatOptions::default()
+        };
+        let output = format.create_formatter(options).format_bytes(&merged)?;
+
+        if let Some(output_path) = &self.output {
+            let mut file = fs::File::create(output_path)?;
+            file.write_all(&output)?;
+            eprintln!(
+                "Merged {} of {} files into: {}",
+                merged_count,
+                self.inputs.len(),
+                output_path.display()
+            );
+        } else {
+            std::io::stdout().write_all(&output)?;
+        }
+
+        Ok(())
+    }
+
+    fn load(&self, path: &Path) -> Result<HwpDocument, CliError> {
+        let parse_error = |details: String| CliError::ParseError {
+            file: path.to_path_buf(),
+            details,
+        };
+        let data = fs::read(path).map_err(|e| parse_error(e.to_string()))?;
+        parse(&data).map_err(|e| parse_error(e.to_string()))
+    }
+
+    /// Concatenate the sections of `documents` into one document
+    ///
+    /// Each file's sections follow those of the files before it, so
+    /// formatters separate the files as they do sections. Shape, style and
+    /// binary data IDs only mean something against their own file's
+    /// DocInfo, so the merged document has an empty one and its text is
+    /// rendered without the files' formatting.
+    fn merge(&self, documents: Vec<(&Path, HwpDocument)>) -> HwpDocument {
+        let mut merged: Option<HwpDocument> = None;
+
+        for (path, document) in documents {
+            let mut sections = document.sections;
+            if self.title_from_filename {
+                let title = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                if sections.is_empty() {
+                    sections.push(Section::new());
+                }
+                prepend_paragraph(&mut sections[0], title);
+            }
+
+            merged
+                .get_or_insert_with(|| HwpDocument::new(document.header))
+                .sections
+                .extend(sections);
+        }
+
+        let mut merged = merged.expect("merge needs at least one document");
+        merged.doc_info.properties.section_count = merged.sections.len() as u16;
+        merged
+    }
+}
+
+/// Insert a paragraph of plain text before a section's first paragraph,
+/// shifting the paragraph indices its controls are anchored by
+fn prepend_paragraph(section: &mut Section, text: String) {
+    let mut paragraph = Paragraph::new();
+    paragraph.text = text;
+    section.paragraphs.insert(0, paragraph);
+
+    for table in &mut section.tables {
+        table.paragraph_index += 1;
+    }
+    for equation in &mut section.equations {
+        equation.paragraph_index += 1;
+    }
+    for note in &mut section.footnotes {
+        note.paragraph_index += 1;
+    }
+    for link in &mut section.hyperlinks {
+        link.para_index += 1;
+    }
+    for picture in &mut section.pictures {
+        picture.paragraph_index += 1;
+    }
+    for header in &mut section.control_headers {
+        header.paragraph_index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_core::models::header::HwpHeader;
+    use hwp_core::models::section::Table;
+    use hwp_core::HwpVersion;
+
+    fn document(texts: &[&str]) -> HwpDocument {
+        let mut document = HwpDocument::new(HwpHeader::new(HwpVersion::new(5, 0, 0, 0)));
+        let mut section = Section::new();
+        for text in texts {
+            let mut paragraph = Paragraph::new();
+            paragraph.text = text.to_string();
+            section.paragraphs.push(paragraph);
+        }
+        section.tables.push(Table {
+            paragraph_index: 1,
+            ..Table::default()
+        });
+        document.sections.push(section);
+        document
+    }
+
+    fn command(title_from_filename: bool) -> MergeCommand {
+        MergeCommand {
+            inputs: Vec::new(),
+            format: "text".to_string(),
+            output: None,
+            title_from_filename,
+            continue_on_error: false,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_argument_order() {
+        let documents = vec![
+            (Path::new("b.hwp"), document(&["second"])),
+            (Path::new("a.hwp"), document(&["first", "more"])),
+        ];
+        let merged = command(false).merge(documents);
+
+        let texts: Vec<Vec<&str>> = merged
+            .sections
+            .iter()
+            .map(|s| s.paragraphs.iter().map(|p| p.text.as_str()).collect())
+            .collect();
+        assert_eq!(texts, vec![vec!["second"], vec!["first", "more"]]);
+        assert_eq!(merged.doc_info.properties.section_count, 2);
+    }
+
+    #[test]
+    fn test_merge_title_from_filename() {
+        let documents = vec![(Path::new("dir/report.hwp"), document(&["body", "table"]))];
+        let merged = command(true).merge(documents);
+
+        let section = &merged.sections[0];
+        assert_eq!(section.paragraphs[0].text, "report.hwp");
+        assert_eq!(section.paragraphs[1].text, "body");
+        // The table stays anchored to its paragraph
+        assert_eq!(section.tables[0].paragraph_index, 2);
+    }
+
+    #[test]
+    fn test_missing_file_reported_as_parse_error() {
+        let error = command(false)
+            .load(Path::new("does-not-exist.hwp"))
+            .unwrap_err();
+        assert!(matches!(error, CliError::ParseError { .. }));
+    }
+}
@@ -1,13 +1,78 @@
 pub mod batch;
 pub mod convert;
+pub mod diff;
 pub mod extract;
+pub mod extract_comments;
 pub mod info;
+pub mod merge;
+pub mod redact;
 pub mod search;
 pub mod validate;
 
 pub use batch::BatchCommand;
 pub use convert::ConvertCommand;
+pub use diff::DiffCommand;
 pub use extract::ExtractCommand;
+pub use extract_comments::ExtractCommentsCommand;
 pub use info::InfoCommand;
+pub use merge::MergeCommand;
+pub use redact::RedactCommand;
 pub use search::SearchCommand;
 pub use validate::ValidateCommand;
+
+use crate::error::{supported_formats, CliError};
+use hwp_parser::OutputFormat;
+
+/// Interpret a `--format` value, accepting the same aliases in every command
+pub(crate) fn parse_output_format(format: &str) -> Result<OutputFormat, CliError> {
+    format.parse().map_err(|_| CliError::UnsupportedFormat {
+        format: format.to_string(),
+        supported: supported_formats(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct ExtractCli {
+        #[command(flatten)]
+        extract: ExtractCommand,
+    }
+
+    #[test]
+    fn test_format_aliases() {
+        for (alias, expected) in [
+            ("plain", OutputFormat::PlainText),
+            ("TXT", OutputFormat::PlainText),
+            ("htm", OutputFormat::Html),
+            ("yml", OutputFormat::Yaml),
+            ("md", OutputFormat::Markdown),
+            ("RTF", OutputFormat::Rtf),
+            ("csv", OutputFormat::Csv),
+            ("DOCX", OutputFormat::Docx),
+        ] {
+            assert_eq!(parse_output_format(alias).unwrap(), expected);
+        }
+        assert!(parse_output_format("pdf").is_err());
+    }
+
+    #[test]
+    fn test_extract_accepts_plain_format() {
+        let missing = "does-not-exist.hwp";
+
+        // "plain" gets past format validation and fails only on the missing file
+        let cli = ExtractCli::parse_from(["hwp", missing, "--format", "plain"]);
+        let err = cli.extract.execute().unwrap_err();
+        assert!(err.downcast_ref::<std::io::Error>().is_some());
+
+        let cli = ExtractCli::parse_from(["hwp", missing, "--format", "pdf"]);
+        let err = cli.extract.execute().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CliError>(),
+            Some(CliError::UnsupportedFormat { .. })
+        ));
+    }
+}
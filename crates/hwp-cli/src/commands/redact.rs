@@ -0,0 +1,81 @@
+use super::parse_output_format;
+use anyhow::{Context, Result};
+use clap::Args;
+use hwp_parser::redact::redact_document;
+use hwp_parser::{parse, FormatOptions};
+use regex::Regex;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Redact command arguments
+#[derive(Args, Debug)]
+pub struct RedactCommand {
+    /// Input HWP file path
+    pub input: PathBuf,
+
+    /// Regular expression to redact; may be given more than once
+    #[arg(short, long, required = true)]
+    pub pattern: Vec<String>,
+
+    /// Output format (text, markdown, html, ...)
+    #[arg(short, long, default_value = "text")]
+    pub format: String,
+
+    /// Output file path (stdout if not specified)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl RedactCommand {
+    pub fn execute(&self) -> Result<()> {
+        let format = parse_output_format(&self.format)?;
+        let patterns = self.patterns()?;
+
+        let hwp_data = fs::read(&self.input)?;
+        let mut document = parse(&hwp_data)?;
+        let count = redact_document(&mut document, &patterns);
+
+        let formatter = format.create_formatter(FormatOptions::default());
+        let output = formatter.format_bytes(&document)?;
+
+        if let Some(output_path) = &self.output {
+            let mut file = fs::File::create(output_path)?;
+            file.write_all(&output)?;
+            eprintln!(
+                "Redacted {} matches; output written to: {}",
+                count,
+                output_path.display()
+            );
+        } else {
+            std::io::stdout().write_all(&output)?;
+            eprintln!("Redacted {} matches", count);
+        }
+
+        Ok(())
+    }
+
+    fn patterns(&self) -> Result<Vec<Regex>> {
+        self.pattern
+            .iter()
+            .map(|p| Regex::new(p).with_context(|| format!("Invalid pattern '{}'", p)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_pattern_rejected() {
+        let command = RedactCommand {
+            input: "a.hwp".into(),
+            pattern: vec![r"\d{3}-\d{2}".to_string(), "(".to_string()],
+            format: "text".to_string(),
+            output: None,
+        };
+        let error = command.patterns().unwrap_err();
+        assert_eq!(error.to_string(), "Invalid pattern '('");
+    }
+}
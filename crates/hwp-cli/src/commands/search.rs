@@ -1,10 +1,11 @@
 use crate::batch::{BatchProcessor, ErrorStrategy};
 use crate::error::CliError;
-use anyhow::{Context, Result};
+use anyhow::Result;
 use clap::Args;
 use colored::*;
 use hwp_core::HwpDocument;
 use hwp_parser::parse;
+use hwp_parser::search::{build_pattern, search_document, SearchOptions};
 use regex::Regex;
 use serde_json::json;
 use std::fs;
@@ -21,6 +22,12 @@ pub struct SearchMatch {
     pub text: String,
     pub context_before: Vec<String>,
     pub context_after: Vec<String>,
+    /// Character offsets of the first match within the paragraph
+    pub match_start: Option<usize>,
+    pub match_end: Option<usize>,
+    /// Byte offsets of the first match within the paragraph's UTF-8 text
+    pub byte_start: Option<usize>,
+    pub byte_end: Option<usize>,
 }
 
 /// Search command arguments
@@ -153,20 +160,18 @@ impl SearchCommand {
         Ok(())
     }
 
-    fn create_pattern(&self) -> Result<Regex> {
-        let pattern = if self.regex {
-            self.query.clone()
-        } else {
-            regex::escape(&self.query)
-        };
-
-        let pattern = if self.case_insensitive {
-            format!("(?i){}", pattern)
-        } else {
-            pattern
-        };
+    fn search_options(&self) -> SearchOptions {
+        SearchOptions {
+            regex: self.regex,
+            case_insensitive: self.case_insensitive,
+            invert_match: self.invert_match,
+            max_results: Some(self.max_results),
+            ..Default::default()
+        }
+    }
 
-        Regex::new(&pattern).with_context(|| format!("Invalid regex pattern: {}", pattern))
+    fn create_pattern(&self) -> Result<Regex> {
+        Ok(build_pattern(&self.query, &self.search_options())?)
     }
 
     fn discover_files(&self) -> Result<Vec<PathBuf>> {
@@ -201,55 +206,28 @@ impl SearchCommand {
         let hwp_data = fs::read(file)?;
         let document = parse(&hwp_data)?;
 
-        let mut matches = Vec::new();
-
-        for (section_idx, section) in document.sections.iter().enumerate() {
-            for (para_idx, paragraph) in section.paragraphs.iter().enumerate() {
-                let text = &paragraph.text;
-                let lines: Vec<&str> = text.lines().collect();
+        let options = SearchOptions {
+            before_context: before,
+            after_context: after,
+            ..self.search_options()
+        };
 
-                for (line_idx, line) in lines.iter().enumerate() {
-                    let is_match = if self.invert_match {
-                        !pattern.is_match(line)
-                    } else {
-                        pattern.is_match(line)
-                    };
-
-                    if is_match {
-                        // Collect context
-                        let start = if line_idx >= before {
-                            line_idx - before
-                        } else {
-                            0
-                        };
-                        let end = std::cmp::min(line_idx + after + 1, lines.len());
-
-                        let context_before = lines[start..line_idx]
-                            .iter()
-                            .map(|s| s.to_string())
-                            .collect();
-                        let context_after = lines[(line_idx + 1)..end]
-                            .iter()
-                            .map(|s| s.to_string())
-                            .collect();
-
-                        matches.push(SearchMatch {
-                            file: file.to_path_buf(),
-                            section: section_idx,
-                            paragraph: para_idx,
-                            line: line_idx,
-                            text: line.to_string(),
-                            context_before,
-                            context_after,
-                        });
-
-                        if matches.len() >= self.max_results {
-                            return Ok(matches);
-                        }
-                    }
-                }
-            }
-        }
+        let matches = search_document(&document, pattern, &options)
+            .into_iter()
+            .map(|m| SearchMatch {
+                file: file.to_path_buf(),
+                section: m.section,
+                paragraph: m.paragraph,
+                line: m.line,
+                text: m.text,
+                context_before: m.context_before,
+                context_after: m.context_after,
+                match_start: m.span.map(|span| span.char_start),
+                match_end: m.span.map(|span| span.char_end),
+                byte_start: m.span.map(|span| span.byte_start),
+                byte_end: m.span.map(|span| span.byte_end),
+            })
+            .collect();
 
         Ok(matches)
     }
@@ -333,6 +311,10 @@ impl SearchCommand {
                     "text": m.text,
                     "context_before": m.context_before,
                     "context_after": m.context_after,
+                    "match_start": m.match_start,
+                    "match_end": m.match_end,
+                    "byte_start": m.byte_start,
+                    "byte_end": m.byte_end,
                 })
             })
             .collect();
@@ -1,9 +1,19 @@
+use crate::error::CliError;
 use anyhow::Result;
 use clap::Args;
-use hwp_parser::parse;
+use hwp_core::HwpError;
+use hwp_parser::formatters::html::HtmlFormatter;
+use hwp_parser::integrity::check_integrity;
+use hwp_parser::{parse_with_options, ParseOptions};
+use serde::Serialize;
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
 
+/// Allowed relative difference between the stored and counted characters,
+/// covering text inside controls the parser does not extract
+const CHAR_COUNT_TOLERANCE: f64 = 0.05;
+
 #[derive(Args, Debug)]
 pub struct ValidateCommand {
     /// Input HWP file path
@@ -28,32 +38,199 @@ pub struct ValidateCommand {
     /// Verbose output
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Exit with an error when any warning is reported
+    #[arg(long)]
+    pub fail_on_warning: bool,
+
+    /// Emit a validation report (json, html) instead of text results
+    #[arg(long)]
+    pub report: Option<String>,
+
+    /// Report output file path (stdout if not specified)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Outcome of a single validation check
+#[derive(Debug, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub passed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Collected validation results for one file
+#[derive(Debug, Default, Serialize)]
+pub struct ValidationReport {
+    pub file: String,
+    pub file_size: usize,
+    pub checks: Vec<CheckResult>,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    fn check(&mut self, name: &str, passed: bool, detail: Option<String>) {
+        self.checks.push(CheckResult {
+            name: name.to_string(),
+            passed,
+            detail,
+        });
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Render the report as a standalone HTML page
+    pub fn to_html(&self) -> String {
+        let escape = HtmlFormatter::escape_html;
+        let mut html = String::new();
+
+        html.push_str("<!DOCTYPE html>\n");
+        html.push_str("<html lang=\"ko\">\n");
+        html.push_str("<head>\n");
+        html.push_str("    <meta charset=\"UTF-8\">\n");
+        html.push_str(&format!(
+            "    <title>Validation Report: {}</title>\n",
+            escape(&self.file)
+        ));
+        html.push_str("    <style>\n");
+        html.push_str(HtmlFormatter::get_default_css());
+        html.push_str("    </style>\n");
+        html.push_str("</head>\n");
+        html.push_str("<body>\n");
+        html.push_str("<div class=\"hwp-content\">\n");
+
+        html.push_str(&format!("<h1>{}</h1>\n", escape(&self.file)));
+        html.push_str(&format!("<p>File size: {} bytes</p>\n", self.file_size));
+
+        html.push_str("<h2>Checks</h2>\n");
+        html.push_str("<table class=\"results\">\n");
+        html.push_str("<tr><th>Check</th><th>Result</th><th>Details</th></tr>\n");
+        for check in &self.checks {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(&check.name),
+                if check.passed { "✓ Pass" } else { "✗ Fail" },
+                escape(check.detail.as_deref().unwrap_or(""))
+            ));
+        }
+        html.push_str("</table>\n");
+
+        for (title, items) in [("Errors", &self.errors), ("Warnings", &self.warnings)] {
+            if items.is_empty() {
+                continue;
+            }
+            html.push_str(&format!("<h2>{} ({})</h2>\n<ul>\n", title, items.len()));
+            for item in items {
+                html.push_str(&format!("<li>{}</li>\n", escape(item)));
+            }
+            html.push_str("</ul>\n");
+        }
+
+        html.push_str("</div>\n");
+        html.push_str("</body>\n");
+        html.push_str("</html>\n");
+        html
+    }
 }
 
 impl ValidateCommand {
     pub fn execute(&self) -> Result<()> {
         let start_time = std::time::Instant::now();
 
+        // Reject unknown report formats before doing any work
+        if let Some(report) = &self.report {
+            if !matches!(report.as_str(), "json" | "html") {
+                return Err(CliError::UnsupportedFormat {
+                    format: report.clone(),
+                    supported: vec!["json".to_string(), "html".to_string()],
+                }
+                .into());
+            }
+        }
+
         // Read the file
         let hwp_data = fs::read(&self.input)?;
         let file_size = hwp_data.len();
 
-        println!("Validating: {}", self.input.display());
-        println!(
+        let mut report = ValidationReport {
+            file: self.input.display().to_string(),
+            file_size,
+            ..Default::default()
+        };
+
+        self.note(&format!("Validating: {}", self.input.display()));
+        self.note(&format!(
             "File size: {} bytes ({:.2} MB)",
             file_size,
             file_size as f64 / 1_048_576.0
-        );
+        ));
+
+        // Check the container and every stream on their own before a full
+        // parse, collecting all problems
+        if self.check_integrity {
+            let mut first_failure = None;
+            for entry in check_integrity(&hwp_data) {
+                let name = format!("Integrity: {}", entry.stream_name);
+                if entry.is_ok() {
+                    self.note(&format!("✓ {} is intact", entry.stream_name));
+                } else {
+                    let issue = entry.issue.clone().unwrap_or_default();
+                    self.note(&format!("✗ {}: {}", entry.stream_name, issue));
+                    first_failure.get_or_insert(format!("{}: {}", entry.stream_name, issue));
+                }
+                report.check(&name, entry.is_ok(), entry.issue);
+            }
+
+            if let Some(failure) = first_failure {
+                if self.strict {
+                    self.write_report(&report)?;
+                    return Err(anyhow::anyhow!("Integrity check failed: {}", failure));
+                }
+            }
+        }
 
         // Parse the document
         let parse_start = std::time::Instant::now();
-        let document = match parse(&hwp_data) {
+        // Strict mode also rejects DocInfo records with unknown tags
+        let options = ParseOptions {
+            strict: self.strict,
+            ..ParseOptions::default()
+        };
+        let mut result = parse_with_options(&hwp_data, &options);
+        // An encrypted body can't be checked, but its header still can
+        let body_read = !matches!(
+            result,
+            Err(HwpError::PasswordProtected | HwpError::DrmProtected)
+        );
+        if !body_read {
+            let header_only = ParseOptions {
+                skip_body: true,
+                ..options
+            };
+            result = parse_with_options(&hwp_data, &header_only);
+        }
+        let document = match result {
+            Ok(doc) if body_read => {
+                report.check("File parsing", true, None);
+                self.note("✓ File parsing successful");
+                doc
+            }
             Ok(doc) => {
-                println!("✓ File parsing successful");
+                let detail = "Body is encrypted; only the header was checked";
+                report.check("File parsing", true, Some(detail.to_string()));
+                self.note("✓ File header parsed; the body is encrypted");
                 doc
             }
             Err(e) => {
-                println!("✗ File parsing failed: {}", e);
+                report.check("File parsing", false, Some(e.to_string()));
+                self.note(&format!("✗ File parsing failed: {}", e));
+                self.write_report(&report)?;
                 if !self.strict {
                     return Err(e.into());
                 }
@@ -63,11 +240,11 @@ impl ValidateCommand {
         let parse_time = parse_start.elapsed();
 
         // Basic validation checks
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
+        let errors = &mut report.errors;
+        let warnings = &mut report.warnings;
 
         // Check header
-        if document.header.version.major < 5 {
+        if !document.header.version.is_supported() {
             warnings.push(format!("Old HWP version: {}", document.header.version));
         }
 
@@ -79,67 +256,151 @@ impl ValidateCommand {
             warnings.push("Document has DRM protection".to_string());
         }
 
-        // Check document properties
-        if document.doc_info.properties.section_count == 0 {
-            errors.push("No sections found in document".to_string());
+        // Recoverable problems noted by the parser
+        for warning in &document.doc_info.warnings {
+            warnings.push(warning.to_string());
         }
 
-        if document.doc_info.properties.section_count as usize != document.sections.len() {
-            warnings.push(format!(
-                "Section count mismatch: header says {}, found {}",
-                document.doc_info.properties.section_count,
-                document.sections.len()
-            ));
-        }
+        if body_read {
+            // Check document properties
+            if document.doc_info.properties.section_count == 0 {
+                errors.push("No sections found in document".to_string());
+            }
+
+            if document.doc_info.properties.section_count as usize != document.sections.len() {
+                warnings.push(format!(
+                    "Section count mismatch: header says {}, found {}",
+                    document.doc_info.properties.section_count,
+                    document.sections.len()
+                ));
+            }
 
-        // Check sections
-        let mut total_paragraphs = 0;
-        let mut empty_sections = 0;
+            // Check sections
+            let mut total_paragraphs = 0;
+            let mut empty_sections = 0;
 
-        for (idx, section) in document.sections.iter().enumerate() {
-            if section.paragraphs.is_empty() {
-                empty_sections += 1;
-                if self.verbose {
-                    warnings.push(format!("Section {} is empty", idx));
+            for (idx, section) in document.sections.iter().enumerate() {
+                if section.paragraphs.is_empty() {
+                    empty_sections += 1;
+                    if self.verbose {
+                        warnings.push(format!("Section {} is empty", idx));
+                    }
                 }
+                total_paragraphs += section.paragraphs.len();
             }
-            total_paragraphs += section.paragraphs.len();
-        }
-
-        if empty_sections > 0 && !self.verbose {
-            warnings.push(format!("{} empty sections found", empty_sections));
-        }
 
-        // Check text extraction
-        if self.verify_structure || self.strict {
-            let text = document.get_text();
-            if text.is_empty() && total_paragraphs > 0 {
-                warnings.push("No text could be extracted despite having paragraphs".to_string());
+            if empty_sections > 0 && !self.verbose {
+                warnings.push(format!("{} empty sections found", empty_sections));
             }
 
-            if self.verbose {
-                println!("\nDocument Statistics:");
-                println!("  Sections: {}", document.sections.len());
-                println!("  Paragraphs: {}", total_paragraphs);
-                println!("  Text length: {} characters", text.chars().count());
-                println!("  Fonts: {}", document.doc_info.face_names.len());
-                println!("  Styles: {}", document.doc_info.styles.len());
+            // Check text extraction
+            if self.verify_structure || self.strict {
+                let text = document.get_text();
+                if text.is_empty() && total_paragraphs > 0 {
+                    warnings
+                        .push("No text could be extracted despite having paragraphs".to_string());
+                }
+
+                let stored = document.doc_info.properties.total_character_count as usize;
+                let counted = document.char_count();
+                if stored > 0
+                    && stored.abs_diff(counted) as f64 > stored as f64 * CHAR_COUNT_TOLERANCE
+                {
+                    warnings.push(format!(
+                        "Character count mismatch: properties say {}, counted {}",
+                        stored, counted
+                    ));
+                }
+
+                if self.verbose {
+                    self.note("\nDocument Statistics:");
+                    self.note(&format!("  Sections: {}", document.sections.len()));
+                    self.note(&format!("  Paragraphs: {}", total_paragraphs));
+                    self.note(&format!(
+                        "  Text length: {} characters",
+                        text.chars().count()
+                    ));
+                    self.note(&format!("  Fonts: {}", document.doc_info.face_names.len()));
+                    self.note(&format!("  Styles: {}", document.doc_info.styles.len()));
+                }
             }
         }
 
         // Performance metrics
         if self.performance {
             let total_time = start_time.elapsed();
-            println!("\nPerformance Metrics:");
-            println!("  Parse time: {:.2}ms", parse_time.as_secs_f64() * 1000.0);
-            println!("  Total time: {:.2}ms", total_time.as_secs_f64() * 1000.0);
-            println!(
+            self.note("\nPerformance Metrics:");
+            self.note(&format!(
+                "  Parse time: {:.2}ms",
+                parse_time.as_secs_f64() * 1000.0
+            ));
+            self.note(&format!(
+                "  Total time: {:.2}ms",
+                total_time.as_secs_f64() * 1000.0
+            ));
+            self.note(&format!(
                 "  Parse speed: {:.2} MB/s",
                 file_size as f64 / 1_048_576.0 / parse_time.as_secs_f64()
-            );
+            ));
         }
 
-        // Report results
+        let errors = &report.errors;
+        let warnings = &report.warnings;
+
+        // Structured reports replace the text results
+        if self.report.is_some() {
+            self.write_report(&report)?;
+        } else {
+            self.print_results(errors, warnings);
+        }
+
+        // Return error if strict mode and there are errors
+        if self.strict && !errors.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation failed with {} errors",
+                errors.len()
+            ));
+        }
+
+        if self.fail_on_warning && !warnings.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation failed with {} warnings",
+                warnings.len()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Print progress to stdout, or stderr when stdout carries a report
+    fn note(&self, line: &str) {
+        if self.report.is_some() {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    /// Write the structured report, if one was requested
+    fn write_report(&self, report: &ValidationReport) -> Result<()> {
+        let output = match self.report.as_deref() {
+            Some("json") => report.to_json()?,
+            Some("html") => report.to_html(),
+            _ => return Ok(()),
+        };
+
+        if let Some(output_path) = &self.output {
+            let mut file = fs::File::create(output_path)?;
+            file.write_all(output.as_bytes())?;
+            eprintln!("Validation report written to: {}", output_path.display());
+        } else {
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+
+    fn print_results(&self, errors: &[String], warnings: &[String]) {
         println!("\nValidation Results:");
 
         if errors.is_empty() && warnings.is_empty() {
@@ -147,27 +408,144 @@ impl ValidateCommand {
         } else {
             if !errors.is_empty() {
                 println!("\nErrors ({}):", errors.len());
-                for error in &errors {
+                for error in errors {
                     println!("  ✗ {}", error);
                 }
             }
 
             if !warnings.is_empty() {
                 println!("\nWarnings ({}):", warnings.len());
-                for warning in &warnings {
+                for warning in warnings {
                     println!("  ⚠ {}", warning);
                 }
             }
         }
+    }
+}
 
-        // Return error if strict mode and there are errors
-        if self.strict && !errors.is_empty() {
-            return Err(anyhow::anyhow!(
-                "Validation failed with {} errors",
-                errors.len()
-            ));
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        Ok(())
+    fn sample_report() -> ValidationReport {
+        let mut report = ValidationReport {
+            file: "reports/<draft>.hwp".to_string(),
+            file_size: 2048,
+            ..Default::default()
+        };
+        report.check("CFB header", true, None);
+        report.check(
+            "Stream DocInfo",
+            false,
+            Some("Stream not found".to_string()),
+        );
+        report.warnings.push("1 empty sections found".to_string());
+        report
+    }
+
+    #[test]
+    fn test_html_report_contains_file_and_results_table() {
+        let html = sample_report().to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>reports/&lt;draft&gt;.hwp</h1>"));
+        assert!(html.contains("<table class=\"results\">"));
+        assert!(html.contains("<tr><td>CFB header</td><td>✓ Pass</td><td></td></tr>"));
+        assert!(html.contains("<td>Stream DocInfo</td><td>✗ Fail</td><td>Stream not found</td>"));
+        assert!(html.contains("<li>1 empty sections found</li>"));
+        assert!(!html.contains("<h2>Errors"));
+    }
+
+    #[test]
+    fn test_json_report() {
+        let json: serde_json::Value =
+            serde_json::from_str(&sample_report().to_json().unwrap()).unwrap();
+
+        assert_eq!(json["file"], "reports/<draft>.hwp");
+        assert_eq!(json["checks"][1]["passed"], false);
+        assert!(json["checks"][0].get("detail").is_none());
+    }
+
+    #[test]
+    fn test_unknown_report_format_rejected() {
+        let cmd = ValidateCommand {
+            input: PathBuf::from("does-not-exist.hwp"),
+            strict: false,
+            check_integrity: false,
+            verify_structure: false,
+            performance: false,
+            verbose: false,
+            fail_on_warning: false,
+            report: Some("pdf".to_string()),
+            output: None,
+        };
+
+        let err = cmd.execute().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CliError>(),
+            Some(CliError::UnsupportedFormat { .. })
+        ));
+    }
+
+    /// A bare version 5.0 FileHeader with the given property flags
+    fn file_header(properties: u32) -> Vec<u8> {
+        let mut data = hwp_core::HWP_SIGNATURE.to_vec();
+        data.extend_from_slice(&hwp_core::HwpVersion::new(5, 0, 0, 0).to_u32().to_le_bytes());
+        data.extend_from_slice(&properties.to_le_bytes());
+        data.extend_from_slice(&[0u8; 216]);
+        data
+    }
+
+    #[test]
+    fn test_fail_on_warning() {
+        // A DRM-protected document, which parses with a warning
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("drm.hwp");
+        fs::write(&path, file_header(0x0010)).unwrap();
+
+        let mut cmd = ValidateCommand {
+            input: path.clone(),
+            strict: false,
+            check_integrity: false,
+            verify_structure: false,
+            performance: false,
+            verbose: false,
+            fail_on_warning: false,
+            report: Some("json".to_string()),
+            output: Some(path.with_extension("json")),
+        };
+        assert!(cmd.execute().is_ok());
+
+        cmd.fail_on_warning = true;
+        let err = cmd.execute().unwrap_err();
+        assert!(err.to_string().contains("warnings"));
+    }
+
+    #[test]
+    fn test_password_protected_header_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("protected.hwp");
+        fs::write(&path, file_header(0x0002)).unwrap();
+        let output = path.with_extension("json");
+
+        let cmd = ValidateCommand {
+            input: path.clone(),
+            strict: false,
+            check_integrity: false,
+            verify_structure: false,
+            performance: false,
+            verbose: false,
+            fail_on_warning: false,
+            report: Some("json".to_string()),
+            output: Some(output.clone()),
+        };
+        let result = cmd.execute();
+        assert!(result.is_ok(), "{:?}", result);
+
+        let report = fs::read_to_string(&output).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+        assert_eq!(report["checks"][0]["passed"], true);
+        assert_eq!(report["warnings"][0], "Document is password protected");
+        assert!(report["errors"].as_array().unwrap().is_empty());
     }
 }
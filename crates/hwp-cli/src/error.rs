@@ -44,12 +44,16 @@ pub fn supported_formats() -> Vec<String> {
     vec![
         "text".to_string(),
         "txt".to_string(),
+        "plain".to_string(),
         "json".to_string(),
         "markdown".to_string(),
         "md".to_string(),
         "html".to_string(),
+        "htm".to_string(),
         "yaml".to_string(),
         "yml".to_string(),
+        "rtf".to_string(),
         "csv".to_string(),
+        "docx".to_string(),
     ]
 }
@@ -6,8 +6,10 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use colored::*;
 use commands::{
-    BatchCommand, ConvertCommand, ExtractCommand, InfoCommand, SearchCommand, ValidateCommand,
+    BatchCommand, ConvertCommand, DiffCommand, ExtractCommand, ExtractCommentsCommand, InfoCommand,
+    MergeCommand, RedactCommand, SearchCommand, ValidateCommand,
 };
+use std::io::IsTerminal;
 
 #[derive(Parser)]
 #[command(name = "hwp")]
@@ -22,6 +24,10 @@ struct Cli {
     #[arg(short, long, global = true)]
     quiet: bool,
 
+    /// Disable colored output (also honors the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -31,6 +37,9 @@ enum Commands {
     /// Extract content from HWP files with advanced options
     Extract(ExtractCommand),
 
+    /// Export memos, tracked changes and range-tag comments as JSON
+    ExtractComments(ExtractCommentsCommand),
+
     /// Display comprehensive file information and analysis
     Info(InfoCommand),
 
@@ -46,6 +55,15 @@ enum Commands {
     /// Process multiple HWP files in batch
     Batch(BatchCommand),
 
+    /// Compare the paragraph text of two HWP files
+    Diff(DiffCommand),
+
+    /// Write a copy of the document text with matches of patterns blacked out
+    Redact(RedactCommand),
+
+    /// Concatenate the content of several HWP files into one output
+    Merge(MergeCommand),
+
     /// Inspect HWP file metadata (legacy, use 'info' instead)
     #[command(hide = true)]
     Inspect {
@@ -71,20 +89,41 @@ fn setup_logging(verbosity: u8, quiet: bool) {
         .init();
 }
 
+/// Decide whether colored output should be used
+fn color_enabled(no_color_flag: bool, no_color_env: bool, stdout_is_tty: bool) -> bool {
+    !no_color_flag && !no_color_env && stdout_is_tty
+}
+
+fn setup_colors(no_color: bool) {
+    let enabled = color_enabled(
+        no_color,
+        std::env::var_os("NO_COLOR").is_some(),
+        std::io::stdout().is_terminal(),
+    );
+    colored::control::set_override(enabled);
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Setup logging
     setup_logging(cli.verbose, cli.quiet);
 
+    // Colors are off when requested or when output is redirected
+    setup_colors(cli.no_color);
+
     // Execute command
     let result = match cli.command {
         Commands::Extract(cmd) => cmd.execute(),
+        Commands::ExtractComments(cmd) => cmd.execute(),
         Commands::Info(cmd) => cmd.execute(),
         Commands::Convert(cmd) => cmd.execute(),
         Commands::Validate(cmd) => cmd.execute(),
         Commands::Search(cmd) => cmd.execute(),
         Commands::Batch(cmd) => cmd.execute(),
+        Commands::Diff(cmd) => cmd.execute(),
+        Commands::Redact(cmd) => cmd.execute(),
+        Commands::Merge(cmd) => cmd.execute(),
         Commands::Inspect { file } => {
             // Legacy command - redirect to info
             eprintln!(
@@ -105,6 +144,7 @@ fn main() -> Result<()> {
                 word_frequency: false,
                 paragraph_stats: false,
                 style_analysis: false,
+                lenient: false,
             };
             info_cmd.execute()
         }
@@ -129,3 +169,26 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_enabled() {
+        assert!(color_enabled(false, false, true));
+        assert!(!color_enabled(true, false, true));
+        assert!(!color_enabled(false, true, true));
+        assert!(!color_enabled(false, false, false));
+    }
+
+    #[test]
+    fn test_no_ansi_codes_when_color_disabled() {
+        colored::control::set_override(false);
+        let output = format!("{}:{}", "file.hwp".blue().bold(), "match".red().bold());
+        colored::control::unset_override();
+
+        assert_eq!(output, "file.hwp:match");
+        assert!(!output.contains('\x1b'));
+    }
+}
@@ -1,5 +1,6 @@
 /// Fill types for shapes and backgrounds
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum FillType {
     None = 0,
@@ -11,6 +12,7 @@ pub enum FillType {
 
 /// Gradient types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum GradientType {
     Linear = 0,
@@ -21,6 +23,7 @@ pub enum GradientType {
 
 /// Pattern types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum PatternType {
     Horizontal = 0,
@@ -33,6 +36,7 @@ pub enum PatternType {
 
 /// Image fill mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum ImageFillMode {
     Tile = 0,
@@ -6,8 +6,14 @@ pub const HWP_SIGNATURE: &[u8] =
     b"HWP Document File\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00";
 pub const HWP_SIGNATURE_LEN: usize = 32;
 
+/// Signature at the start of an HWP 3.x file
+pub const HWP3_SIGNATURE: &[u8] = b"HWP Document File V3.00 \x1A\x01\x02\x03\x04\x05";
+
 /// HWP version struct
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Versions order by `(major, minor, build, revision)`, so a version can be
+/// compared directly against a minimum.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HwpVersion {
     pub major: u8,
@@ -42,9 +48,20 @@ impl HwpVersion {
             | (self.revision as u32)
     }
 
+    /// Whether this is the given version or a later one
+    pub fn is_at_least(&self, major: u8, minor: u8, build: u8, revision: u8) -> bool {
+        *self >= HwpVersion::new(major, minor, build, revision)
+    }
+
+    /// Whether documents of this version can be read: HWP 5.0.0.0 and
+    /// later, and HWP 3.x through its own flat layout
     pub fn is_supported(&self) -> bool {
-        // Support HWP 5.0.0.0 and above
-        self.major >= 5
+        self.major == 3 || self.is_hwp5()
+    }
+
+    /// Whether this version uses the HWP 5.0 layout
+    pub fn is_hwp5(&self) -> bool {
+        *self >= HwpVersion::new(5, 0, 0, 0)
     }
 }
 
@@ -54,3 +54,62 @@ pub mod section {
     pub const VIDEO_DATA: u16 = 0x006C;
     pub const SHAPE_COMPONENT_UNKNOWN: u16 = 0x006D;
 }
+
+/// Name of a record tag, as written in the format specification
+///
+/// TRACK_CHANGE_AUTHOR shares its ID with PARA_HEADER; without knowing the
+/// stream, the ID resolves to PARA_HEADER.
+pub fn name(tag: u16) -> Option<&'static str> {
+    let name = match tag {
+        doc_info::DOCUMENT_PROPERTIES => "DOCUMENT_PROPERTIES",
+        doc_info::ID_MAPPINGS => "ID_MAPPINGS",
+        doc_info::BIN_DATA => "BIN_DATA",
+        doc_info::FACE_NAME => "FACE_NAME",
+        doc_info::BORDER_FILL => "BORDER_FILL",
+        doc_info::CHAR_SHAPE => "CHAR_SHAPE",
+        doc_info::TAB_DEF => "TAB_DEF",
+        doc_info::NUMBERING => "NUMBERING",
+        doc_info::BULLET => "BULLET",
+        doc_info::PARA_SHAPE => "PARA_SHAPE",
+        doc_info::STYLE => "STYLE",
+        doc_info::DOC_DATA => "DOC_DATA",
+        doc_info::DISTRIBUTE_DOC_DATA => "DISTRIBUTE_DOC_DATA",
+        doc_info::COMPATIBLE_DOCUMENT => "COMPATIBLE_DOCUMENT",
+        doc_info::LAYOUT_COMPATIBILITY => "LAYOUT_COMPATIBILITY",
+        doc_info::TRACK_CHANGE => "TRACK_CHANGE",
+        doc_info::MEMO_SHAPE => "MEMO_SHAPE",
+        doc_info::FORBIDDEN_CHAR => "FORBIDDEN_CHAR",
+        doc_info::CHANGE_TRACKING => "CHANGE_TRACKING",
+        section::PARA_HEADER => "PARA_HEADER",
+        section::PARA_TEXT => "PARA_TEXT",
+        section::PARA_CHAR_SHAPE => "PARA_CHAR_SHAPE",
+        section::PARA_LINE_SEG => "PARA_LINE_SEG",
+        section::PARA_RANGE_TAG => "PARA_RANGE_TAG",
+        section::CTRL_HEADER => "CTRL_HEADER",
+        section::LIST_HEADER => "LIST_HEADER",
+        section::PAGE_DEF => "PAGE_DEF",
+        section::FOOTNOTE_SHAPE => "FOOTNOTE_SHAPE",
+        section::PAGE_BORDER_FILL => "PAGE_BORDER_FILL",
+        section::SHAPE_COMPONENT => "SHAPE_COMPONENT",
+        section::TABLE => "TABLE",
+        section::SHAPE_COMPONENT_LINE => "SHAPE_COMPONENT_LINE",
+        section::SHAPE_COMPONENT_RECTANGLE => "SHAPE_COMPONENT_RECTANGLE",
+        section::SHAPE_COMPONENT_ELLIPSE => "SHAPE_COMPONENT_ELLIPSE",
+        section::SHAPE_COMPONENT_ARC => "SHAPE_COMPONENT_ARC",
+        section::SHAPE_COMPONENT_POLYGON => "SHAPE_COMPONENT_POLYGON",
+        section::SHAPE_COMPONENT_CURVE => "SHAPE_COMPONENT_CURVE",
+        section::SHAPE_COMPONENT_OLE => "SHAPE_COMPONENT_OLE",
+        section::SHAPE_COMPONENT_PICTURE => "SHAPE_COMPONENT_PICTURE",
+        section::SHAPE_COMPONENT_CONTAINER => "SHAPE_COMPONENT_CONTAINER",
+        section::CTRL_DATA => "CTRL_DATA",
+        section::EQEDIT => "EQEDIT",
+        section::SHAPE_COMPONENT_TEXTART => "SHAPE_COMPONENT_TEXTART",
+        section::FORM_OBJECT => "FORM_OBJECT",
+        section::MEMO_LIST => "MEMO_LIST",
+        section::CHART_DATA => "CHART_DATA",
+        section::VIDEO_DATA => "VIDEO_DATA",
+        section::SHAPE_COMPONENT_UNKNOWN => "SHAPE_COMPONENT_UNKNOWN",
+        _ => return None,
+    };
+    Some(name)
+}
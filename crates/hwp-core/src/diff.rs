@@ -0,0 +1,385 @@
+use crate::models::{HwpDocument, Section};
+use std::cell::RefCell;
+
+/// How a paragraph changed between two documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    /// The paragraph only exists in the new document
+    Added,
+    /// The paragraph only exists in the old document
+    Removed,
+    /// The paragraph was replaced by one with different text
+    Modified,
+}
+
+/// One changed paragraph
+///
+/// Section indices are positions in the old and new document, and
+/// paragraph indices positions within those sections; `old_*` fields are
+/// `None` for added paragraphs and `new_*` fields for removed ones. A
+/// paragraph of a section only one document has carries the section index
+/// on that side alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiffHunk {
+    pub kind: ChangeKind,
+    pub old_section: Option<usize>,
+    pub new_section: Option<usize>,
+    pub old_paragraph: Option<usize>,
+    pub new_paragraph: Option<usize>,
+    pub old_text: Option<String>,
+    pub new_text: Option<String>,
+}
+
+/// Paragraph-level changes between two documents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentDiff {
+    /// Changed paragraphs in section order, then document order
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl DocumentDiff {
+    /// Compare the paragraph text of two documents section by section
+    ///
+    /// Sections are aligned first, so inserting or deleting a whole section
+    /// reports just its paragraphs rather than shifting every section after
+    /// it. Two sections correspond when at least half the paragraphs of the
+    /// longer one are unchanged, or when they take each other's place
+    /// between corresponding sections. Within corresponding sections the
+    /// paragraphs are aligned with the Myers diff algorithm, and a run of
+    /// removals directly followed by additions is paired up into
+    /// modifications.
+    pub fn compute(old: &HwpDocument, new: &HwpDocument) -> Self {
+        let old_texts: Vec<Vec<&str>> = old.sections.iter().map(texts).collect();
+        let new_texts: Vec<Vec<&str>> = new.sections.iter().map(texts).collect();
+        // Myers probes the same pair more than once, and each probe is a
+        // paragraph-level diff of its own
+        let (n, m) = (old_texts.len(), new_texts.len());
+        let probed = RefCell::new(vec![None; n * m]);
+        let edits = myers(n, m, |i, j| {
+            *probed.borrow_mut()[i * m + j]
+                .get_or_insert_with(|| similar(&old_texts[i], &new_texts[j]))
+        });
+
+        let mut hunks = Vec::new();
+        for run in runs(&edits) {
+            match run {
+                Run::Equal(i, j) => {
+                    diff_section((i, &old.sections[i]), (j, &new.sections[j]), &mut hunks)
+                }
+                Run::Changed(deleted, inserted) => {
+                    let paired = deleted.len().min(inserted.len());
+                    for (&i, &j) in deleted.iter().zip(&inserted) {
+                        diff_section((i, &old.sections[i]), (j, &new.sections[j]), &mut hunks);
+                    }
+                    for &i in &deleted[paired..] {
+                        for (p, text) in texts(&old.sections[i]).into_iter().enumerate() {
+                            hunks.push(removed(i, p, text));
+                        }
+                    }
+                    for &j in &inserted[paired..] {
+                        for (p, text) in texts(&new.sections[j]).into_iter().enumerate() {
+                            hunks.push(added(j, p, text));
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { hunks }
+    }
+
+    /// Hunks of the given kind, in document order
+    pub fn hunks_of(&self, kind: ChangeKind) -> Vec<&DiffHunk> {
+        self.hunks.iter().filter(|h| h.kind == kind).collect()
+    }
+
+    /// Whether the documents have the same paragraph text
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Number of hunks of the given kind
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.hunks.iter().filter(|h| h.kind == kind).count()
+    }
+}
+
+impl HwpDocument {
+    /// Compare this document's paragraph text against `other`
+    ///
+    /// See [`DocumentDiff::compute`]; `self` is the old document.
+    pub fn diff(&self, other: &HwpDocument) -> DocumentDiff {
+        DocumentDiff::compute(self, other)
+    }
+}
+
+/// A single step of the edit script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal,
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Whether two sections, given as their paragraph texts, are versions of
+/// the same section
+fn similar(a: &[&str], b: &[&str]) -> bool {
+    let longest = a.len().max(b.len());
+    let unchanged = myers(a.len(), b.len(), |i, j| a[i] == b[j])
+        .iter()
+        .filter(|e| **e == Edit::Equal)
+        .count();
+    unchanged * 2 >= longest
+}
+
+fn texts(section: &Section) -> Vec<&str> {
+    section.paragraphs.iter().map(|p| p.text.as_str()).collect()
+}
+
+fn diff_section(
+    (old_index, old): (usize, &Section),
+    (new_index, new): (usize, &Section),
+    hunks: &mut Vec<DiffHunk>,
+) {
+    let old_text = texts(old);
+    let new_text = texts(new);
+
+    let edits = myers(old_text.len(), new_text.len(), |i, j| {
+        old_text[i] == new_text[j]
+    });
+    for run in runs(&edits) {
+        let (deleted, inserted) = match run {
+            Run::Equal(..) => continue,
+            Run::Changed(deleted, inserted) => (deleted, inserted),
+        };
+
+        let paired = deleted.len().min(inserted.len());
+        for (&i, &j) in deleted.iter().zip(&inserted) {
+            hunks.push(DiffHunk {
+                kind: ChangeKind::Modified,
+                old_section: Some(old_index),
+                new_section: Some(new_index),
+                old_paragraph: Some(i),
+                new_paragraph: Some(j),
+                old_text: Some(old_text[i].to_string()),
+                new_text: Some(new_text[j].to_string()),
+            });
+        }
+        for &i in &deleted[paired..] {
+            hunks.push(removed(old_index, i, old_text[i]));
+        }
+        for &j in &inserted[paired..] {
+            hunks.push(added(new_index, j, new_text[j]));
+        }
+    }
+}
+
+/// A kept item, or the items deleted and inserted between two kept ones
+enum Run {
+    Equal(usize, usize),
+    Changed(Vec<usize>, Vec<usize>),
+}
+
+/// Group an edit script into kept items and runs of changes
+fn runs(edits: &[Edit]) -> Vec<Run> {
+    let mut runs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut deleted = Vec::new();
+    let mut inserted = Vec::new();
+    for edit in edits {
+        match *edit {
+            Edit::Equal => {
+                if !deleted.is_empty() || !inserted.is_empty() {
+                    runs.push(Run::Changed(
+                        std::mem::take(&mut deleted),
+                        std::mem::take(&mut inserted),
+                    ));
+                }
+                runs.push(Run::Equal(i, j));
+                i += 1;
+                j += 1;
+            }
+            Edit::Delete(index) => {
+                deleted.push(index);
+                i += 1;
+            }
+            Edit::Insert(index) => {
+                inserted.push(index);
+                j += 1;
+            }
+        }
+    }
+    if !deleted.is_empty() || !inserted.is_empty() {
+        runs.push(Run::Changed(deleted, inserted));
+    }
+    runs
+}
+
+fn removed(section: usize, paragraph: usize, text: &str) -> DiffHunk {
+    DiffHunk {
+        kind: ChangeKind::Removed,
+        old_section: Some(section),
+        new_section: None,
+        old_paragraph: Some(paragraph),
+        new_paragraph: None,
+        old_text: Some(text.to_string()),
+        new_text: None,
+    }
+}
+
+fn added(section: usize, paragraph: usize, text: &str) -> DiffHunk {
+    DiffHunk {
+        kind: ChangeKind::Added,
+        old_section: None,
+        new_section: Some(section),
+        old_paragraph: None,
+        new_paragraph: Some(paragraph),
+        old_text: None,
+        new_text: Some(text.to_string()),
+    }
+}
+
+/// Shortest edit script from a sequence of `n` items to one of `m` items,
+/// where `matches(i, j)` tells whether item `i` of the first may be kept as
+/// item `j` of the second (Myers, "An O(ND) Difference Algorithm and Its
+/// Variations", 1986)
+///
+/// Uses the linear-space refinement from the same paper: the middle snake
+/// of an optimal path splits the problem in two, and each half is solved
+/// the same way, so memory stays proportional to `n + m`.
+fn myers(n: usize, m: usize, matches: impl Fn(usize, usize) -> bool) -> Vec<Edit> {
+    let mut diff = Myers {
+        matches,
+        forward: Frontier::new(n + m),
+        backward: Frontier::new(n + m),
+        edits: Vec::with_capacity(n.max(m)),
+    };
+    diff.conquer(0, n, 0, m);
+    diff.edits
+}
+
+/// Furthest x reached on each diagonal k, for k in -max..=max
+struct Frontier {
+    offset: isize,
+    x: Vec<usize>,
+}
+
+impl Frontier {
+    fn new(max: usize) -> Self {
+        Self {
+            offset: max as isize + 1,
+            x: vec![0; 2 * max + 3],
+        }
+    }
+
+    fn get(&self, k: isize) -> usize {
+        self.x[(k + self.offset) as usize]
+    }
+
+    fn set(&mut self, k: isize, x: usize) {
+        self.x[(k + self.offset) as usize] = x;
+    }
+}
+
+struct Myers<F> {
+    matches: F,
+    forward: Frontier,
+    backward: Frontier,
+    edits: Vec<Edit>,
+}
+
+impl<F: Fn(usize, usize) -> bool> Myers<F> {
+    /// Append the edit script from items `a0..a1` to items `b0..b1`
+    fn conquer(&mut self, mut a0: usize, mut a1: usize, mut b0: usize, mut b1: usize) {
+        while a0 < a1 && b0 < b1 && (self.matches)(a0, b0) {
+            self.edits.push(Edit::Equal);
+            a0 += 1;
+            b0 += 1;
+        }
+        let mut suffix = 0;
+        while a0 < a1 && b0 < b1 && (self.matches)(a1 - 1, b1 - 1) {
+            a1 -= 1;
+            b1 -= 1;
+            suffix += 1;
+        }
+
+        if a0 == a1 {
+            self.edits.extend((b0..b1).map(Edit::Insert));
+        } else if b0 == b1 {
+            self.edits.extend((a0..a1).map(Edit::Delete));
+        } else {
+            let (x, y) = self.middle_snake(a0, a1, b0, b1);
+            self.conquer(a0, x, b0, y);
+            self.conquer(x, a1, y, b1);
+        }
+
+        self.edits
+            .extend(std::iter::repeat(Edit::Equal).take(suffix));
+    }
+
+    /// A point that an optimal path from `(a0, b0)` to `(a1, b1)` passes
+    /// through, found by searching from both ends until the paths overlap
+    ///
+    /// Both ranges must be non-empty and differ in their first and last
+    /// items, so the point is neither end.
+    fn middle_snake(&mut self, a0: usize, a1: usize, b0: usize, b1: usize) -> (usize, usize) {
+        let n = a1 - a0;
+        let m = b1 - b0;
+        let delta = n as isize - m as isize;
+        let odd = delta & 1 == 1;
+        self.forward.set(1, 0);
+        self.backward.set(1, 0);
+
+        for d in 0..=((n + m + 1) / 2) as isize {
+            // Forward paths from (a0, b0)
+            let mut k = -d;
+            while k <= d {
+                let f = &self.forward;
+                let x = if k == -d || (k != d && f.get(k - 1) < f.get(k + 1)) {
+                    f.get(k + 1)
+                } else {
+                    f.get(k - 1) + 1
+                };
+                let y = (x as isize - k) as usize;
+                let (mut end_x, mut end_y) = (x, y);
+                while end_x < n && end_y < m && (self.matches)(a0 + end_x, b0 + end_y) {
+                    end_x += 1;
+                    end_y += 1;
+                }
+                self.forward.set(k, end_x);
+                if odd && (k - delta).abs() < d && end_x + self.backward.get(delta - k) >= n {
+                    return (a0 + x, b0 + y);
+                }
+                k += 2;
+            }
+
+            // Backward paths from (a1, b1), with x counted from the end
+            let mut k = -d;
+            while k <= d {
+                let b = &self.backward;
+                let x = if k == -d || (k != d && b.get(k - 1) < b.get(k + 1)) {
+                    b.get(k + 1)
+                } else {
+                    b.get(k - 1) + 1
+                };
+                let y = (x as isize - k) as usize;
+                let (mut end_x, mut end_y) = (x, y);
+                while end_x < n && end_y < m && (self.matches)(a1 - end_x - 1, b1 - end_y - 1) {
+                    end_x += 1;
+                    end_y += 1;
+                }
+                self.backward.set(k, end_x);
+                if !odd && (k - delta).abs() <= d && end_x + self.forward.get(delta - k) >= n {
+                    return (a1 - end_x, b1 - end_y);
+                }
+                k += 2;
+            }
+        }
+
+        // Unreachable: the searches meet by d = (n + m + 1) / 2
+        (a0 + n / 2, b0 + m / 2)
+    }
+}
@@ -8,6 +8,12 @@ pub enum HwpError {
     #[error("Unsupported HWP version: {version}")]
     UnsupportedVersion { version: String },
 
+    #[error("Document is password protected")]
+    PasswordProtected,
+
+    #[error("Document is DRM protected")]
+    DrmProtected,
+
     #[error("Invalid file format: {reason}")]
     InvalidFormat { reason: String },
 
@@ -26,7 +32,10 @@ pub enum HwpError {
     #[error("Unsupported feature: {feature}")]
     UnsupportedFeature { feature: String },
 
-    #[error("Invalid record: tag={tag}, level={level}, size={size}")]
+    #[error(
+        "Invalid record: {}(0x{tag:04X}) level={level} size={size}",
+        crate::constants::tag_id::name(*tag).unwrap_or("UNKNOWN")
+    )]
     InvalidRecord { tag: u16, level: u8, size: u32 },
 
     #[error(
@@ -38,4 +47,47 @@ pub enum HwpError {
     ValidationError { message: String },
 }
 
+impl HwpError {
+    /// Stable, machine-readable code for the error kind
+    ///
+    /// Unlike the `Display` message, codes never change between releases,
+    /// so bindings can branch on them or look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            HwpError::InvalidSignature => "INVALID_SIGNATURE",
+            HwpError::UnsupportedVersion { .. } => "UNSUPPORTED_VERSION",
+            HwpError::PasswordProtected => "PASSWORD_PROTECTED",
+            HwpError::DrmProtected => "DRM_PROTECTED",
+            HwpError::InvalidFormat { .. } => "INVALID_FORMAT",
+            HwpError::DecompressionError(_) => "DECOMPRESSION_FAILED",
+            HwpError::ParseError { .. } => "PARSE_ERROR",
+            HwpError::EncodingError(_) => "ENCODING_ERROR",
+            HwpError::IoError(_) => "IO_ERROR",
+            HwpError::UnsupportedFeature { .. } => "UNSUPPORTED_FEATURE",
+            HwpError::InvalidRecord { .. } => "INVALID_RECORD",
+            HwpError::BufferUnderflow { .. } => "BUFFER_UNDERFLOW",
+            HwpError::ValidationError { .. } => "VALIDATION_ERROR",
+        }
+    }
+}
+
+/// Serializes as `{ "code": ..., "message": ... }`
+///
+/// There is no `Deserialize`: an I/O error cannot be rebuilt from its
+/// message, and consumers only need the code and text.
+#[cfg(feature = "serde")]
+impl serde::Serialize for HwpError {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut error = serializer.serialize_struct("HwpError", 2)?;
+        error.serialize_field("code", self.code())?;
+        error.serialize_field("message", &self.to_string())?;
+        error.end()
+    }
+}
+
 pub type Result<T> = std::result::Result<T, HwpError>;
@@ -1,8 +1,12 @@
 pub mod constants;
+pub mod diff;
 pub mod errors;
 pub mod models;
 
 // Re-export commonly used items
 pub use constants::{HwpVersion, HWP_SIGNATURE, HWP_SIGNATURE_LEN};
+pub use diff::{ChangeKind, DiffHunk, DocumentDiff};
 pub use errors::{HwpError, Result};
-pub use models::{HwpDocument, HwpHeader, Paragraph, Record, Section};
+pub use models::{
+    FontLanguage, HwpDocument, HwpHeader, Paragraph, ParagraphRange, Record, Section, TextItem,
+};
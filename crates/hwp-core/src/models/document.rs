@@ -1,5 +1,8 @@
-use crate::models::{HwpHeader, Section};
+use crate::errors::HwpError;
+use crate::models::section::Table;
+use crate::models::{HwpHeader, Paragraph, Section};
 use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Main HWP document structure
 #[derive(Debug)]
@@ -16,6 +19,10 @@ pub struct HwpDocument {
 
     /// Binary data storage
     pub bin_data: HashMap<u16, Vec<u8>>,
+
+    /// Summary properties (title, author, ...), when the file has them
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub summary: Option<DocumentSummary>,
 }
 
 impl HwpDocument {
@@ -26,23 +33,371 @@ impl HwpDocument {
             doc_info: DocInfo::default(),
             sections: Vec::new(),
             bin_data: HashMap::new(),
+            summary: None,
         }
     }
 
     /// Get the total page count
+    ///
+    /// See [`Section::page_count_with`]; the document's own shapes are
+    /// used for sections that have to be estimated.
     pub fn page_count(&self) -> usize {
-        self.sections.iter().map(|s| s.page_count()).sum()
+        self.sections
+            .iter()
+            .map(|s| s.page_count_with(&self.doc_info))
+            .sum()
+    }
+
+    /// Count characters the way HWP does for `total_character_count`
+    ///
+    /// Controls are excluded and each Hangul or CJK syllable counts as one,
+    /// so the result is comparable to the count stored in the document
+    /// properties. This is the count integrity checks should use.
+    pub fn char_count(&self) -> usize {
+        self.sections.iter().map(|s| s.char_count()).sum()
+    }
+
+    /// Count the characters drawn in each font, most used first
+    ///
+    /// Each character's font is resolved from its char shape run and its
+    /// language group. Whitespace and paragraphs without char shapes are
+    /// not counted.
+    pub fn font_usage(&self) -> Vec<(&str, usize)> {
+        let mut usage: Vec<(&str, usize)> = Vec::new();
+
+        for paragraph in self.sections.iter().flat_map(|s| &s.paragraphs) {
+            let runs = &paragraph.char_shapes;
+            for (index, c) in paragraph.text.chars().enumerate() {
+                if c.is_whitespace() {
+                    continue;
+                }
+                let run = match runs.iter().rev().find(|r| r.position as usize <= index) {
+                    Some(run) => run,
+                    None => continue,
+                };
+                let face = self
+                    .doc_info
+                    .char_shapes
+                    .get(run.shape_id as usize)
+                    .and_then(|shape| {
+                        self.doc_info
+                            .char_shape_font(shape, FontLanguage::for_char(c))
+                    });
+
+                if let Some(face) = face {
+                    match usage.iter_mut().find(|(name, _)| *name == face.name) {
+                        Some((_, count)) => *count += 1,
+                        None => usage.push((face.name.as_str(), 1)),
+                    }
+                }
+            }
+        }
+
+        // Stable sort keeps first-use order among equally used fonts
+        usage.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        usage
     }
 
     /// Get all text content from the document
     pub fn get_text(&self) -> String {
+        let mut text = String::new();
+        for item in self.text_items() {
+            match item {
+                TextItem::Paragraph { text: t, .. } => {
+                    text.push_str(t);
+                    text.push('\n');
+                }
+                TextItem::SectionEnd { .. } => text.push('\n'),
+            }
+        }
+        text
+    }
+
+    /// Get all text with tables delimited for splitting by later tools
+    ///
+    /// Sections are joined as in [`get_text`](Self::get_text); see
+    /// [`Section::marked_text`] for how tables are marked.
+    pub fn marked_text(&self) -> String {
         let mut text = String::new();
         for section in &self.sections {
-            text.push_str(&section.get_text());
+            text.push_str(&section.marked_text());
             text.push('\n');
         }
         text
     }
+
+    /// Iterate over paragraph text one paragraph at a time
+    ///
+    /// Text is borrowed from the document, and a [`TextItem::SectionEnd`]
+    /// marker follows the last paragraph of every section.
+    pub fn text_items(&self) -> impl Iterator<Item = TextItem<'_>> {
+        self.sections.iter().enumerate().flat_map(|(section, sec)| {
+            sec.paragraphs
+                .iter()
+                .enumerate()
+                .map(move |(index, p)| TextItem::Paragraph {
+                    section,
+                    index,
+                    text: &p.text,
+                })
+                .chain(std::iter::once(TextItem::SectionEnd { section }))
+        })
+    }
+
+    /// Check whether the document has nothing worth processing:
+    /// no non-whitespace text, no tables and no embedded images
+    pub fn is_empty(&self) -> bool {
+        let has_text = self
+            .sections
+            .iter()
+            .flat_map(|s| &s.paragraphs)
+            .any(|p| !p.text.trim().is_empty());
+        let has_tables = self.sections.iter().any(|s| !s.tables.is_empty());
+        // Linked entries and non-image data such as OLE objects leave no
+        // image stream behind
+        let has_images = self
+            .bin_data
+            .values()
+            .any(|data| image_extension(data).is_some());
+
+        !(has_text || has_tables || has_images)
+    }
+
+    /// Check whether the document has any text, tables or images
+    pub fn has_content(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Number of words in the body and table cell text
+    ///
+    /// Words follow Unicode word boundaries (UAX #29) closely enough for
+    /// statistics: a word is a run of letters and digits, which an
+    /// apostrophe, period, comma or colon between two of them does not
+    /// end, and each Chinese character or kana counts as a word of its own.
+    pub fn word_count(&self) -> usize {
+        self.paragraph_texts().into_iter().map(count_words).sum()
+    }
+
+    /// Number of Hangul syllables and jamo in the body and table cell text
+    pub fn char_count_hangul(&self) -> usize {
+        self.count_chars(is_hangul)
+    }
+
+    /// Number of Latin letters in the body and table cell text, accented
+    /// and full-width forms included
+    pub fn char_count_latin(&self) -> usize {
+        self.count_chars(is_latin)
+    }
+
+    /// Number of Chinese characters (hanja) and Japanese kana in the body
+    /// and table cell text; Hangul is counted by [`char_count_hangul`]
+    ///
+    /// [`char_count_hangul`]: HwpDocument::char_count_hangul
+    pub fn char_count_cjk(&self) -> usize {
+        self.count_chars(is_cjk)
+    }
+
+    /// Text of every paragraph, table cells (nested ones included) after
+    /// the body of their section, the same text [`char_count`] covers
+    ///
+    /// [`char_count`]: HwpDocument::char_count
+    fn paragraph_texts(&self) -> Vec<&str> {
+        fn table_texts<'a>(table: &'a Table, texts: &mut Vec<&'a str>) {
+            for cell in &table.cells {
+                texts.extend(cell.paragraphs.iter().map(|p| p.text.as_str()));
+                for nested in &cell.tables {
+                    table_texts(nested, texts);
+                }
+            }
+        }
+
+        let mut texts = Vec::new();
+        for section in &self.sections {
+            texts.extend(section.paragraphs.iter().map(|p| p.text.as_str()));
+            for table in &section.tables {
+                table_texts(table, &mut texts);
+            }
+        }
+        texts
+    }
+
+    fn count_chars(&self, script: fn(char) -> bool) -> usize {
+        self.paragraph_texts()
+            .into_iter()
+            .map(|text| text.chars().filter(|c| script(*c)).count())
+            .sum()
+    }
+
+    /// Iterate over paragraphs in a range, numbered across all sections
+    pub fn paragraphs_in_range(
+        &self,
+        range: ParagraphRange,
+    ) -> impl Iterator<Item = (usize, &Paragraph)> {
+        self.sections
+            .iter()
+            .flat_map(|s| &s.paragraphs)
+            .enumerate()
+            .skip(range.start)
+            .take_while(move |(idx, _)| range.contains(*idx))
+    }
+
+    /// Extract the text of the paragraphs in a range, one paragraph per line
+    pub fn extract_range(&self, range: ParagraphRange) -> String {
+        let mut text = String::new();
+        for (_, paragraph) in self.paragraphs_in_range(range) {
+            if !paragraph.text.is_empty() {
+                text.push_str(&paragraph.text);
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// Group the non-empty paragraphs by their style's name
+    ///
+    /// Groups are in the order each style first appears and keep the
+    /// paragraphs in document order. A paragraph whose style ID has no
+    /// matching style is grouped under `Style <id>`.
+    pub fn paragraphs_by_style(&self) -> Vec<(String, Vec<&Paragraph>)> {
+        let mut groups: Vec<(String, Vec<&Paragraph>)> = Vec::new();
+
+        for paragraph in self.sections.iter().flat_map(|s| &s.paragraphs) {
+            if paragraph.text.is_empty() {
+                continue;
+            }
+            let name = match self.doc_info.paragraph_style(paragraph) {
+                Some(style) => style.display_name().to_string(),
+                None => format!("Style {}", paragraph.header.style_id),
+            };
+            match groups.iter_mut().find(|(group, _)| *group == name) {
+                Some((_, paragraphs)) => paragraphs.push(paragraph),
+                None => groups.push((name, vec![paragraph])),
+            }
+        }
+
+        groups
+    }
+}
+
+pub(crate) fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+fn is_latin(c: char) -> bool {
+    c.is_alphabetic()
+        && matches!(
+            c,
+            'A'..='Z'
+                | 'a'..='z'
+                | '\u{C0}'..='\u{24F}'
+                | '\u{1E00}'..='\u{1EFF}'
+                | '\u{FF21}'..='\u{FF3A}'
+                | '\u{FF41}'..='\u{FF5A}'
+        )
+}
+
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{30FF}' // Hiragana and Katakana
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3134F}'
+    )
+}
+
+/// Count the words of one paragraph, as described on [`HwpDocument::word_count`]
+fn count_words(text: &str) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let is_word_char = |c: char| c.is_alphanumeric() && !is_cjk(c);
+
+    let mut count = 0;
+    let mut in_word = false;
+    for (i, &c) in chars.iter().enumerate() {
+        if is_cjk(c) {
+            count += 1;
+            in_word = false;
+        } else if is_word_char(c) {
+            if !in_word {
+                count += 1;
+                in_word = true;
+            }
+        } else {
+            // "don't", "3.14" and "1,000" are single words
+            let joins = in_word
+                && matches!(c, '\'' | '\u{2019}' | '.' | ',' | ':')
+                && chars.get(i + 1).is_some_and(|next| is_word_char(*next));
+            in_word = joins;
+        }
+    }
+    count
+}
+
+/// Item yielded by [`HwpDocument::text_items`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextItem<'a> {
+    /// Text of one paragraph, numbered within its section
+    Paragraph {
+        section: usize,
+        index: usize,
+        text: &'a str,
+    },
+    /// End of a section
+    SectionEnd { section: usize },
+}
+
+/// Range of paragraph indices, counted across all sections
+///
+/// Both bounds are inclusive; an open end extends to the last paragraph.
+/// Parses from strings like `"2-4"`, `"5-"`, `"-3"` or `"7"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphRange {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+impl ParagraphRange {
+    /// Create a range from `start` to `end` (inclusive)
+    pub fn new(start: usize, end: Option<usize>) -> Self {
+        Self { start, end }
+    }
+
+    /// Check whether a paragraph index falls within the range
+    pub fn contains(&self, index: usize) -> bool {
+        index >= self.start && self.end.map_or(true, |end| index <= end)
+    }
+}
+
+impl FromStr for ParagraphRange {
+    type Err = HwpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_bound = |part: &str| -> Result<Option<usize>, HwpError> {
+            let part = part.trim();
+            if part.is_empty() {
+                return Ok(None);
+            }
+            part.parse().map(Some).map_err(|_| HwpError::InvalidFormat {
+                reason: format!("Invalid paragraph range '{}'", s),
+            })
+        };
+
+        match s.split_once('-') {
+            Some((start, end)) => Ok(Self::new(
+                parse_bound(start)?.unwrap_or(0),
+                parse_bound(end)?,
+            )),
+            None => match parse_bound(s)? {
+                Some(index) => Ok(Self::new(index, Some(index))),
+                None => Err(HwpError::InvalidFormat {
+                    reason: "Empty paragraph range".to_string(),
+                }),
+            },
+        }
+    }
 }
 
 /// Document information container
@@ -76,6 +431,10 @@ pub struct DocInfo {
     /// Document-specific data
     pub doc_data: Vec<u8>,
 
+    /// Document variables, when the DOC_DATA record holds a parameter set
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub doc_variables: Option<DocData>,
+
     /// Tab definitions
     pub tab_defs: Vec<TabDef>,
 
@@ -105,6 +464,126 @@ pub struct DocInfo {
 
     /// Forbidden characters
     pub forbidden_chars: Option<ForbiddenChar>,
+
+    /// Recoverable problems found while parsing
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// A recoverable problem found while parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationWarning {
+    /// Tag ID of the offending record, if any
+    pub tag_id: Option<u16>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tag_id {
+            Some(tag_id) => write!(f, "{} (tag 0x{:04X})", self.message, tag_id),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl DocInfo {
+    /// Resolve a font id for a language to its face name
+    ///
+    /// Face names are stored grouped by language, and each group's size is
+    /// given by `ID_MAPPINGS`, so `id` indexes into its language's group.
+    /// Without usable mappings this falls back to the record position.
+    pub fn face_name(&self, language: FontLanguage, id: u16) -> Option<&FaceName> {
+        let lang = language as usize;
+        let counts = match self.id_mappings.get(1..=FontLanguage::COUNT) {
+            Some(counts) => counts,
+            None => return self.face_names.get(id as usize),
+        };
+
+        if id as u32 >= counts[lang] {
+            return None;
+        }
+
+        let offset: u32 = counts[..lang].iter().sum();
+        self.face_names.get((offset + id as u32) as usize)
+    }
+
+    /// Resolve the style a paragraph's header refers to
+    pub fn paragraph_style(&self, paragraph: &Paragraph) -> Option<&Style> {
+        self.styles.get(paragraph.header.style_id as usize)
+    }
+
+    /// Resolve the numbered or bulleted list a paragraph belongs to
+    ///
+    /// Returns `None` for paragraphs whose shape has no numbering or bullet
+    /// heading, or refers to a definition the document lacks. Outline
+    /// numbering comes from the section rather than the paragraph shape and
+    /// is not resolved here.
+    pub fn paragraph_list(&self, paragraph: &Paragraph) -> Option<ListItem> {
+        let shape = self
+            .para_shapes
+            .get(paragraph.header.para_shape_id as usize)?;
+        let kind = match shape.heading_type() {
+            2 => ListKind::Numbered,
+            3 => ListKind::Bulleted,
+            _ => return None,
+        };
+
+        // Numbering and bullet IDs count from 1; 0 means none
+        let index = (shape.numbering_id as usize).checked_sub(1)?;
+        let defined = match kind {
+            ListKind::Numbered => index < self.numberings.len(),
+            ListKind::Bulleted => index < self.bullets.len(),
+        };
+        defined.then_some(ListItem {
+            kind,
+            id: shape.numbering_id,
+            level: shape.level(),
+        })
+    }
+
+    /// Number the first item of a list level starts at
+    ///
+    /// `id` is a [`ListItem::id`] of a numbered list; levels without a
+    /// definition start at 1.
+    pub fn list_start_number(&self, id: u16, level: u8) -> u32 {
+        (id as usize)
+            .checked_sub(1)
+            .and_then(|index| self.numberings.get(index))
+            .and_then(|numbering| numbering.levels.get(level as usize))
+            .map_or(1, |level| level.start_number as u32)
+    }
+
+    /// Resolve the face name a character shape uses for a language
+    pub fn char_shape_font(
+        &self,
+        char_shape: &CharShape,
+        language: FontLanguage,
+    ) -> Option<&FaceName> {
+        let id = *char_shape.face_name_ids.get(language as usize)?;
+        self.face_name(language, id)
+    }
+
+    /// Names of every font the character shapes reference, deduplicated
+    /// in first-use order
+    pub fn unique_font_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for shape in &self.char_shapes {
+            for language in FontLanguage::ALL {
+                if let Some(face) = self.char_shape_font(shape, language) {
+                    if !names.contains(&face.name.as_str()) {
+                        names.push(face.name.as_str());
+                    }
+                }
+            }
+        }
+        names
+    }
 }
 
 /// Document properties
@@ -122,6 +601,20 @@ pub struct DocumentProperties {
     pub total_page_count: u32,
 }
 
+/// Document summary properties from the `HwpSummaryInformation` stream
+///
+/// Timestamps are ISO 8601 UTC strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentSummary {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    pub created: Option<String>,
+    pub modified: Option<String>,
+}
+
 /// Character shape information
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -142,16 +635,44 @@ pub struct CharShape {
     pub border_fill_id: Option<u16>,
 }
 
+impl CharShape {
+    /// Whether the text is italic (property bit 0)
+    pub fn is_italic(&self) -> bool {
+        self.properties & 1 != 0
+    }
+
+    /// Whether the text is bold (property bit 1)
+    pub fn is_bold(&self) -> bool {
+        self.properties & (1 << 1) != 0
+    }
+
+    /// Whether the text has an underline or overline (property bits 2-3)
+    pub fn is_underlined(&self) -> bool {
+        (self.properties >> 2) & 0x3 != 0
+    }
+
+    /// Whether the text is raised as superscript (property bit 15)
+    pub fn is_superscript(&self) -> bool {
+        self.properties & (1 << 15) != 0
+    }
+
+    /// Whether the text is lowered as subscript (property bit 16)
+    pub fn is_subscript(&self) -> bool {
+        self.properties & (1 << 16) != 0
+    }
+}
+
 /// Paragraph shape information
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParaShape {
     pub properties1: u32,
-    pub left_margin: i32,
-    pub right_margin: i32,
-    pub indent: i32,
-    pub prev_spacing: i32,
-    pub next_spacing: i32,
+    pub left_margin: HwpUnit,
+    pub right_margin: HwpUnit,
+    pub indent: HwpUnit,
+    pub prev_spacing: HwpUnit,
+    pub next_spacing: HwpUnit,
+    /// Percent or HWPUNIT, depending on `line_spacing_type`
     pub line_spacing: i32,
     pub tab_def_id: u16,
     pub numbering_id: u16,
@@ -165,6 +686,104 @@ pub struct ParaShape {
     pub line_spacing_type: u32,
 }
 
+impl ParaShape {
+    /// Heading type (bits 23-24 of `properties1`): 0 none, 1 outline,
+    /// 2 numbering, 3 bullet
+    pub fn heading_type(&self) -> u8 {
+        ((self.properties1 >> 23) & 0x03) as u8
+    }
+
+    /// Outline or list level (bits 25-27 of `properties1`), 0 for the
+    /// outermost
+    pub fn level(&self) -> u8 {
+        ((self.properties1 >> 25) & 0x07) as u8
+    }
+
+    /// Decode `line_spacing` according to `line_spacing_type`
+    ///
+    /// Unknown spacing types fall back to percent, which is what the
+    /// default (type 0) paragraph shape uses.
+    pub fn line_spacing(&self) -> LineSpacing {
+        let value = HwpUnit(self.line_spacing);
+        match self.line_spacing_type {
+            1 => LineSpacing::Fixed(value),
+            2 => LineSpacing::BetweenLines(value),
+            3 => LineSpacing::AtLeast(value),
+            _ => LineSpacing::Percent(self.line_spacing.clamp(0, u16::MAX as i32) as u16),
+        }
+    }
+
+    /// Resolve the line height in points for text of the given size
+    pub fn line_spacing_pt(&self, font_size_pt: f32) -> f32 {
+        match self.line_spacing() {
+            LineSpacing::Percent(percent) => font_size_pt * percent as f32 / 100.0,
+            LineSpacing::Fixed(value) => value.to_pt(),
+            LineSpacing::BetweenLines(value) => font_size_pt + value.to_pt(),
+            LineSpacing::AtLeast(value) => value.to_pt().max(font_size_pt),
+        }
+    }
+}
+
+/// Length in HWPUNIT (1/7200 inch, so 100 units per point)
+///
+/// Values the format describes in 1/100 pt are the same unit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HwpUnit(pub i32);
+
+impl HwpUnit {
+    /// Units per inch
+    pub const PER_INCH: i32 = 7200;
+
+    /// Convert to points
+    pub fn to_pt(self) -> f32 {
+        self.0 as f32 / 100.0
+    }
+
+    /// Convert to inches
+    pub fn to_inch(self) -> f32 {
+        self.0 as f32 / Self::PER_INCH as f32
+    }
+
+    /// Convert to millimetres
+    pub fn to_mm(self) -> f32 {
+        self.to_inch() * 25.4
+    }
+}
+
+/// Line spacing mode of a paragraph shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineSpacing {
+    /// Percentage of the font size
+    Percent(u16),
+    /// Exact line height
+    Fixed(HwpUnit),
+    /// Gap added between lines (margin only)
+    BetweenLines(HwpUnit),
+    /// Minimum line height
+    AtLeast(HwpUnit),
+}
+
+/// Kind of list a paragraph shape puts its paragraphs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ListKind {
+    Numbered,
+    Bulleted,
+}
+
+/// A paragraph's place in a numbered or bulleted list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListItem {
+    pub kind: ListKind,
+    /// NUMBERING or BULLET id from the paragraph shape, counting from 1
+    pub id: u16,
+    /// Nesting level, 0 for the outermost
+    pub level: u8,
+}
+
 /// Style information
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -178,6 +797,60 @@ pub struct Style {
     pub char_shape_id: u16,
 }
 
+impl Style {
+    /// The style's local name, or its English name when that is empty
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.english_name
+        } else {
+            &self.name
+        }
+    }
+}
+
+/// Language groups that face names and character shapes are split into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontLanguage {
+    Korean = 0,
+    English = 1,
+    Hanja = 2,
+    Japanese = 3,
+    Other = 4,
+    Symbol = 5,
+    User = 6,
+}
+
+impl FontLanguage {
+    /// Number of font language groups
+    pub const COUNT: usize = 7;
+
+    /// All font language groups, in face name order
+    pub const ALL: [FontLanguage; FontLanguage::COUNT] = [
+        FontLanguage::Korean,
+        FontLanguage::English,
+        FontLanguage::Hanja,
+        FontLanguage::Japanese,
+        FontLanguage::Other,
+        FontLanguage::Symbol,
+        FontLanguage::User,
+    ];
+
+    /// Language group whose font HWP uses to draw a character
+    pub fn for_char(c: char) -> Self {
+        match c as u32 {
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7A3 => FontLanguage::Korean,
+            0x0000..=0x024F => FontLanguage::English,
+            0x2E80..=0x2FDF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => {
+                FontLanguage::Hanja
+            }
+            0x3040..=0x30FF => FontLanguage::Japanese,
+            0x2000..=0x2BFF => FontLanguage::Symbol,
+            _ => FontLanguage::Other,
+        }
+    }
+}
+
 /// Face name (font) information
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -190,7 +863,7 @@ pub struct FaceName {
     pub base_font_name: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FaceNameType {
     pub family: u8,
@@ -237,6 +910,21 @@ pub struct BinDataEntry {
     pub data: Vec<u8>,
 }
 
+/// Detect an image format from its magic bytes
+pub fn image_extension(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("jpg")
+    } else if data.starts_with(b"BM") {
+        Some("bmp")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("gif")
+    } else {
+        None
+    }
+}
+
 /// Tab definition
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -289,6 +977,17 @@ pub struct DistributeDocData {
     pub data: Vec<u8>,
 }
 
+/// Document variables decoded from a DOC_DATA parameter set
+///
+/// Keys are parameter item IDs in decimal; items of nested sets are keyed
+/// by the path of IDs to them, joined with `.`, and array elements by their
+/// index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocData {
+    pub variables: HashMap<String, String>,
+}
+
 /// Compatible document settings
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -26,6 +26,18 @@ pub struct HwpHeader {
 impl HwpHeader {
     pub const SIZE: usize = 256; // Total header size in bytes
 
+    /// Header for a new, uncompressed document of `version`
+    pub fn new(version: HwpVersion) -> Self {
+        let mut signature = [0u8; 32];
+        signature.copy_from_slice(crate::constants::HWP_SIGNATURE);
+        Self {
+            signature,
+            version,
+            properties: HwpProperties::from_u32(0),
+            reserved: [0; 216],
+        }
+    }
+
     /// Check if the document is compressed
     pub fn is_compressed(&self) -> bool {
         self.properties.compressed
@@ -4,7 +4,7 @@ pub mod paragraph;
 pub mod record;
 pub mod section;
 
-pub use document::HwpDocument;
+pub use document::{FontLanguage, HwpDocument, ParagraphRange, TextItem, ValidationWarning};
 pub use header::HwpHeader;
 pub use paragraph::Paragraph;
 pub use record::Record;
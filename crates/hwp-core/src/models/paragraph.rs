@@ -38,6 +38,39 @@ impl Paragraph {
     pub fn get_text(&self) -> String {
         self.text.clone()
     }
+
+    /// Get the text length in Unicode scalar values (what users count as characters)
+    pub fn char_len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Count characters the way HWP does for its document statistics
+    ///
+    /// Control characters such as tabs and line breaks and U+FFFC object
+    /// placeholders are not counted, and a Hangul syllable stored as
+    /// decomposed jamo counts once, like its precomposed form.
+    pub fn char_count(&self) -> usize {
+        self.text
+            .chars()
+            .filter(|&c| !c.is_control() && c != '\u{FFFC}' && !is_trailing_jamo(c))
+            .count()
+    }
+
+    /// Whether a hard page break comes before this paragraph
+    pub fn starts_new_page(&self) -> bool {
+        self.header.division_type & 0x04 != 0
+    }
+
+    /// Get the text length in UTF-8 bytes
+    pub fn byte_len(&self) -> usize {
+        self.text.len()
+    }
+}
+
+/// Whether a character is a conjoining vowel or final consonant, which
+/// joins the preceding initial consonant into one syllable
+fn is_trailing_jamo(c: char) -> bool {
+    matches!(c as u32, 0x1160..=0x11FF | 0xD7B0..=0xD7FF)
 }
 
 impl Default for Paragraph {
@@ -66,6 +99,8 @@ pub struct ParagraphHeader {
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharShapePos {
+    /// Index of the first character the shape applies to, counted in
+    /// Unicode scalar values of `Paragraph::text`
     pub position: u32,
     pub shape_id: u16,
 }
@@ -129,6 +164,7 @@ pub enum ExtendedControl {
     PageOddEvenAdjust,
     PageBreak,
     Field,
+    Hyperlink,
     Bookmark,
     IndexMark,
     HiddenComment,
@@ -153,10 +189,11 @@ impl ExtendedControl {
             Some(CtrlId::PageNumPos) => Self::PageNumPos,
             Some(CtrlId::PageHiding) => Self::PageHiding,
             Some(CtrlId::PageOddEvenAdjust) => Self::PageOddEvenAdjust,
-            Some(CtrlId::PageBreak) => Self::PageBreak,
             Some(CtrlId::Field) => Self::Field,
+            Some(CtrlId::Hyperlink) => Self::Hyperlink,
             Some(CtrlId::Bookmark) => Self::Bookmark,
             Some(CtrlId::IndexMark) => Self::IndexMark,
+            Some(CtrlId::HiddenComment) => Self::HiddenComment,
             _ => Self::Other(id),
         }
     }
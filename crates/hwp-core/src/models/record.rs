@@ -1,5 +1,6 @@
 /// Record structure for HWP tag-based format
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Record {
     /// Tag ID identifying the record type
     pub tag_id: u16,
@@ -30,6 +31,23 @@ impl Record {
         4 + self.size as usize // 4 bytes for header + data size
     }
 
+    /// Encode the record header and data as they appear in a stream
+    ///
+    /// The size is taken from the data; data of 4095 bytes or more gets the
+    /// extended size field.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = self.data.len() as u32;
+        let header =
+            (self.tag_id as u32 & 0x3FF) | ((self.level as u32) << 10) | (size.min(0xFFF) << 20);
+
+        let mut bytes = header.to_le_bytes().to_vec();
+        if size >= 0xFFF {
+            bytes.extend_from_slice(&size.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+
     /// Check if this is a DocInfo record
     pub fn is_doc_info(&self) -> bool {
         use crate::constants::tag_id::doc_info::*;
@@ -96,8 +114,37 @@ impl Record {
     }
 }
 
+/// A record together with the records nested under it
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordNode {
+    pub record: Record,
+    /// Records at deeper levels that follow this one, in stream order
+    pub children: Vec<RecordNode>,
+}
+
+impl RecordNode {
+    /// Create a node with no children
+    pub fn new(record: Record) -> Self {
+        Self {
+            record,
+            children: Vec::new(),
+        }
+    }
+
+    /// Visit this node and every descendant, depth first
+    pub fn walk(&self) -> Vec<&RecordNode> {
+        let mut nodes = vec![self];
+        for child in &self.children {
+            nodes.extend(child.walk());
+        }
+        nodes
+    }
+}
+
 /// Record header for parsing
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecordHeader {
     /// Tag ID (10 bits) + Level (2 bits) + Size (20 bits) packed in 32 bits
     pub value: u32,
@@ -1,4 +1,17 @@
+use crate::constants::ctrl_id::CtrlId;
+use crate::models::document::{is_cjk, is_hangul, DocInfo, HwpUnit};
 use crate::models::Paragraph;
+use crate::HwpError;
+
+/// Marks the start and end of a table in [`Section::marked_text`]
+pub const TABLE_SEPARATOR: char = '\u{1E}';
+/// Separates the rows of a table in [`Section::marked_text`]
+pub const ROW_SEPARATOR: char = '\u{1D}';
+/// Separates the cells of a row in [`Section::marked_text`]
+pub const CELL_SEPARATOR: char = '\u{1F}';
+
+/// Line segment flag set on the first line of a page
+const FIRST_LINE_OF_PAGE: u32 = 0x01;
 
 /// Section structure representing a document section
 #[derive(Debug)]
@@ -10,12 +23,44 @@ pub struct Section {
     /// Paragraphs in this section
     pub paragraphs: Vec<Paragraph>,
 
-    /// Page definitions
+    /// Tables in this section, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tables: Vec<Table>,
+
+    /// Equations in this section, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub equations: Vec<Equation>,
+
+    /// Footnotes and endnotes in this section, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub footnotes: Vec<Note>,
+
+    /// Headers and footers defined in this section, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub headers_footers: Vec<HeaderFooter>,
+
+    /// Hyperlinks in the section's paragraphs, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hyperlinks: Vec<Hyperlink>,
+
+    /// Pictures anchored in the section's paragraphs, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pictures: Vec<Picture>,
+
+    /// Headers of the section's control objects, in document order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub control_headers: Vec<ControlHeader>,
+
+    /// Page definitions; see [`Section::page_def`]
     pub page_defs: Vec<PageDef>,
 
     /// Footnote shape
     pub footnote_shape: Option<FootnoteShape>,
 
+    /// Endnote shape
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub endnote_shape: Option<FootnoteShape>,
+
     /// Page border fill
     pub page_border_fill: Option<PageBorderFill>,
 }
@@ -26,17 +71,135 @@ impl Section {
         Self {
             definition: SectionDefinition::default(),
             paragraphs: Vec::new(),
+            tables: Vec::new(),
+            equations: Vec::new(),
+            footnotes: Vec::new(),
+            headers_footers: Vec::new(),
+            hyperlinks: Vec::new(),
+            pictures: Vec::new(),
+            control_headers: Vec::new(),
             page_defs: Vec::new(),
             footnote_shape: None,
+            endnote_shape: None,
             page_border_fill: None,
         }
     }
 
-    /// Get the page count for this section
+    /// The section's page definition, or an A4 portrait default when the
+    /// section has no PAGE_DEF record
+    pub fn page_def(&self) -> PageDef {
+        self.page_defs.first().cloned().unwrap_or_default()
+    }
+
+    /// Get the page count for this section, estimating with default
+    /// shapes if needed
+    ///
+    /// See [`Section::page_count_with`].
     pub fn page_count(&self) -> usize {
-        // Simple estimation based on content
-        // In real implementation, this would calculate based on layout
-        1
+        self.page_count_with(&DocInfo::default())
+    }
+
+    /// Get the page count for this section
+    ///
+    /// HWP stores the layout it last computed as line segments, so when
+    /// the paragraphs have them the pages are counted from the lines that
+    /// start one. Otherwise the layout is estimated within the body area
+    /// of [`Section::page_def`]: each paragraph is filled with full-width
+    /// Hangul and CJK characters and half-width others at the size of its
+    /// first character shape, with its paragraph shape's margins, line
+    /// spacing and paragraph spacing. Tables and pictures are not measured.
+    /// Hard page breaks always start a page, and a section has at least one.
+    pub fn page_count_with(&self, doc_info: &DocInfo) -> usize {
+        if self.paragraphs.iter().any(|p| !p.line_segments.is_empty()) {
+            self.laid_out_page_count()
+        } else {
+            self.estimated_page_count(doc_info)
+        }
+    }
+
+    fn laid_out_page_count(&self) -> usize {
+        let mut pages = 0;
+        for paragraph in &self.paragraphs {
+            let mut segments = paragraph.line_segments.iter();
+            let first_on_page = segments
+                .next()
+                .is_some_and(|s| s.flags & FIRST_LINE_OF_PAGE != 0);
+            if pages == 0 || first_on_page || paragraph.starts_new_page() {
+                pages += 1;
+            }
+            pages += segments
+                .filter(|s| s.flags & FIRST_LINE_OF_PAGE != 0)
+                .count();
+        }
+        pages.max(1)
+    }
+
+    fn estimated_page_count(&self, doc_info: &DocInfo) -> usize {
+        let (body_width, body_height) = self.page_def().body_size();
+        let body_height = body_height.0.max(1) as f32;
+
+        let mut pages = 1;
+        let mut used = 0.0;
+        for (index, paragraph) in self.paragraphs.iter().enumerate() {
+            if index > 0 && paragraph.starts_new_page() {
+                pages += 1;
+                used = 0.0;
+            }
+
+            let shape = doc_info
+                .para_shapes
+                .get(paragraph.header.para_shape_id as usize);
+            let font_size = paragraph
+                .char_shapes
+                .first()
+                .and_then(|run| doc_info.char_shapes.get(run.shape_id as usize))
+                .map_or(1000.0, |shape| shape.base_size.max(1) as f32);
+            let (line_height, width, before, after) = match shape {
+                Some(shape) => (
+                    shape.line_spacing_pt(font_size / 100.0) * 100.0,
+                    inner_length(body_width, &[shape.left_margin, shape.right_margin]).0,
+                    shape.prev_spacing.0 as f32,
+                    shape.next_spacing.0 as f32,
+                ),
+                None => (font_size * 1.6, body_width.0, 0.0, 0.0),
+            };
+            let line_height = line_height.max(1.0);
+            let width = (width as f32).max(font_size);
+
+            let text_width: f32 = paragraph
+                .text
+                .chars()
+                .filter(|c| !c.is_control() && *c != '\u{FFFC}')
+                .map(|c| {
+                    if is_hangul(c) || is_cjk(c) {
+                        font_size
+                    } else {
+                        font_size / 2.0
+                    }
+                })
+                .sum();
+            let lines = ((text_width / width).ceil() as usize).max(1);
+
+            used += before;
+            for _ in 0..lines {
+                if used > 0.0 && used + line_height > body_height {
+                    pages += 1;
+                    used = 0.0;
+                }
+                used += line_height;
+            }
+            used += after;
+        }
+        pages
+    }
+
+    /// Count characters the way HWP does, including table cell text
+    pub fn char_count(&self) -> usize {
+        self.paragraphs
+            .iter()
+            .map(|p| p.char_count())
+            .sum::<usize>()
+            + self.tables.iter().map(|t| t.char_count()).sum::<usize>()
     }
 
     /// Get all text content from the section
@@ -48,6 +211,180 @@ impl Section {
         }
         text
     }
+
+    /// Get the text content with tables rendered inline as ASCII grids
+    ///
+    /// Each table is drawn right after the paragraph that anchors it, and
+    /// note bodies follow the section text.
+    pub fn text_with_tables(&self) -> String {
+        let mut text = self.body_text();
+        text.push_str(&self.notes_text());
+        text
+    }
+
+    /// Get the text content in the given part order
+    ///
+    /// Parts are separated by a blank line; empty parts are left out. The
+    /// body keeps its note markers whether or not the notes are included.
+    pub fn get_text_ordered(&self, order: &[SectionPart]) -> String {
+        let parts: Vec<String> = order
+            .iter()
+            .map(|part| match part {
+                SectionPart::Body => self.body_text(),
+                SectionPart::Notes => self.notes_text(),
+                SectionPart::HeadersFooters => self.headers_footers_text(),
+            })
+            .map(|text| text.trim_matches('\n').to_string())
+            .filter(|text| !text.is_empty())
+            .collect();
+
+        if parts.is_empty() {
+            return String::new();
+        }
+        let mut text = parts.join("\n\n");
+        text.push('\n');
+        text
+    }
+
+    /// Paragraph text with note markers and tables drawn after their anchors
+    pub fn body_text(&self) -> String {
+        let mut text = String::new();
+
+        for item in self.body_items() {
+            match item {
+                BodyItem::Paragraph(index, paragraph) => {
+                    if !paragraph.text.is_empty() {
+                        text.push_str(&self.with_note_markers(index, &paragraph.text));
+                        text.push('\n');
+                    }
+                }
+                BodyItem::Table(table) => text.push_str(&table.render_ascii()),
+            }
+        }
+
+        text
+    }
+
+    /// Iterate over the paragraphs and tables in reading order
+    ///
+    /// Each table follows the paragraph that anchors it; tables anchored
+    /// past the last paragraph come at the end.
+    pub fn body_items(&self) -> BodyItems<'_> {
+        BodyItems {
+            paragraphs: self.paragraphs.iter().enumerate(),
+            tables: self.tables.iter().peekable(),
+            last_index: None,
+        }
+    }
+
+    /// Paragraph text with tables delimited by ASCII separator characters
+    ///
+    /// Each table follows the paragraph that anchors it, enclosed in a pair
+    /// of [`TABLE_SEPARATOR`]s, so splitting the text on that character
+    /// alternates between prose and tables. Within a table, rows are
+    /// separated by [`ROW_SEPARATOR`] and cells by [`CELL_SEPARATOR`].
+    pub fn marked_text(&self) -> String {
+        let mut text = String::new();
+
+        for item in self.body_items() {
+            match item {
+                BodyItem::Paragraph(_, paragraph) => {
+                    if !paragraph.text.is_empty() {
+                        text.push_str(&paragraph.text);
+                        text.push('\n');
+                    }
+                }
+                BodyItem::Table(table) => text.push_str(&table.to_marked()),
+            }
+        }
+
+        text
+    }
+
+    /// Insert `[n]` markers for the notes referenced from a paragraph
+    ///
+    /// `text` is the paragraph's text; markers go at the character
+    /// positions where the note controls sit.
+    pub fn with_note_markers(&self, paragraph_index: usize, text: &str) -> String {
+        let mut notes = self
+            .footnotes
+            .iter()
+            .filter(|n| n.paragraph_index == paragraph_index)
+            .peekable();
+        if notes.peek().is_none() {
+            return text.to_string();
+        }
+
+        let mut result = String::with_capacity(text.len() + 8);
+        for (position, ch) in text.chars().enumerate() {
+            while let Some(note) = notes.next_if(|n| n.position <= position) {
+                result.push_str(&note.marker());
+            }
+            result.push(ch);
+        }
+        for note in notes {
+            result.push_str(&note.marker());
+        }
+        result
+    }
+
+    /// Hyperlinks in a paragraph that can be rendered as links, by position
+    ///
+    /// Links are ordered by where they start. A link that starts inside an
+    /// earlier one (a nested or overlapping field) is left out, so the
+    /// links returned never overlap.
+    pub fn paragraph_links(&self, paragraph_index: usize) -> Vec<&Hyperlink> {
+        let mut links: Vec<&Hyperlink> = self
+            .hyperlinks
+            .iter()
+            .filter(|l| l.para_index == paragraph_index)
+            .collect();
+        links.sort_by_key(|l| l.char_offset);
+
+        let mut end = 0;
+        links.retain(|link| {
+            if link.char_offset < end {
+                return false;
+            }
+            end = link.end();
+            true
+        });
+        links
+    }
+
+    /// Render the note bodies, one note per line, for the end of the section
+    ///
+    /// Empty when the section has no notes; otherwise starts with a blank
+    /// line separating the notes from the body text.
+    pub fn notes_text(&self) -> String {
+        let mut text = String::new();
+        for note in &self.footnotes {
+            if text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&note.marker());
+            text.push(' ');
+            text.push_str(&note.text());
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Render headers and footers, one per line, labelled by kind
+    pub fn headers_footers_text(&self) -> String {
+        let mut text = String::new();
+        for header_footer in &self.headers_footers {
+            let body = header_footer.text();
+            if body.is_empty() {
+                continue;
+            }
+            text.push_str(header_footer.kind.label());
+            text.push_str(": ");
+            text.push_str(&body);
+            text.push('\n');
+        }
+        text
+    }
 }
 
 impl Default for Section {
@@ -56,6 +393,43 @@ impl Default for Section {
     }
 }
 
+/// A paragraph or table of a section body
+#[derive(Debug, Clone, Copy)]
+pub enum BodyItem<'a> {
+    /// A paragraph with its index in [`Section::paragraphs`]
+    Paragraph(usize, &'a Paragraph),
+    /// A table, following the paragraph that anchors it
+    Table(&'a Table),
+}
+
+/// Iterator over a section's paragraphs and tables, from [`Section::body_items`]
+#[derive(Debug, Clone)]
+pub struct BodyItems<'a> {
+    paragraphs: std::iter::Enumerate<std::slice::Iter<'a, Paragraph>>,
+    tables: std::iter::Peekable<std::slice::Iter<'a, Table>>,
+    last_index: Option<usize>,
+}
+
+impl<'a> Iterator for BodyItems<'a> {
+    type Item = BodyItem<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(last_index) = self.last_index {
+            if let Some(table) = self.tables.next_if(|t| t.paragraph_index <= last_index) {
+                return Some(BodyItem::Table(table));
+            }
+        }
+        match self.paragraphs.next() {
+            Some((index, paragraph)) => {
+                self.last_index = Some(index);
+                Some(BodyItem::Paragraph(index, paragraph))
+            }
+            // Tables anchored past the last paragraph go at the end
+            None => self.tables.next().map(BodyItem::Table),
+        }
+    }
+}
+
 /// Section definition information
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -80,43 +454,115 @@ pub struct SectionDefinition {
     pub header_footer_different_odd_even: bool,
 }
 
-/// Page definition
-#[derive(Debug)]
+/// Page definition: paper size and margins
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageDef {
-    pub width: u32,
-    pub height: u32,
-    pub padding_left: u32,
-    pub padding_right: u32,
-    pub padding_top: u32,
-    pub padding_bottom: u32,
-    pub header_padding: u32,
-    pub footer_padding: u32,
-    pub gutter_padding: u32,
+    pub width: HwpUnit,
+    pub height: HwpUnit,
+    pub margin_left: HwpUnit,
+    pub margin_right: HwpUnit,
+    pub margin_top: HwpUnit,
+    pub margin_bottom: HwpUnit,
+    pub header_margin: HwpUnit,
+    pub footer_margin: HwpUnit,
+    pub gutter_margin: HwpUnit,
     pub properties: u32,
+    pub orientation: PageOrientation,
     pub footnote_shape_id: u16,
 }
 
+impl PageDef {
+    /// Width and height of the area between the margins that body text
+    /// fills, in the page's orientation
+    ///
+    /// Margins that add up to more than the page leave a size of 0.
+    pub fn body_size(&self) -> (HwpUnit, HwpUnit) {
+        let (width, height) = self.oriented_size();
+        (
+            inner_length(
+                width,
+                &[self.margin_left, self.margin_right, self.gutter_margin],
+            ),
+            inner_length(
+                height,
+                &[
+                    self.margin_top,
+                    self.margin_bottom,
+                    self.header_margin,
+                    self.footer_margin,
+                ],
+            ),
+        )
+    }
+
+    /// Width and height of the page as laid out, swapped for landscape
+    pub fn oriented_size(&self) -> (HwpUnit, HwpUnit) {
+        match self.orientation {
+            PageOrientation::Portrait => (self.width, self.height),
+            PageOrientation::Landscape => (self.height, self.width),
+        }
+    }
+
+    /// Name of the standard paper size within 1mm of the page's, such as
+    /// `"A4"`
+    pub fn paper_name(&self) -> Option<&'static str> {
+        const PAPERS: [(&str, f32, f32); 7] = [
+            ("A3", 297.0, 420.0),
+            ("A4", 210.0, 297.0),
+            ("A5", 148.0, 210.0),
+            ("B4", 257.0, 364.0),
+            ("B5", 182.0, 257.0),
+            ("Letter", 215.9, 279.4),
+            ("Legal", 215.9, 355.6),
+        ];
+
+        let (width, height) = (self.width.to_mm(), self.height.to_mm());
+        PAPERS
+            .iter()
+            .find(|(_, w, h)| (width - w).abs() <= 1.0 && (height - h).abs() <= 1.0)
+            .map(|(name, _, _)| *name)
+    }
+}
+
+/// `length` less `margins`, clamped to `0..=i32::MAX`
+fn inner_length(length: HwpUnit, margins: &[HwpUnit]) -> HwpUnit {
+    let inner = margins
+        .iter()
+        .fold(length.0 as i64, |inner, margin| inner - margin.0 as i64);
+    HwpUnit(inner.clamp(0, i32::MAX as i64) as i32)
+}
+
 impl Default for PageDef {
     fn default() -> Self {
-        // A4 size defaults (210mm x 297mm in HWPUNIT)
-        // 1mm = 7200 HWPUNIT
+        // A4 portrait with Hangul's default margins
+        // 1mm = 7200 / 25.4 ≈ 283.46 HWPUNIT
         Self {
-            width: 59528,  // 210mm * 283.465 (approximately)
-            height: 84188, // 297mm * 283.465 (approximately)
-            padding_left: 8504,
-            padding_right: 8504,
-            padding_top: 5668,
-            padding_bottom: 4252,
-            header_padding: 4252,
-            footer_padding: 4252,
-            gutter_padding: 0,
+            width: HwpUnit(59528),        // 210mm
+            height: HwpUnit(84188),       // 297mm
+            margin_left: HwpUnit(8504),   // 30mm
+            margin_right: HwpUnit(8504),  // 30mm
+            margin_top: HwpUnit(5668),    // 20mm
+            margin_bottom: HwpUnit(4252), // 15mm
+            header_margin: HwpUnit(4252),
+            footer_margin: HwpUnit(4252),
+            gutter_margin: HwpUnit(0),
             properties: 0,
+            orientation: PageOrientation::Portrait,
             footnote_shape_id: 0,
         }
     }
 }
 
+/// Paper orientation (PAGE_DEF property bit 0)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
 /// Footnote shape
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -136,6 +582,91 @@ pub struct FootnoteShape {
     pub divider_color: u32,
 }
 
+impl FootnoteShape {
+    /// Decode the numbering and placement settings packed into `properties`
+    pub fn config(&self) -> FootnoteConfig {
+        let props = self.properties;
+        FootnoteConfig {
+            number_format: NumberFormat::from((props & 0xFF) as u8),
+            placement: ((props >> 8) & 0x03) as u8,
+            restart: match (props >> 10) & 0x03 {
+                1 => FootnoteRestart::PerSection,
+                2 => FootnoteRestart::PerPage,
+                _ => FootnoteRestart::Continuous,
+            },
+            superscript: props & (1 << 12) != 0,
+            starting_number: self.starting_number,
+        }
+    }
+}
+
+/// Footnote or endnote display settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FootnoteConfig {
+    pub number_format: NumberFormat,
+    /// Where notes are placed; its meaning differs for footnotes and endnotes
+    pub placement: u8,
+    pub restart: FootnoteRestart,
+    /// Whether note numbers are drawn as superscript
+    pub superscript: bool,
+    pub starting_number: u16,
+}
+
+/// When note numbering starts over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FootnoteRestart {
+    Continuous,
+    PerSection,
+    PerPage,
+}
+
+/// Number shape used for note, page and outline numbering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberFormat {
+    /// 1, 2, 3
+    Digit,
+    /// ①, ②, ③
+    CircledDigit,
+    /// I, II, III
+    UpperRoman,
+    /// i, ii, iii
+    LowerRoman,
+    /// A, B, C
+    UpperLatin,
+    /// a, b, c
+    LowerLatin,
+    /// 가, 나, 다
+    HangulSyllable,
+    /// ㄱ, ㄴ, ㄷ
+    HangulJamo,
+    /// 一, 二, 三
+    HanjaDigit,
+    /// The user-defined symbol
+    UserSymbol,
+    Other(u8),
+}
+
+impl From<u8> for NumberFormat {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => NumberFormat::Digit,
+            1 => NumberFormat::CircledDigit,
+            2 => NumberFormat::UpperRoman,
+            3 => NumberFormat::LowerRoman,
+            4 => NumberFormat::UpperLatin,
+            5 => NumberFormat::LowerLatin,
+            8 => NumberFormat::HangulSyllable,
+            10 => NumberFormat::HangulJamo,
+            13 => NumberFormat::HanjaDigit,
+            0x81 => NumberFormat::UserSymbol,
+            other => NumberFormat::Other(other),
+        }
+    }
+}
+
 /// Page border fill
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -151,3 +682,515 @@ pub struct PageBorderFill {
     pub offset_bottom: i16,
     pub border_fill_id: u16,
 }
+
+/// Table control within a section
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Table {
+    /// Index of the paragraph that anchors this table
+    pub paragraph_index: usize,
+    pub rows: u16,
+    pub cols: u16,
+    pub cells: Vec<TableCell>,
+}
+
+/// Single cell of a table
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableCell {
+    pub row: u16,
+    pub col: u16,
+    pub row_span: u16,
+    pub col_span: u16,
+    pub paragraphs: Vec<Paragraph>,
+
+    /// Tables nested inside this cell, anchored to its paragraphs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tables: Vec<Table>,
+}
+
+impl TableCell {
+    /// Create an empty cell at the given position
+    pub fn new(row: u16, col: u16) -> Self {
+        Self {
+            row,
+            col,
+            row_span: 1,
+            col_span: 1,
+            paragraphs: Vec::new(),
+            tables: Vec::new(),
+        }
+    }
+
+    /// Count characters the way HWP does, including nested tables
+    pub fn char_count(&self) -> usize {
+        self.paragraphs
+            .iter()
+            .map(|p| p.char_count())
+            .sum::<usize>()
+            + self.tables.iter().map(|t| t.char_count()).sum::<usize>()
+    }
+
+    /// Get the cell text, one line per paragraph
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Table {
+    /// Count characters the way HWP does across every cell
+    pub fn char_count(&self) -> usize {
+        self.cells.iter().map(|c| c.char_count()).sum()
+    }
+
+    /// Find the cell whose top-left corner is at the given position
+    pub fn cell(&self, row: u16, col: u16) -> Option<&TableCell> {
+        self.cells.iter().find(|c| c.row == row && c.col == col)
+    }
+
+    /// Render the table as tab-separated rows
+    ///
+    /// Merged cells put their text in the top-left position and leave the
+    /// positions they cover empty. Tabs and line breaks inside a cell are
+    /// replaced with spaces so every row stays on one line.
+    pub fn to_tsv(&self) -> String {
+        let mut out = String::new();
+        for row in self.text_grid() {
+            out.push_str(&row.join("\t"));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Render the table for [`Section::marked_text`]
+    ///
+    /// Separator characters inside a cell are replaced with spaces, as tabs
+    /// and line breaks are.
+    pub fn to_marked(&self) -> String {
+        let rows: Vec<String> = self
+            .text_grid()
+            .into_iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|cell| cell.replace([TABLE_SEPARATOR, ROW_SEPARATOR, CELL_SEPARATOR], " "))
+                    .collect();
+                cells.join(&CELL_SEPARATOR.to_string())
+            })
+            .collect();
+
+        format!(
+            "{}{}{}",
+            TABLE_SEPARATOR,
+            rows.join(&ROW_SEPARATOR.to_string()),
+            TABLE_SEPARATOR
+        )
+    }
+
+    /// Cell text laid out by position, flattened to a single line per cell
+    ///
+    /// Merged cells put their text in the top-left position and leave the
+    /// positions they cover empty. The grid reaches as far as the cells do,
+    /// within the row and column counts of the table header; rows and
+    /// columns no cell covers are left out at the end.
+    pub fn text_grid(&self) -> Vec<Vec<String>> {
+        let extent = |position: u16, span: u16, limit: u16| {
+            (position as usize + span.max(1) as usize).min(limit as usize)
+        };
+        let (rows, cols) = self.cells.iter().fold((0, 0), |(rows, cols), cell| {
+            (
+                rows.max(extent(cell.row, cell.row_span, self.rows)),
+                cols.max(extent(cell.col, cell.col_span, self.cols)),
+            )
+        });
+
+        let mut grid = vec![vec![String::new(); cols]; rows];
+        for cell in &self.cells {
+            if let Some(slot) = grid
+                .get_mut(cell.row as usize)
+                .and_then(|r| r.get_mut(cell.col as usize))
+            {
+                *slot = cell.text().replace(['\n', '\t'], " ");
+            }
+        }
+        grid
+    }
+
+    /// Render the table as a fixed-width ASCII grid
+    ///
+    /// Column widths fit the widest cell in each column. Merged cells show
+    /// their text in the top-left position; covered positions stay blank.
+    pub fn render_ascii(&self) -> String {
+        // Multi-line cell text is flattened so every row stays one line tall
+        let grid = self.text_grid();
+        let cols = grid.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return String::new();
+        }
+
+        let widths: Vec<usize> = (0..cols)
+            .map(|c| grid.iter().map(|r| display_width(&r[c])).max().unwrap_or(0))
+            .collect();
+
+        let border = widths.iter().fold(String::from("+"), |mut line, w| {
+            line.push_str(&"-".repeat(w + 2));
+            line.push('+');
+            line
+        });
+
+        let mut out = String::new();
+        out.push_str(&border);
+        out.push('\n');
+        for row in &grid {
+            out.push('|');
+            for (text, width) in row.iter().zip(&widths) {
+                out.push(' ');
+                out.push_str(text);
+                out.push_str(&" ".repeat(width - display_width(text) + 1));
+                out.push('|');
+            }
+            out.push('\n');
+            out.push_str(&border);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Equation control within a section
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equation {
+    /// Index of the paragraph that anchors this equation
+    pub paragraph_index: usize,
+    /// Undecoded EQEDIT record data
+    pub raw_data: Vec<u8>,
+    /// Equation script as written in the equation editor (e.g. `{a+b} over 2`)
+    pub text_representation: String,
+}
+
+/// Whether a note is printed at the foot of the page or the end of the document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteKind {
+    #[default]
+    Footnote,
+    Endnote,
+}
+
+/// Hyperlink field within a section
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hyperlink {
+    /// Display text the link covers
+    pub text: String,
+    /// Link target
+    pub url: String,
+    /// Index of the paragraph holding the link
+    pub para_index: usize,
+    /// Character position of the display text within that paragraph
+    pub char_offset: usize,
+}
+
+impl Hyperlink {
+    /// Character position just past the display text
+    pub fn end(&self) -> usize {
+        self.char_offset + self.text.chars().count()
+    }
+}
+
+/// Common header of a control object, from its CTRL_HEADER record
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlHeader {
+    /// Four-character control ID, such as `tbl ` or `%hlk`
+    pub ctrl_id: u32,
+    /// Control-specific property bits; 0 when the record has none
+    pub properties: u32,
+    /// Index of the paragraph that anchors this control
+    pub paragraph_index: usize,
+}
+
+impl ControlHeader {
+    /// The control's kind, if it is one this crate knows
+    pub fn kind(&self) -> Option<CtrlId> {
+        CtrlId::from_u32(self.ctrl_id)
+    }
+
+    /// Whether the control is a field, whose IDs all start with `%`
+    pub fn is_field(&self) -> bool {
+        self.ctrl_id >> 24 == b'%' as u32
+    }
+
+    /// The control ID as its four characters
+    pub fn name(&self) -> String {
+        self.ctrl_id
+            .to_be_bytes()
+            .iter()
+            .map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '?'
+                }
+            })
+            .collect()
+    }
+}
+
+/// Picture drawing object within a section
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Picture {
+    /// Index of the paragraph that anchors this picture
+    pub paragraph_index: usize,
+    /// ID of the BIN_DATA entry holding the image, matching
+    /// [`BinDataEntry::id`](crate::models::document::BinDataEntry::id)
+    pub bin_data_id: u16,
+    /// Horizontal offset from the position the object is anchored to
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x: HwpUnit,
+    /// Vertical offset from the position the object is anchored to
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y: HwpUnit,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub width: HwpUnit,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: HwpUnit,
+    /// Part of the image that is shown; recorded as stored, not applied
+    /// to the image data
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub crop: PictureCrop,
+    /// Caption text, paragraphs separated by spaces
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub caption: Option<String>,
+}
+
+/// Crop rectangle of a picture, in the image's own coordinates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PictureCrop {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+/// Footnote or endnote within a section
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Note {
+    pub kind: NoteKind,
+    /// Note number as shown at the reference point
+    pub number: u16,
+    /// Index of the paragraph that references this note
+    pub paragraph_index: usize,
+    /// Character position of the reference within that paragraph
+    pub position: usize,
+    /// Note body
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl Note {
+    /// Reference marker, e.g. `[1]`
+    pub fn marker(&self) -> String {
+        format!("[{}]", self.number)
+    }
+
+    /// Note body text, paragraphs separated by spaces
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| p.text.as_str())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Whether a header/footer control is a header or a footer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeaderFooterKind {
+    #[default]
+    Header,
+    Footer,
+}
+
+impl HeaderFooterKind {
+    /// Label used when rendering the text, e.g. `Header`
+    pub fn label(&self) -> &'static str {
+        match self {
+            HeaderFooterKind::Header => "Header",
+            HeaderFooterKind::Footer => "Footer",
+        }
+    }
+}
+
+/// Header or footer within a section
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeaderFooter {
+    pub kind: HeaderFooterKind,
+    /// Header or footer body
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl HeaderFooter {
+    /// Body text, paragraphs separated by spaces
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| p.text.as_str())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// A part of a section's text, for choosing what extraction emits and in
+/// which order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SectionPart {
+    /// Paragraphs and tables
+    Body,
+    /// Footnote and endnote bodies
+    Notes,
+    /// Header and footer text
+    HeadersFooters,
+}
+
+impl SectionPart {
+    /// The order plain text extraction uses by default
+    pub const DEFAULT_ORDER: [SectionPart; 2] = [SectionPart::Body, SectionPart::Notes];
+}
+
+impl std::str::FromStr for SectionPart {
+    type Err = HwpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "body" => Ok(SectionPart::Body),
+            "notes" | "footnotes" => Ok(SectionPart::Notes),
+            "headers" | "footers" | "headers-footers" => Ok(SectionPart::HeadersFooters),
+            _ => Err(HwpError::InvalidFormat {
+                reason: format!(
+                    "Unknown section part '{}' (expected body, notes or headers)",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+impl Equation {
+    /// Plain-text approximation of the equation script
+    ///
+    /// Grouping braces are dropped and common keywords become symbols, so
+    /// `{a+b} over 2 times pi` reads as `a+b / 2 × π`. Keywords the
+    /// approximation does not know are kept as written.
+    pub fn plain_text(&self) -> String {
+        let mut out = String::new();
+        let mut chars = self.text_representation.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c.is_ascii_alphabetic() {
+                let mut word = c.to_string();
+                while let Some(next) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                    word.push(next);
+                }
+                out.push_str(equation_symbol(&word).unwrap_or(&word));
+            } else if c.is_whitespace() || c == '~' || c == '`' {
+                if !out.ends_with(' ') {
+                    out.push(' ');
+                }
+            } else if c != '{' && c != '}' {
+                out.push(c);
+            }
+        }
+
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Symbol for an equation script keyword; an empty string drops the keyword
+fn equation_symbol(word: &str) -> Option<&'static str> {
+    const GREEK: [(&str, &str, &str); 24] = [
+        ("alpha", "α", "Α"),
+        ("beta", "β", "Β"),
+        ("gamma", "γ", "Γ"),
+        ("delta", "δ", "Δ"),
+        ("epsilon", "ε", "Ε"),
+        ("zeta", "ζ", "Ζ"),
+        ("eta", "η", "Η"),
+        ("theta", "θ", "Θ"),
+        ("iota", "ι", "Ι"),
+        ("kappa", "κ", "Κ"),
+        ("lambda", "λ", "Λ"),
+        ("mu", "μ", "Μ"),
+        ("nu", "ν", "Ν"),
+        ("xi", "ξ", "Ξ"),
+        ("omicron", "ο", "Ο"),
+        ("pi", "π", "Π"),
+        ("rho", "ρ", "Ρ"),
+        ("sigma", "σ", "Σ"),
+        ("tau", "τ", "Τ"),
+        ("upsilon", "υ", "Υ"),
+        ("phi", "φ", "Φ"),
+        ("chi", "χ", "Χ"),
+        ("psi", "ψ", "Ψ"),
+        ("omega", "ω", "Ω"),
+    ];
+
+    // Upper-case Greek names select capital letters
+    for (name, lower, upper) in GREEK {
+        if word == name {
+            return Some(lower);
+        }
+        if word.eq_ignore_ascii_case(name) && word.chars().all(|c| c.is_ascii_uppercase()) {
+            return Some(upper);
+        }
+    }
+
+    let symbol = match word.to_ascii_lowercase().as_str() {
+        "over" => "/",
+        "sqrt" => "√",
+        "times" => "×",
+        "div" => "÷",
+        "cdot" => "·",
+        "pm" => "±",
+        "mp" => "∓",
+        "le" | "leq" => "≤",
+        "ge" | "geq" => "≥",
+        "ne" | "neq" => "≠",
+        "approx" => "≈",
+        "inf" | "infinity" => "∞",
+        "sum" => "∑",
+        "prod" => "∏",
+        "int" => "∫",
+        "partial" => "∂",
+        "left" | "right" | "rm" | "it" | "bold" => "",
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+/// Terminal column width of a string, counting Hangul and CJK as two columns
+pub fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
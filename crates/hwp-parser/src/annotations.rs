@@ -0,0 +1,102 @@
+use hwp_core::HwpDocument;
+use serde::{Deserialize, Serialize};
+
+/// Kind of reviewer-facing annotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationKind {
+    Memo,
+    TrackChange,
+    RangeTag,
+}
+
+/// A memo, tracked change or range-tag comment found in a document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub kind: AnnotationKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paragraph: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchored_text: Option<String>,
+}
+
+impl Annotation {
+    fn new(kind: AnnotationKind) -> Self {
+        Self {
+            kind,
+            id: None,
+            author: None,
+            timestamp: None,
+            section: None,
+            paragraph: None,
+            anchored_text: None,
+        }
+    }
+}
+
+/// Collect memos, tracked changes and range tags into a single list
+///
+/// Memos and tracked changes come from DocInfo and carry no anchor; range
+/// tags are anchored to the text span they cover within their paragraph.
+pub fn collect_annotations(document: &HwpDocument) -> Vec<Annotation> {
+    let doc_info = &document.doc_info;
+    let mut annotations = Vec::new();
+
+    for memo in &doc_info.memo_shapes {
+        annotations.push(Annotation {
+            id: Some(memo.memo_id),
+            ..Annotation::new(AnnotationKind::Memo)
+        });
+    }
+
+    for change in &doc_info.track_changes {
+        let author = doc_info
+            .track_change_authors
+            .iter()
+            .find(|a| a.id == change.author_id)
+            .map(|a| a.name.clone());
+
+        annotations.push(Annotation {
+            author,
+            timestamp: Some(change.timestamp),
+            ..Annotation::new(AnnotationKind::TrackChange)
+        });
+    }
+
+    for (section_idx, section) in document.sections.iter().enumerate() {
+        for (para_idx, paragraph) in section.paragraphs.iter().enumerate() {
+            for tag in &paragraph.range_tags {
+                // Upper 8 bits are the tag type, lower 24 bits the tag ID
+                let id = tag
+                    .tag_data
+                    .get(..4)
+                    .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) & 0x00FF_FFFF);
+
+                let anchored_text: String = paragraph
+                    .text
+                    .chars()
+                    .skip(tag.start as usize)
+                    .take(tag.end.saturating_sub(tag.start) as usize)
+                    .collect();
+
+                annotations.push(Annotation {
+                    id,
+                    section: Some(section_idx),
+                    paragraph: Some(para_idx),
+                    anchored_text: Some(anchored_text),
+                    ..Annotation::new(AnnotationKind::RangeTag)
+                });
+            }
+        }
+    }
+
+    annotations
+}
@@ -0,0 +1,162 @@
+use crate::reader::ByteReader;
+pub use hwp_core::models::document::image_extension;
+use hwp_core::models::document::BinDataEntry;
+use hwp_core::{HwpDocument, HwpError, Result};
+
+/// Compression setting stored on a BIN_DATA entry
+const COMPRESSION_DEFAULT: u8 = 0;
+const COMPRESSION_DEFLATE: u8 = 1;
+const COMPRESSION_NONE: u8 = 2;
+
+/// Storage type (bits 0-3 of a BIN_DATA record's properties) of an image
+/// that is a file outside the document
+pub const LINK_TYPE_LINK: u8 = 0;
+
+/// An embedded image recovered from the document's binary data
+#[derive(Debug, Clone)]
+pub struct EmbeddedImage {
+    pub id: u16,
+    /// File extension derived from the image's magic bytes
+    pub extension: &'static str,
+    pub data: Vec<u8>,
+}
+
+impl EmbeddedImage {
+    /// MIME type matching the image's format
+    pub fn mime_type(&self) -> &'static str {
+        image_mime_type(&self.data).unwrap_or("application/octet-stream")
+    }
+}
+
+/// Decode the payload of a BIN_DATA entry
+///
+/// Entries marked as compressed are inflated. Entries that follow the
+/// document default are inflated when `doc_compressed` is set, falling back
+/// to the raw bytes if they turn out not to be deflated.
+pub fn decode_entry(entry: &BinDataEntry, doc_compressed: bool) -> Result<Vec<u8>> {
+    match entry.compression_type {
+        COMPRESSION_DEFLATE => inflate(&entry.data),
+        COMPRESSION_DEFAULT if doc_compressed => {
+            Ok(inflate(&entry.data).unwrap_or_else(|_| entry.data.clone()))
+        }
+        _ => Ok(entry.data.clone()),
+    }
+}
+
+/// Decode a `BinData` stream such as `BIN0001.jpg`
+///
+/// The stream's BIN_DATA entry, when there is one, says whether it was
+/// deflated; otherwise it follows the document's compression flag. Data
+/// that cannot be inflated is kept as stored.
+pub fn decode_stream(data: &[u8], entry: Option<&BinDataEntry>, doc_compressed: bool) -> Vec<u8> {
+    let compressed = match entry.map(|e| e.compression_type) {
+        Some(COMPRESSION_DEFLATE) => true,
+        Some(COMPRESSION_NONE) => false,
+        _ => doc_compressed,
+    };
+    let kind = crate::compression::detect_compression(data, compressed);
+    crate::compression::decompress_as(data, kind).unwrap_or_else(|_| data.to_vec())
+}
+
+/// Inflate raw deflate data, accepting a zlib header as well
+fn inflate(data: &[u8]) -> Result<Vec<u8>> {
+    use flate2::read::{DeflateDecoder, ZlibDecoder};
+    use std::io::Read;
+
+    let mut decompressed = Vec::new();
+    if DeflateDecoder::new(data)
+        .read_to_end(&mut decompressed)
+        .is_ok()
+    {
+        return Ok(decompressed);
+    }
+
+    decompressed.clear();
+    ZlibDecoder::new(data)
+        .read_to_end(&mut decompressed)
+        .map_err(|e| {
+            HwpError::DecompressionError(format!("Failed to inflate binary data: {}", e))
+        })?;
+    Ok(decompressed)
+}
+
+/// Detect an image's MIME type from its magic bytes
+pub fn image_mime_type(data: &[u8]) -> Option<&'static str> {
+    image_extension(data).and_then(|extension| match extension {
+        "png" => Some("image/png"),
+        "jpg" => Some("image/jpeg"),
+        "bmp" => Some("image/bmp"),
+        "gif" => Some("image/gif"),
+        _ => None,
+    })
+}
+
+/// Collect every embedded image in the document, ordered by binary data id
+///
+/// Stream data loaded into `document.bin_data` takes precedence over the
+/// payload of a DocInfo entry with the same id. Data that is not a
+/// recognised image is skipped.
+pub fn extract_images(document: &HwpDocument) -> Vec<EmbeddedImage> {
+    let mut payloads: Vec<(u16, Vec<u8>)> = document
+        .bin_data
+        .iter()
+        .map(|(id, data)| (*id, data.clone()))
+        .collect();
+
+    let compressed = document.header.is_compressed();
+    for entry in &document.doc_info.bin_data_entries {
+        if entry.data.is_empty() || document.bin_data.contains_key(&entry.id) {
+            continue;
+        }
+        if let Ok(data) = decode_entry(entry, compressed) {
+            payloads.push((entry.id, data));
+        }
+    }
+
+    payloads.sort_by_key(|(id, _)| *id);
+    payloads
+        .into_iter()
+        .filter_map(|(id, data)| {
+            image_extension(&data).map(|extension| EmbeddedImage {
+                id,
+                extension,
+                data,
+            })
+        })
+        .collect()
+}
+
+/// Path of the external file a linked BIN_DATA entry refers to
+///
+/// Linked entries hold an absolute and then a relative path, each a
+/// length-prefixed UTF-16 string. The absolute path is preferred when it
+/// is not empty. Returns `None` for entries that are not links or whose
+/// paths cannot be read.
+pub fn linked_path(entry: &BinDataEntry) -> Option<String> {
+    if entry.link_type != LINK_TYPE_LINK {
+        return None;
+    }
+
+    let mut reader = ByteReader::new(&entry.data);
+    let mut read_path = || {
+        let length = reader.read_u16().ok()? as usize;
+        reader.read_utf16_string_n(length).ok()
+    };
+    let absolute = read_path()?;
+    if !absolute.is_empty() {
+        return Some(absolute);
+    }
+    read_path().filter(|relative| !relative.is_empty())
+}
+
+/// Binary data id for a `BinData` stream name such as `BIN0001.png`
+///
+/// The id is the hexadecimal number after the `BIN` prefix.
+pub fn stream_id(name: &str) -> Option<u16> {
+    let name = name.strip_prefix("BinData/").unwrap_or(name);
+    let stem = name.strip_prefix("BIN")?.split('.').next()?;
+    if stem.len() != 4 {
+        return None;
+    }
+    u16::from_str_radix(stem, 16).ok()
+}
@@ -4,10 +4,25 @@ use super::fat::{FatTable, MiniFatTable};
 use super::header::CfbHeader;
 use super::stream::Stream;
 use hwp_core::{HwpError, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::io::{Read, Seek, SeekFrom};
 
+/// Options controlling how a [`CfbContainer`] reads its streams
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CfbContainerOptions {
+    /// Upper bound on the bytes of stream data kept cached after reading;
+    /// the least recently read streams are dropped first. The stream just
+    /// read is always kept, so `Some(0)` caches only the latest one.
+    /// `None` caches every stream read.
+    pub cache_limit_bytes: Option<usize>,
+}
+
 /// CFB (Compound File Binary) container
+///
+/// Parsing reads only the header, FAT, mini FAT and directory; stream data
+/// is read from its sector chain when first requested by
+/// [`read_stream`](Self::read_stream).
 pub struct CfbContainer {
     /// CFB header
     pub header: CfbHeader,
@@ -19,11 +34,24 @@ pub struct CfbContainer {
     pub directory: DirectoryTree,
     /// Cached streams
     streams: HashMap<String, Stream>,
+    /// Cached stream names, least recently read first
+    cache_order: VecDeque<String>,
+    /// Total size of the cached stream data
+    cached_bytes: usize,
+    options: CfbContainerOptions,
 }
 
 impl CfbContainer {
     /// Parse a CFB container from a reader
     pub fn from_reader<R: Read + Seek>(reader: &mut R) -> Result<Self> {
+        Self::from_reader_with_options(reader, CfbContainerOptions::default())
+    }
+
+    /// Parse a CFB container from a reader, caching streams per `options`
+    pub fn from_reader_with_options<R: Read + Seek>(
+        reader: &mut R,
+        options: CfbContainerOptions,
+    ) -> Result<Self> {
         // Parse header
         let header = CfbHeader::from_reader(reader)?;
 
@@ -63,6 +91,9 @@ impl CfbContainer {
             mini_fat,
             directory,
             streams: HashMap::new(),
+            cache_order: VecDeque::new(),
+            cached_bytes: 0,
+            options,
         })
     }
 
@@ -99,12 +130,13 @@ impl CfbContainer {
                 let end = start + DIR_ENTRY_SIZE;
                 let entry = DirectoryEntry::from_bytes(&sector_data[start..end])?;
 
-                // Stop at first invalid entry
-                if !entry.is_valid() && entries.is_empty() {
-                    continue;
+                // Unallocated entries can appear anywhere in the list; keep a
+                // placeholder in their slot so DIDs still index correctly
+                if entry.is_valid() {
+                    entries.push(entry);
+                } else {
+                    entries.push(DirectoryEntry::unallocated());
                 }
-
-                entries.push(entry);
             }
         }
 
@@ -117,16 +149,20 @@ impl CfbContainer {
     }
 
     /// Read a stream by name
+    ///
+    /// The stream is read from its sector chain on first access and cached;
+    /// with a cache limit set, older streams may be dropped and read again
+    /// on their next access.
     pub fn read_stream<R: Read + Seek>(&mut self, reader: &mut R, name: &str) -> Result<&Stream> {
         // Check if already cached
         if self.streams.contains_key(name) {
+            self.touch(name);
             return Ok(&self.streams[name]);
         }
 
         // Find the directory entry
         let entry = self
-            .directory
-            .find(name)
+            .find_stream_ci(name)
             .ok_or_else(|| HwpError::InvalidFormat {
                 reason: format!("Stream '{}' not found", name),
             })?;
@@ -140,21 +176,103 @@ impl CfbContainer {
             self.mini_fat.as_ref(),
         )?;
 
-        // Cache and return
+        // Make room within the cache limit, then cache and return
+        self.evict_for(stream.data.len());
+        self.cached_bytes += stream.data.len();
+        self.cache_order.push_back(name.to_string());
         self.streams.insert(name.to_string(), stream);
         Ok(&self.streams[name])
     }
 
+    /// Total size of the stream data currently cached
+    pub fn cached_bytes(&self) -> usize {
+        self.cached_bytes
+    }
+
+    /// Mark a cached stream as the most recently read
+    fn touch(&mut self, name: &str) {
+        if let Some(pos) = self.cache_order.iter().position(|n| n == name) {
+            if let Some(name) = self.cache_order.remove(pos) {
+                self.cache_order.push_back(name);
+            }
+        }
+    }
+
+    /// Drop the least recently read streams until `incoming` more bytes fit
+    /// under the cache limit
+    fn evict_for(&mut self, incoming: usize) {
+        let limit = match self.options.cache_limit_bytes {
+            Some(limit) => limit,
+            None => return,
+        };
+
+        while self.cached_bytes + incoming > limit {
+            let oldest = match self.cache_order.pop_front() {
+                Some(name) => name,
+                None => break,
+            };
+            if let Some(stream) = self.streams.remove(&oldest) {
+                self.cached_bytes -= stream.data.len();
+            }
+        }
+    }
+
     /// Read a stream by path (e.g., "BodyText/Section0")
     pub fn read_stream_by_path<R: Read + Seek>(
         &mut self,
         reader: &mut R,
         path: &str,
     ) -> Result<&Stream> {
-        // For HWP files, paths like "BodyText/Section0" are stored as single entries
         self.read_stream(reader, path)
     }
 
+    /// Check that a stream's sector chain is as long as its declared size needs
+    ///
+    /// Streams under the mini stream cutoff are checked against the mini FAT.
+    pub fn verify_chain(&self, name: &str) -> Result<()> {
+        let entry = self
+            .find_stream_ci(name)
+            .ok_or_else(|| HwpError::InvalidFormat {
+                reason: format!("Stream '{}' not found", name),
+            })?;
+
+        let size = entry.stream_size();
+        if size == 0 {
+            return Ok(());
+        }
+
+        let (chain_len, sector_size) = if size < self.header.mini_stream_cutoff_size as u64 {
+            match &self.mini_fat {
+                Some(mini_fat) => (
+                    mini_fat.get_chain(entry.starting_sector).len(),
+                    self.header.mini_sector_size(),
+                ),
+                None => {
+                    return Err(HwpError::InvalidFormat {
+                        reason: "Mini FAT not available for mini stream".to_string(),
+                    })
+                }
+            }
+        } else {
+            (
+                self.fat.get_chain(entry.starting_sector).len(),
+                self.header.sector_size(),
+            )
+        };
+
+        let expected = ((size + sector_size as u64 - 1) / sector_size as u64) as usize;
+        if chain_len != expected {
+            return Err(HwpError::InvalidFormat {
+                reason: format!(
+                    "sector chain has {} sectors, {} bytes need {}",
+                    chain_len, size, expected
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
     /// List all stream names
     pub fn list_streams(&self) -> Vec<String> {
         self.directory
@@ -173,14 +291,42 @@ impl CfbContainer {
             .collect()
     }
 
-    /// Check if a stream exists
+    /// Check if a stream exists, falling back to a case-insensitive match
     pub fn has_stream(&self, name: &str) -> bool {
-        self.directory
-            .find(name)
+        self.find_stream_ci(name)
             .map(|e| e.is_stream())
             .unwrap_or(false)
     }
 
+    /// Find the directory entry for `name`, tolerating case variants
+    ///
+    /// A name containing `/` is also tried as a path through nested
+    /// storages, after any entry stored under the whole name. An exact
+    /// match always wins. Only when there is none is a stream
+    /// whose name matches ignoring ASCII case used, and only if exactly one
+    /// stream does, so an ambiguous name never resolves to an arbitrary
+    /// stream.
+    pub fn find_stream_ci(&self, name: &str) -> Option<&DirectoryEntry> {
+        if let Some(entry) = self.directory.find(name) {
+            return Some(entry);
+        }
+        if name.contains('/') {
+            if let Some(entry) = self.directory.find_by_path(name) {
+                return Some(entry);
+            }
+        }
+
+        let mut matches = self
+            .directory
+            .streams()
+            .into_iter()
+            .filter(|e| e.name.eq_ignore_ascii_case(name));
+        match (matches.next(), matches.next()) {
+            (Some(entry), None) => Some(entry),
+            _ => None,
+        }
+    }
+
     /// Get the root directory entry
     pub fn root_entry(&self) -> Option<&DirectoryEntry> {
         self.directory.root()
@@ -189,6 +335,7 @@ impl CfbContainer {
 
 /// A stream extracted from a CFB container
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CfbStream {
     /// Stream name
     pub name: String,
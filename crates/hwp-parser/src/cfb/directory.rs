@@ -5,6 +5,7 @@ use std::io::Read;
 
 /// Object type for directory entries
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectType {
     /// Unknown or unallocated
     Unknown = 0,
@@ -29,6 +30,7 @@ impl From<u8> for ObjectType {
 
 /// Color flag for directory tree nodes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorFlag {
     Red = 0,
     Black = 1,
@@ -45,6 +47,7 @@ impl From<u8> for ColorFlag {
 
 /// Directory entry structure (128 bytes)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectoryEntry {
     /// Entry name (UTF-16LE, up to 32 characters)
     pub name: String,
@@ -179,6 +182,26 @@ impl DirectoryEntry {
         })
     }
 
+    /// Create an empty placeholder for an unallocated directory slot
+    pub fn unallocated() -> Self {
+        DirectoryEntry {
+            name: String::new(),
+            name_len: 0,
+            object_type: ObjectType::Unknown,
+            color_flag: ColorFlag::Black,
+            left_sibling_did: 0xFFFFFFFF,
+            right_sibling_did: 0xFFFFFFFF,
+            child_did: 0xFFFFFFFF,
+            clsid: [0; 16],
+            state_bits: 0,
+            creation_time: 0,
+            modified_time: 0,
+            starting_sector: 0,
+            stream_size_low: 0,
+            stream_size_high: 0,
+        }
+    }
+
     /// Get the total stream size
     pub fn stream_size(&self) -> u64 {
         ((self.stream_size_high as u64) << 32) | (self.stream_size_low as u64)
@@ -209,6 +232,8 @@ impl DirectoryEntry {
 }
 
 /// Directory tree for navigating the CFB structure
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectoryTree {
     /// All directory entries
     pub entries: Vec<DirectoryEntry>,
@@ -222,19 +247,27 @@ impl DirectoryTree {
 
     /// Find an entry by name
     pub fn find(&self, name: &str) -> Option<&DirectoryEntry> {
-        self.entries.iter().find(|e| e.name == name)
+        self.entries.iter().find(|e| e.is_valid() && e.name == name)
     }
 
     /// Find an entry by path (e.g., "BodyText/Section0")
+    ///
+    /// Starting at the root entry, each `/`-separated segment names a child
+    /// of the storage matched by the segment before it. Empty segments, as
+    /// from a leading or doubled `/`, are ignored; a path with no segments
+    /// finds nothing.
     pub fn find_by_path(&self, path: &str) -> Option<&DirectoryEntry> {
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.is_empty() {
-            return None;
+        let mut segments = path.split('/').filter(|s| !s.is_empty()).peekable();
+        segments.peek()?;
+
+        let mut current = self.root()?;
+        for segment in segments {
+            current = self
+                .get_children(current)
+                .into_iter()
+                .find(|child| child.is_valid() && child.name == segment)?;
         }
-
-        // For now, just find by the full path as a name
-        // In a real implementation, we'd traverse the tree structure
-        self.find(path)
+        Some(current)
     }
 
     /// Get all stream entries
@@ -265,21 +298,31 @@ impl DirectoryTree {
 
         let mut children = Vec::new();
         if parent.child_did != 0xFFFFFFFF {
-            self.collect_siblings(parent.child_did, &mut children);
+            let mut visited = vec![false; self.entries.len()];
+            self.collect_siblings(parent.child_did, &mut visited, &mut children);
         }
         children
     }
 
     /// Recursively collect siblings in the red-black tree
-    fn collect_siblings<'a>(&'a self, did: u32, result: &mut Vec<&'a DirectoryEntry>) {
-        if did == 0xFFFFFFFF {
-            return;
+    ///
+    /// Each entry is visited at most once, so a cyclic sibling link in a
+    /// malformed directory ends the walk instead of recursing forever.
+    fn collect_siblings<'a>(
+        &'a self,
+        did: u32,
+        visited: &mut [bool],
+        result: &mut Vec<&'a DirectoryEntry>,
+    ) {
+        match visited.get_mut(did as usize) {
+            Some(seen) if !*seen => *seen = true,
+            _ => return,
         }
 
         if let Some(entry) = self.get(did) {
             // Traverse left subtree
             if entry.left_sibling_did != 0xFFFFFFFF {
-                self.collect_siblings(entry.left_sibling_did, result);
+                self.collect_siblings(entry.left_sibling_did, visited, result);
             }
 
             // Add current node
@@ -287,7 +330,7 @@ impl DirectoryTree {
 
             // Traverse right subtree
             if entry.right_sibling_did != 0xFFFFFFFF {
-                self.collect_siblings(entry.right_sibling_did, result);
+                self.collect_siblings(entry.right_sibling_did, visited, result);
             }
         }
     }
@@ -310,6 +353,67 @@ mod tests {
         assert_eq!(ObjectType::from(5), ObjectType::RootStorage);
     }
 
+    fn entry(
+        name: &str,
+        object_type: ObjectType,
+        left: u32,
+        right: u32,
+        child: u32,
+    ) -> DirectoryEntry {
+        DirectoryEntry {
+            name: name.to_string(),
+            name_len: ((name.encode_utf16().count() + 1) * 2) as u16,
+            object_type,
+            color_flag: ColorFlag::Black,
+            left_sibling_did: left,
+            right_sibling_did: right,
+            child_did: child,
+            clsid: [0; 16],
+            state_bits: 0,
+            creation_time: 0,
+            modified_time: 0,
+            starting_sector: 0,
+            stream_size_low: 0,
+            stream_size_high: 0,
+        }
+    }
+
+    #[test]
+    fn test_find_by_path_nested_storages() {
+        const NONE: u32 = 0xFFFFFFFF;
+        // Root
+        // ├── FileHeader
+        // └── Storage
+        //     ├── Stream
+        //     └── Inner
+        //         └── Stream
+        let tree = DirectoryTree::new(vec![
+            entry("Root Entry", ObjectType::RootStorage, NONE, NONE, 2),
+            entry("FileHeader", ObjectType::Stream, NONE, NONE, NONE),
+            entry("Storage", ObjectType::Storage, 1, NONE, 4),
+            entry("Stream", ObjectType::Stream, NONE, NONE, NONE),
+            entry("Inner", ObjectType::Storage, NONE, 3, 5),
+            entry("Stream", ObjectType::Stream, NONE, NONE, NONE),
+        ]);
+        let find = |path: &str| {
+            tree.find_by_path(path).map(|found| {
+                tree.entries
+                    .iter()
+                    .position(|e| std::ptr::eq(e, found))
+                    .unwrap()
+            })
+        };
+
+        assert_eq!(find("FileHeader"), Some(1));
+        assert_eq!(find("Storage"), Some(2));
+        assert_eq!(find("Storage/Stream"), Some(3));
+        assert_eq!(find("/Storage/Inner/Stream"), Some(5));
+        assert_eq!(find("Stream"), None);
+        assert_eq!(find("FileHeader/Stream"), None);
+        assert_eq!(find("Storage/Missing"), None);
+        assert_eq!(find(""), None);
+    }
+
     #[test]
     fn test_stream_size() {
         let entry = DirectoryEntry {
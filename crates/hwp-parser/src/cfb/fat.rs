@@ -5,6 +5,8 @@ use hwp_core::{HwpError, Result};
 use std::io::{Read, Seek, SeekFrom};
 
 /// FAT (File Allocation Table) manager
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FatTable {
     /// FAT entries
     pub entries: Vec<u32>,
@@ -151,6 +153,8 @@ impl FatTable {
 }
 
 /// Mini FAT table for small streams
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MiniFatTable {
     /// Mini FAT entries
     pub entries: Vec<u32>,
@@ -5,6 +5,7 @@ use std::io::{Read, Seek, SeekFrom};
 
 /// CFB Header structure (512 bytes)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CfbHeader {
     /// Signature (0xD0CF11E0A1B11AE1)
     pub signature: [u8; 8],
@@ -41,6 +42,7 @@ pub struct CfbHeader {
     /// Number of DIFAT sectors
     pub difat_sectors: u32,
     /// First 109 FAT sector positions (DIFAT array)
+    #[cfg_attr(feature = "serde", serde(with = "serde_arrays"))]
     pub difat: [u32; 109],
 }
 
@@ -176,6 +178,100 @@ impl CfbHeader {
     pub fn is_valid_version(&self) -> bool {
         matches!(self.major_version, 3 | 4)
     }
+
+    /// Validate header fields without parsing the rest of the container
+    ///
+    /// Checks the signature, version, byte order, sector shifts, mini stream
+    /// cutoff and DIFAT consistency. All problems found are reported together
+    /// in a single `InvalidFormat` error.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        if self.signature != CFB_SIGNATURE {
+            problems.push("invalid signature".to_string());
+        }
+
+        if self.byte_order != 0xFFFE {
+            problems.push(format!(
+                "invalid byte order marker 0x{:04X}",
+                self.byte_order
+            ));
+        }
+
+        match (self.major_version, self.sector_shift) {
+            (3, 9) | (4, 12) => {}
+            (3, shift) | (4, shift) => problems.push(format!(
+                "sector shift {} does not match major version {}",
+                shift, self.major_version
+            )),
+            (version, _) => problems.push(format!("unsupported major version {}", version)),
+        }
+
+        if self.mini_sector_shift != 6 {
+            problems.push(format!(
+                "invalid mini sector shift {} (expected 6)",
+                self.mini_sector_shift
+            ));
+        }
+
+        if self.mini_stream_cutoff_size != 4096 {
+            problems.push(format!(
+                "invalid mini stream cutoff size {} (expected 4096)",
+                self.mini_stream_cutoff_size
+            ));
+        }
+
+        // DIFAT consistency: the header holds the first 109 FAT sector locations,
+        // the remainder must live in DIFAT sectors
+        let header_fat_entries = self.difat.iter().filter(|&&s| s != FREESECT).count();
+        let expected_header_entries = (self.fat_sectors as usize).min(self.difat.len());
+        if header_fat_entries != expected_header_entries {
+            problems.push(format!(
+                "DIFAT array lists {} FAT sectors, header declares {}",
+                header_fat_entries, self.fat_sectors
+            ));
+        }
+
+        if let Some(&sector) = self
+            .difat
+            .iter()
+            .find(|&&s| s != FREESECT && s > MAXREGSECT)
+        {
+            problems.push(format!(
+                "DIFAT array contains invalid sector 0x{:08X}",
+                sector
+            ));
+        }
+
+        if self.fat_sectors as usize > self.difat.len() && self.difat_sectors == 0 {
+            problems.push(format!(
+                "{} FAT sectors require DIFAT sectors, but none are declared",
+                self.fat_sectors
+            ));
+        }
+
+        if self.difat_sectors == 0 && self.first_difat_sector != ENDOFCHAIN {
+            problems.push(format!(
+                "first DIFAT sector 0x{:08X} set but DIFAT sector count is 0",
+                self.first_difat_sector
+            ));
+        }
+
+        if self.difat_sectors > 0 && self.first_difat_sector == ENDOFCHAIN {
+            problems.push(format!(
+                "{} DIFAT sectors declared but first DIFAT sector is ENDOFCHAIN",
+                self.difat_sectors
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(HwpError::InvalidFormat {
+                reason: format!("Invalid CFB header: {}", problems.join("; ")),
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -191,6 +287,98 @@ mod tests {
         );
     }
 
+    fn valid_header() -> CfbHeader {
+        let mut difat = [FREESECT; 109];
+        difat[0] = 0;
+
+        CfbHeader {
+            signature: CFB_SIGNATURE,
+            clsid: [0; 16],
+            minor_version: 0x3E,
+            major_version: 3,
+            byte_order: 0xFFFE,
+            sector_shift: 9,
+            mini_sector_shift: 6,
+            reserved: [0; 6],
+            total_sectors: 0,
+            fat_sectors: 1,
+            first_dir_sector: 1,
+            transaction_signature: 0,
+            mini_stream_cutoff_size: 4096,
+            first_mini_fat_sector: ENDOFCHAIN,
+            mini_fat_sectors: 0,
+            first_difat_sector: ENDOFCHAIN,
+            difat_sectors: 0,
+            difat,
+        }
+    }
+
+    fn validation_reason(header: &CfbHeader) -> String {
+        match header.validate() {
+            Err(HwpError::InvalidFormat { reason }) => reason,
+            other => panic!("expected InvalidFormat, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_valid_header() {
+        assert!(valid_header().validate().is_ok());
+
+        let mut v4 = valid_header();
+        v4.major_version = 4;
+        v4.sector_shift = 12;
+        assert!(v4.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_bad_signature() {
+        let mut header = valid_header();
+        header.signature = [0; 8];
+        assert!(validation_reason(&header).contains("invalid signature"));
+    }
+
+    #[test]
+    fn test_validate_bad_version_and_sector_shift() {
+        let mut header = valid_header();
+        header.major_version = 5;
+        assert!(validation_reason(&header).contains("unsupported major version 5"));
+
+        let mut header = valid_header();
+        header.sector_shift = 12;
+        assert!(validation_reason(&header).contains("sector shift 12"));
+
+        let mut header = valid_header();
+        header.mini_sector_shift = 7;
+        assert!(validation_reason(&header).contains("mini sector shift"));
+    }
+
+    #[test]
+    fn test_validate_difat_inconsistency() {
+        let mut header = valid_header();
+        header.fat_sectors = 2;
+        assert!(validation_reason(&header).contains("DIFAT array lists 1 FAT sectors"));
+
+        let mut header = valid_header();
+        header.first_difat_sector = 5;
+        assert!(validation_reason(&header).contains("DIFAT sector count is 0"));
+
+        let mut header = valid_header();
+        header.difat[1] = 0xFFFFFFFC;
+        header.fat_sectors = 2;
+        assert!(validation_reason(&header).contains("invalid sector 0xFFFFFFFC"));
+    }
+
+    #[test]
+    fn test_validate_reports_all_problems() {
+        let mut header = valid_header();
+        header.byte_order = 0xFEFF;
+        header.mini_stream_cutoff_size = 512;
+
+        let reason = validation_reason(&header);
+        assert!(reason.contains("byte order"));
+        assert!(reason.contains("mini stream cutoff"));
+    }
+
     #[test]
     fn test_sector_sizes() {
         let mut header = CfbHeader {
@@ -4,7 +4,7 @@ pub mod fat;
 pub mod header;
 pub mod stream;
 
-pub use container::{CfbContainer, CfbStream};
+pub use container::{CfbContainer, CfbContainerOptions, CfbStream};
 pub use directory::DirectoryEntry;
 pub use header::CfbHeader;
 
@@ -3,10 +3,12 @@ use super::directory::DirectoryEntry;
 use super::fat::{FatTable, MiniFatTable};
 use super::header::CfbHeader;
 use hwp_core::{HwpError, Result};
+use log::{debug, trace};
 use std::io::{Read, Seek};
 
 /// A stream within a CFB container
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stream {
     /// Stream name
     pub name: String,
@@ -42,7 +44,7 @@ impl Stream {
             // Empty stream
             Vec::new()
         } else if size < header.mini_stream_cutoff_size as u64 {
-            // Mini stream
+            // Mini stream; a stream of exactly the cutoff size is regular
             if let Some(mini_fat) = mini_fat {
                 if entry.starting_sector != ENDOFCHAIN {
                     mini_fat.read_chain(entry.starting_sector)?
@@ -63,6 +65,19 @@ impl Stream {
             }
         };
 
+        // A chain shorter than the declared size means a damaged directory
+        // entry or FAT; fail here rather than hand back a short stream
+        if (data.len() as u64) < size {
+            return Err(HwpError::InvalidFormat {
+                reason: format!(
+                    "Stream '{}' declares {} bytes but its sector chain holds {}",
+                    entry.name,
+                    size,
+                    data.len()
+                ),
+            });
+        }
+
         // Truncate to actual size (chains are sector-aligned)
         let mut data = data;
         data.truncate(size as usize);
@@ -77,16 +92,15 @@ impl Stream {
 
     /// Check if the stream is compressed
     pub fn is_compressed(&self) -> bool {
-        eprintln!("[DEBUG] Checking compression for stream: {}", self.name);
-        eprintln!("[DEBUG]   Data size: {} bytes", self.data.len());
+        debug!(
+            "Checking compression for stream: {} ({} bytes)",
+            self.name,
+            self.data.len()
+        );
         if self.data.len() >= 16 {
-            eprintln!("[DEBUG]   First 16 bytes: {:02X?}", &self.data[..16]);
+            trace!("First 16 bytes: {:02X?}", &self.data[..16]);
         } else if self.data.len() >= 8 {
-            eprintln!(
-                "[DEBUG]   First {} bytes: {:02X?}",
-                self.data.len(),
-                &self.data
-            );
+            trace!("First {} bytes: {:02X?}", self.data.len(), &self.data);
         }
 
         // For DocInfo and BodyText streams in HWP v5.x, they are typically compressed
@@ -122,8 +136,10 @@ impl Stream {
                 let valid_size = size > 0 && size as usize <= (self.data.len() - 4);
 
                 if valid_tag && valid_level && valid_size {
-                    eprintln!("[DEBUG]   -> Looks like uncompressed record (tag:0x{:04X}, level:{}, size:{})", 
-                             tag_id, level, size);
+                    debug!(
+                        "Looks like uncompressed record (tag:0x{:04X}, level:{}, size:{})",
+                        tag_id, level, size
+                    );
                     return false;
                 }
 
@@ -136,22 +152,19 @@ impl Stream {
                 ]);
                 if potential_size > 0 && potential_size < (100 * 1024 * 1024) {
                     // Could be HWP compression format
-                    eprintln!(
-                        "[DEBUG]   -> Could be HWP compressed (size header: {})",
-                        potential_size
-                    );
+                    debug!("Could be HWP compressed (size header: {})", potential_size);
                     return true;
                 }
             }
 
             // For these critical streams, assume compressed if we can't determine otherwise
-            eprintln!("[DEBUG]   -> Assuming {} stream is compressed", self.name);
+            debug!("Assuming {} stream is compressed", self.name);
             return true;
         }
 
         // First check for HWP compression format (4-byte size header + raw deflate)
         if crate::compression::is_hwp_compressed(&self.data) {
-            eprintln!("[DEBUG]   -> HWP compression detected");
+            debug!("HWP compression detected");
             return true;
         }
 
@@ -159,15 +172,12 @@ impl Stream {
         if self.data.len() >= 2 {
             let header = u16::from_be_bytes([self.data[0], self.data[1]]);
             if matches!(header, 0x789C | 0x78DA | 0x7801 | 0x785E | 0x78DE) {
-                eprintln!(
-                    "[DEBUG]   -> Zlib compression detected (header: 0x{:04X})",
-                    header
-                );
+                debug!("Zlib compression detected (header: 0x{:04X})", header);
                 return true;
             }
         }
 
-        eprintln!("[DEBUG]   -> No compression detected");
+        debug!("No compression detected");
         false
     }
 
@@ -177,37 +187,34 @@ impl Stream {
             return Ok(self.data.clone());
         }
 
-        eprintln!("[DEBUG] Attempting decompression for stream: {}", self.name);
+        debug!("Attempting decompression for stream: {}", self.name);
 
         // For DocInfo and BodyText streams, try different compression methods
         if self.name == "DocInfo" || self.name.starts_with("BodyText/Section") {
             // Method 1: Try as HWP format (4-byte size + raw deflate)
             if self.data.len() >= 8 {
-                eprintln!("[DEBUG] Trying HWP format decompression...");
+                debug!("Trying HWP format decompression...");
                 if let Ok(result) = crate::compression::decompress_hwp(&self.data) {
-                    eprintln!(
-                        "[DEBUG] HWP decompression successful: {} bytes",
-                        result.len()
-                    );
+                    debug!("HWP decompression successful: {} bytes", result.len());
                     return Ok(result);
                 }
             }
 
             // Method 2: Try entire data as raw deflate
-            eprintln!("[DEBUG] Trying raw deflate on entire stream...");
+            debug!("Trying raw deflate on entire stream...");
             if let Ok(result) = crate::compression::decompress_raw(&self.data) {
-                eprintln!("[DEBUG] Raw deflate successful: {} bytes", result.len());
+                debug!("Raw deflate successful: {} bytes", result.len());
                 return Ok(result);
             }
 
             // Method 3: Try as zlib compressed
-            eprintln!("[DEBUG] Trying zlib decompression...");
+            debug!("Trying zlib decompression...");
             use flate2::read::ZlibDecoder;
             let mut decoder = ZlibDecoder::new(&self.data[..]);
             let mut decompressed = Vec::new();
             if decoder.read_to_end(&mut decompressed).is_ok() {
-                eprintln!(
-                    "[DEBUG] Zlib decompression successful: {} bytes",
+                debug!(
+                    "Zlib decompression successful: {} bytes",
                     decompressed.len()
                 );
                 return Ok(decompressed);
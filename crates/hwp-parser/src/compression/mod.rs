@@ -1,6 +1,7 @@
 use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::read::DeflateDecoder;
 use hwp_core::{HwpError, Result};
+use log::{debug, trace};
 use std::io::Read;
 
 /// Decompress data using deflate algorithm (legacy function)
@@ -56,14 +57,12 @@ pub fn decompress_hwp(data: &[u8]) -> Result<Vec<u8>> {
         .read_u32::<LittleEndian>()
         .map_err(|e| HwpError::DecompressionError(format!("Failed to read size header: {}", e)))?;
 
-    eprintln!("[DEBUG] HWP Compression Header:");
-    eprintln!("  - Total data size: {} bytes", data.len());
-    eprintln!(
-        "  - Uncompressed size from header: {} bytes",
+    debug!(
+        "HWP compressed data: {} bytes, {} bytes uncompressed according to the header",
+        data.len(),
         uncompressed_size
     );
-    eprintln!("  - Compressed data size: {} bytes", data.len() - 4);
-    eprintln!("  - First 16 bytes: {:02X?}", &data[..16.min(data.len())]);
+    trace!("First 16 bytes: {:02X?}", &data[..16.min(data.len())]);
 
     // Validate uncompressed size
     if uncompressed_size == 0 {
@@ -82,23 +81,23 @@ pub fn decompress_hwp(data: &[u8]) -> Result<Vec<u8>> {
     // Get compressed data (skip 4-byte header)
     let compressed_data = &data[4..];
 
-    eprintln!("[DEBUG] Attempting raw deflate decompression...");
-    eprintln!(
-        "[DEBUG] First 8 bytes of compressed data: {:02X?}",
+    debug!("Attempting raw deflate decompression...");
+    trace!(
+        "First 8 bytes of compressed data: {:02X?}",
         &compressed_data[..8.min(compressed_data.len())]
     );
 
     // Decompress using raw deflate (windowBits = -15)
     match decompress_raw_with_size(compressed_data, uncompressed_size as usize) {
         Ok(result) => {
-            eprintln!(
-                "[DEBUG] Decompression successful, {} bytes decompressed",
+            debug!(
+                "Decompression successful, {} bytes decompressed",
                 result.len()
             );
             Ok(result)
         }
         Err(_e) => {
-            eprintln!("[DEBUG] Raw deflate failed, trying with zlib wrapper...");
+            debug!("Raw deflate failed, trying with zlib wrapper...");
             // Fallback: Try with zlib wrapper in case the format is different
             decompress_with_zlib_fallback(data, uncompressed_size as usize)
         }
@@ -115,8 +114,8 @@ fn decompress_with_zlib_fallback(data: &[u8], expected_size: usize) -> Result<Ve
 
     match decoder.read_to_end(&mut decompressed) {
         Ok(_) => {
-            eprintln!(
-                "[DEBUG] Zlib decompression successful (fallback), {} bytes",
+            debug!(
+                "Zlib decompression successful (fallback), {} bytes",
                 decompressed.len()
             );
             Ok(decompressed)
@@ -129,8 +128,8 @@ fn decompress_with_zlib_fallback(data: &[u8], expected_size: usize) -> Result<Ve
                 decoder.read_to_end(&mut decompressed).map_err(|e| {
                     HwpError::DecompressionError(format!("All decompression methods failed: {}", e))
                 })?;
-                eprintln!(
-                    "[DEBUG] Zlib decompression of data[4..] successful, {} bytes",
+                debug!(
+                    "Zlib decompression of data[4..] successful, {} bytes",
                     decompressed.len()
                 );
                 Ok(decompressed)
@@ -207,6 +206,78 @@ pub fn decompress_raw(data: &[u8]) -> Result<Vec<u8>> {
     }
 }
 
+/// How the bytes of a stream are compressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionKind {
+    /// Stored as is
+    None,
+    /// A 4-byte uncompressed size, then raw deflate
+    SizedDeflate,
+    /// Raw deflate with no header
+    RawDeflate,
+    /// Deflate with a zlib header and checksum
+    Zlib,
+}
+
+/// Work out how a stream is compressed from its first bytes
+///
+/// `compressed` is the FileHeader's compressed flag; without it streams are
+/// stored as is. A size prefix is only trusted when it fits the stream,
+/// since deflate can neither shrink data more than 1032:1 nor grow it by
+/// more than a few bytes per stored block.
+pub fn detect_compression(data: &[u8], compressed: bool) -> CompressionKind {
+    if !compressed {
+        return CompressionKind::None;
+    }
+
+    if data.len() > 4 {
+        let size = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
+        let body = data.len() - 4;
+        if size < 100 * 1024 * 1024
+            && size <= body.saturating_mul(1032)
+            && body <= size + size / 1000 + 64
+        {
+            return CompressionKind::SizedDeflate;
+        }
+    }
+
+    if data.len() >= 2 {
+        let header = u16::from_be_bytes([data[0], data[1]]);
+        // CM 8 (deflate) with a window of at most 32K, and a valid FCHECK
+        if data[0] & 0x0F == 8 && data[0] >> 4 <= 7 && header % 31 == 0 {
+            return CompressionKind::Zlib;
+        }
+    }
+
+    CompressionKind::RawDeflate
+}
+
+/// Decompress a stream with the one method `kind` names
+pub fn decompress_as(data: &[u8], kind: CompressionKind) -> Result<Vec<u8>> {
+    match kind {
+        CompressionKind::None => Ok(data.to_vec()),
+        // An empty stream is still written with a size prefix
+        CompressionKind::SizedDeflate if data.get(..4) == Some(&[0; 4]) => Ok(Vec::new()),
+        CompressionKind::SizedDeflate => decompress_hwp(data),
+        CompressionKind::RawDeflate => decompress(data),
+        CompressionKind::Zlib => decompress_zlib(data),
+    }
+}
+
+/// Decompress data with a zlib header
+pub fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>> {
+    use flate2::read::ZlibDecoder;
+
+    let mut decoder = ZlibDecoder::new(data);
+    let mut decompressed = Vec::new();
+
+    decoder
+        .read_to_end(&mut decompressed)
+        .map_err(|e| HwpError::DecompressionError(e.to_string()))?;
+
+    Ok(decompressed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,4 +356,37 @@ mod tests {
         // Test with wrong size should fail
         assert!(decompress_raw_with_size(&compressed, original.len() + 10).is_err());
     }
+
+    #[test]
+    fn test_detect_compression() {
+        let original = "HWP 본문 ".repeat(200);
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(original.as_bytes()).unwrap();
+        let raw = encoder.finish().unwrap();
+
+        let mut sized = (original.len() as u32).to_le_bytes().to_vec();
+        sized.extend_from_slice(&raw);
+
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(original.as_bytes()).unwrap();
+        let zlib = encoder.finish().unwrap();
+
+        for (data, kind) in [
+            (&sized, CompressionKind::SizedDeflate),
+            (&raw, CompressionKind::RawDeflate),
+            (&zlib, CompressionKind::Zlib),
+        ] {
+            assert_eq!(detect_compression(data, true), kind);
+            assert_eq!(decompress_as(data, kind).unwrap(), original.as_bytes());
+        }
+        assert_eq!(detect_compression(&sized, false), CompressionKind::None);
+
+        // A size prefix that cannot describe the stream is not trusted
+        let mut implausible = 50_000_000u32.to_le_bytes().to_vec();
+        implausible.extend_from_slice(&raw);
+        assert_eq!(
+            detect_compression(&implausible, true),
+            CompressionKind::RawDeflate
+        );
+    }
 }
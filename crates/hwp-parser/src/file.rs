@@ -0,0 +1,63 @@
+use crate::formatters::{DocumentMetadata, FormatOptions};
+use hwp_core::{HwpDocument, Result};
+use std::path::{Path, PathBuf};
+
+/// An HWP file opened from disk together with its parsed document
+#[derive(Debug)]
+pub struct HwpFile {
+    path: PathBuf,
+    size: u64,
+    document: HwpDocument,
+}
+
+impl HwpFile {
+    /// Read and parse an HWP file
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let document = crate::parse(&data)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            size: data.len() as u64,
+            document,
+        })
+    }
+
+    /// Path the file was opened from
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// File size in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The parsed document
+    pub fn document(&self) -> &HwpDocument {
+        &self.document
+    }
+
+    /// Take ownership of the parsed document
+    pub fn into_document(self) -> HwpDocument {
+        self.document
+    }
+
+    /// Plain text of every paragraph in the document
+    pub fn text(&self) -> String {
+        self.document.get_text()
+    }
+
+    /// Document metadata, using the file stem as the fallback title
+    pub fn metadata(&self) -> DocumentMetadata {
+        let options = FormatOptions {
+            source_name: self
+                .path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned()),
+            ..FormatOptions::default()
+        };
+        DocumentMetadata::resolve(&self.document, &options)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::formatters::{FormatOptions, OutputFormatter};
+use hwp_core::models::document::DocInfo;
+use hwp_core::models::{Paragraph, Section};
+use hwp_core::{HwpDocument, Result};
+
+/// CSV formatter for tabular document data
+///
+/// Documents with tables are written one row per table cell
+/// (`section,table,row,col,text`); documents without tables one row per
+/// non-empty paragraph (`section,paragraph,text`). Records end in CRLF as
+/// RFC 4180 specifies; line breaks inside quoted fields are kept as is.
+pub struct CsvFormatter {
+    options: FormatOptions,
+}
+
+impl CsvFormatter {
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
+    }
+
+    /// Quote a field if it contains the delimiter, the quote character or a
+    /// line break, doubling any quote characters inside it
+    pub fn escape_field(&self, field: &str) -> String {
+        let quote = self.options.csv_quote_char;
+        let needs_quotes = field
+            .chars()
+            .any(|c| c == self.options.csv_delimiter || c == quote || c == '\n' || c == '\r');
+        if !needs_quotes {
+            return field.to_string();
+        }
+
+        let mut escaped = String::with_capacity(field.len() + 2);
+        escaped.push(quote);
+        for c in field.chars() {
+            if c == quote {
+                escaped.push(quote);
+            }
+            escaped.push(c);
+        }
+        escaped.push(quote);
+        escaped
+    }
+
+    /// Join fields into one CRLF-terminated record
+    fn row(&self, fields: &[&str]) -> String {
+        let mut line = fields
+            .iter()
+            .map(|f| self.escape_field(f))
+            .collect::<Vec<_>>()
+            .join(&self.options.csv_delimiter.to_string());
+        line.push_str("\r\n");
+        line
+    }
+
+    /// Rows for every table cell in a section, in cell order
+    fn table_rows(&self, section: &Section, index: usize) -> String {
+        let mut csv = String::new();
+        for (table_idx, table) in section.tables.iter().enumerate() {
+            for cell in &table.cells {
+                csv.push_str(&self.row(&[
+                    &index.to_string(),
+                    &table_idx.to_string(),
+                    &cell.row.to_string(),
+                    &cell.col.to_string(),
+                    &cell.text(),
+                ]));
+            }
+        }
+        csv
+    }
+
+    /// Rows for every non-empty paragraph in a section
+    fn paragraph_rows(&self, section: &Section, index: usize) -> String {
+        let mut csv = String::new();
+        for (para_idx, paragraph) in section.paragraphs.iter().enumerate() {
+            if !paragraph.text.is_empty() {
+                csv.push_str(&self.row(&[
+                    &index.to_string(),
+                    &para_idx.to_string(),
+                    &paragraph.text,
+                ]));
+            }
+        }
+        csv
+    }
+}
+
+impl OutputFormatter for CsvFormatter {
+    fn format_document(&self, doc: &HwpDocument) -> Result<String> {
+        let has_tables = doc.sections.iter().any(|s| !s.tables.is_empty());
+
+        let mut csv = if has_tables {
+            self.row(&["section", "table", "row", "col", "text"])
+        } else {
+            self.row(&["section", "paragraph", "text"])
+        };
+
+        for (index, section) in doc.sections.iter().enumerate() {
+            if has_tables {
+                csv.push_str(&self.table_rows(section, index));
+            } else {
+                csv.push_str(&self.paragraph_rows(section, index));
+            }
+        }
+
+        Ok(csv)
+    }
+
+    fn format_metadata(&self, _doc_info: &DocInfo) -> Result<String> {
+        // CSV has no place for metadata
+        Ok(String::new())
+    }
+
+    fn format_section(&self, section: &Section, index: usize) -> Result<String> {
+        if section.tables.is_empty() {
+            Ok(self.paragraph_rows(section, index))
+        } else {
+            Ok(self.table_rows(section, index))
+        }
+    }
+
+    fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
+        Ok(self.row(&[&paragraph.text]))
+    }
+}
@@ -0,0 +1,303 @@
+use crate::formatters::{DocumentMetadata, FormatOptions, OutputFormatter};
+use crate::text_extractor::TextRun;
+use hwp_core::models::document::{CharShape, DocInfo};
+use hwp_core::models::{Paragraph, Section};
+use hwp_core::{HwpDocument, HwpError, Result};
+use std::io::{Cursor, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+</Types>
+"#;
+
+const PACKAGE_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>
+</Relationships>
+"#;
+
+const DOCUMENT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>
+"#;
+
+const WORDML_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+/// DOCX formatter for HWP documents
+///
+/// [`format_document`](OutputFormatter::format_document) returns the
+/// WordprocessingML body (`word/document.xml`); the `.docx` archive itself
+/// comes from [`format_bytes`](OutputFormatter::format_bytes).
+pub struct DocxFormatter {
+    options: FormatOptions,
+}
+
+impl DocxFormatter {
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escape text for XML character data and attribute values
+    ///
+    /// Control characters other than tab and line breaks are not allowed in
+    /// XML 1.0 and are dropped.
+    pub fn escape_xml(text: &str) -> String {
+        let mut xml = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => xml.push_str("&amp;"),
+                '<' => xml.push_str("&lt;"),
+                '>' => xml.push_str("&gt;"),
+                '"' => xml.push_str("&quot;"),
+                '\'' => xml.push_str("&apos;"),
+                '\t' | '\n' | '\r' => xml.push(c),
+                c if c.is_control() => {}
+                c => xml.push(c),
+            }
+        }
+        xml
+    }
+
+    /// Write a run, turning tabs and line breaks into their own elements
+    fn run_xml(text: &str, properties: &str) -> String {
+        let mut xml = String::from("<w:r>");
+        if !properties.is_empty() {
+            xml.push_str(&format!("<w:rPr>{}</w:rPr>", properties));
+        }
+
+        let mut pending = String::new();
+        let flush = |xml: &mut String, pending: &mut String| {
+            if !pending.is_empty() {
+                xml.push_str(&format!(
+                    "<w:t xml:space=\"preserve\">{}</w:t>",
+                    Self::escape_xml(pending)
+                ));
+                pending.clear();
+            }
+        };
+        for c in text.chars() {
+            match c {
+                '\t' => {
+                    flush(&mut xml, &mut pending);
+                    xml.push_str("<w:tab/>");
+                }
+                '\n' => {
+                    flush(&mut xml, &mut pending);
+                    xml.push_str("<w:br/>");
+                }
+                '\r' => {}
+                c => pending.push(c),
+            }
+        }
+        flush(&mut xml, &mut pending);
+
+        xml.push_str("</w:r>");
+        xml
+    }
+
+    /// Write a paragraph as a `<w:p>` element
+    ///
+    /// The paragraph style comes from the paragraph header's style ID;
+    /// runs follow the paragraph's char shape positions.
+    fn paragraph_xml(paragraph: &Paragraph, doc_info: Option<&DocInfo>) -> String {
+        let mut xml = String::from("<w:p>");
+
+        let style = doc_info.and_then(|d| d.styles.get(paragraph.header.style_id as usize));
+        if let Some(style) = style {
+            if is_paragraph_style(style.properties) {
+                xml.push_str(&format!(
+                    "<w:pPr><w:pStyle w:val=\"{}\"/></w:pPr>",
+                    style_id(paragraph.header.style_id as usize)
+                ));
+            }
+        }
+
+        let doc_info = match doc_info {
+            Some(doc_info) if !paragraph.char_shapes.is_empty() => doc_info,
+            _ => {
+                if !paragraph.text.is_empty() {
+                    xml.push_str(&Self::run_xml(&paragraph.text, ""));
+                }
+                xml.push_str("</w:p>");
+                return xml;
+            }
+        };
+
+        for run in TextRun::split(paragraph) {
+            let properties = doc_info
+                .char_shapes
+                .get(run.char_shape_id as usize)
+                .map(run_properties)
+                .unwrap_or_default();
+            xml.push_str(&Self::run_xml(&run.text, &properties));
+        }
+
+        xml.push_str("</w:p>");
+        xml
+    }
+
+    /// `word/styles.xml` with one style per DocInfo style
+    fn styles_xml(doc_info: &DocInfo) -> String {
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:styles xmlns:w=\"{}\">",
+            WORDML_NS
+        );
+
+        for (index, style) in doc_info.styles.iter().enumerate() {
+            let style_type = if is_paragraph_style(style.properties) {
+                "paragraph"
+            } else {
+                "character"
+            };
+            let name = if style.english_name.is_empty() {
+                &style.name
+            } else {
+                &style.english_name
+            };
+
+            xml.push_str(&format!(
+                "<w:style w:type=\"{}\" w:styleId=\"{}\"><w:name w:val=\"{}\"/>",
+                style_type,
+                style_id(index),
+                Self::escape_xml(name)
+            ));
+            let properties = doc_info
+                .char_shapes
+                .get(style.char_shape_id as usize)
+                .map(run_properties)
+                .unwrap_or_default();
+            if !properties.is_empty() {
+                xml.push_str(&format!("<w:rPr>{}</w:rPr>", properties));
+            }
+            xml.push_str("</w:style>");
+        }
+
+        xml.push_str("</w:styles>\n");
+        xml
+    }
+
+    /// `docProps/core.xml` with the resolved title and author
+    fn core_xml(&self, doc: &HwpDocument) -> String {
+        let metadata = DocumentMetadata::resolve(doc, &self.options);
+
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<cp:coreProperties xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">",
+        );
+        if let Some(title) = &metadata.title {
+            xml.push_str(&format!("<dc:title>{}</dc:title>", Self::escape_xml(title)));
+        }
+        if let Some(author) = &metadata.author {
+            xml.push_str(&format!(
+                "<dc:creator>{}</dc:creator>",
+                Self::escape_xml(author)
+            ));
+        }
+        xml.push_str("</cp:coreProperties>\n");
+        xml
+    }
+}
+
+/// Whether a DocInfo style is a paragraph style (type bits 0-2 are zero)
+fn is_paragraph_style(properties: u8) -> bool {
+    properties & 0x07 == 0
+}
+
+/// Style ID used for the DocInfo style at `index`
+fn style_id(index: usize) -> String {
+    format!("Style{}", index)
+}
+
+/// Run properties for a character shape, empty when it needs no styling
+///
+/// `w:rPr` is a sequence, so the elements are written in schema order.
+fn run_properties(shape: &CharShape) -> String {
+    let mut xml = String::new();
+
+    if shape.is_bold() {
+        xml.push_str("<w:b/>");
+    }
+    if shape.is_italic() {
+        xml.push_str("<w:i/>");
+    }
+    if shape.text_color != 0 {
+        let [r, g, b, _] = shape.text_color.to_le_bytes();
+        xml.push_str(&format!("<w:color w:val=\"{:02X}{:02X}{:02X}\"/>", r, g, b));
+    }
+    if shape.base_size != 0 {
+        // base_size is in 1/100 pt; w:sz is in half-points
+        xml.push_str(&format!("<w:sz w:val=\"{}\"/>", shape.base_size / 50));
+    }
+    if shape.is_underlined() {
+        xml.push_str("<w:u w:val=\"single\"/>");
+    }
+
+    xml
+}
+
+impl OutputFormatter for DocxFormatter {
+    fn format_document(&self, document: &HwpDocument) -> Result<String> {
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:document xmlns:w=\"{}\"><w:body>",
+            WORDML_NS
+        );
+
+        for section in &document.sections {
+            for paragraph in &section.paragraphs {
+                xml.push_str(&Self::paragraph_xml(paragraph, Some(&document.doc_info)));
+            }
+        }
+
+        xml.push_str("</w:body></w:document>\n");
+        Ok(xml)
+    }
+
+    fn format_bytes(&self, document: &HwpDocument) -> Result<Vec<u8>> {
+        let parts = [
+            ("[Content_Types].xml", CONTENT_TYPES_XML.to_string()),
+            ("_rels/.rels", PACKAGE_RELS_XML.to_string()),
+            ("docProps/core.xml", self.core_xml(document)),
+            (
+                "word/_rels/document.xml.rels",
+                DOCUMENT_RELS_XML.to_string(),
+            ),
+            ("word/document.xml", self.format_document(document)?),
+            ("word/styles.xml", Self::styles_xml(&document.doc_info)),
+        ];
+
+        let zip_error = |e: zip::result::ZipError| HwpError::EncodingError(e.to_string());
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, content) in parts {
+            zip.start_file(name, options).map_err(zip_error)?;
+            zip.write_all(content.as_bytes())?;
+        }
+
+        Ok(zip.finish().map_err(zip_error)?.into_inner())
+    }
+
+    fn format_metadata(&self, _doc_info: &DocInfo) -> Result<String> {
+        // Metadata goes in docProps/core.xml, which needs the whole document
+        Ok(String::new())
+    }
+
+    fn format_section(&self, section: &Section, _index: usize) -> Result<String> {
+        Ok(section
+            .paragraphs
+            .iter()
+            .map(|p| Self::paragraph_xml(p, None))
+            .collect())
+    }
+
+    fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
+        Ok(Self::paragraph_xml(paragraph, None))
+    }
+}
@@ -1,5 +1,10 @@
+use crate::bin_data::{extract_images, EmbeddedImage};
 use crate::formatters::{FormatOptions, OutputFormatter};
-use hwp_core::models::document::DocInfo;
+use crate::text_extractor::TextRun;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hwp_core::models::document::{CharShape, DocInfo};
+use hwp_core::models::section::{Hyperlink, Picture};
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::{HwpDocument, Result};
 
@@ -13,7 +18,183 @@ impl HtmlFormatter {
         Self { options }
     }
 
-    fn escape_html(text: &str) -> String {
+    /// Escape a paragraph's text, wrapping styled runs in `<span>` elements
+    ///
+    /// Runs come from the paragraph's char shape positions. Bold, italic,
+    /// underline and non-black text colors are emitted as inline CSS; runs
+    /// without any of these are left unwrapped.
+    pub fn styled_text(paragraph: &Paragraph, doc_info: &DocInfo) -> String {
+        Self::linked_text(paragraph, doc_info, &[])
+    }
+
+    /// Like [`styled_text`](Self::styled_text), with `links` wrapped in
+    /// `<a href>` elements
+    ///
+    /// `links` must be ordered and must not overlap, as
+    /// [`Section::paragraph_links`] returns them. Styled runs are split at
+    /// link boundaries so the elements nest; a link without display text
+    /// shows its URL.
+    pub fn linked_text(paragraph: &Paragraph, doc_info: &DocInfo, links: &[&Hyperlink]) -> String {
+        Self::runs_html(paragraph, doc_info, links, None)
+    }
+
+    /// Like [`linked_text`](Self::linked_text), with styled runs referencing
+    /// the classes of [`char_shape_css`](Self::char_shape_css) instead of
+    /// carrying inline CSS
+    pub fn classed_text(
+        paragraph: &Paragraph,
+        doc_info: &DocInfo,
+        links: &[&Hyperlink],
+        prefix: &str,
+    ) -> String {
+        Self::runs_html(paragraph, doc_info, links, Some(prefix))
+    }
+
+    /// CSS rules defining a `{prefix}cs-{id}` class for every character
+    /// shape that needs styling
+    pub fn char_shape_css(doc_info: &DocInfo, prefix: &str) -> String {
+        let mut css = String::new();
+        for (id, shape) in doc_info.char_shapes.iter().enumerate() {
+            let style = span_style(shape);
+            if !style.is_empty() {
+                css.push_str(&format!("        .{}cs-{} {{ {} }}\n", prefix, id, style));
+            }
+        }
+        css
+    }
+
+    /// Shared body of [`linked_text`](Self::linked_text) and
+    /// [`classed_text`](Self::classed_text); runs use classes with
+    /// `class_prefix` when it is given
+    fn runs_html(
+        paragraph: &Paragraph,
+        doc_info: &DocInfo,
+        links: &[&Hyperlink],
+        class_prefix: Option<&str>,
+    ) -> String {
+        let chars: Vec<char> = paragraph.text.chars().collect();
+
+        // (start, end, attribute) for each run; a paragraph without char
+        // shape records is left unstyled
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for run in TextRun::split(paragraph) {
+            let end = start + run.text.chars().count();
+            let style = if paragraph.char_shapes.is_empty() {
+                String::new()
+            } else {
+                doc_info
+                    .char_shapes
+                    .get(run.char_shape_id as usize)
+                    .map(span_style)
+                    .unwrap_or_default()
+            };
+            let attribute = match class_prefix {
+                _ if style.is_empty() => style,
+                Some(prefix) => format!("class=\"{}cs-{}\"", prefix, run.char_shape_id),
+                None => format!("style=\"{}\"", style),
+            };
+            runs.push((start, end, attribute));
+            start = end;
+        }
+
+        let mut html = String::new();
+        let mut links = links.iter().peekable();
+        let mut open: Option<&Hyperlink> = None;
+        for (start, end, attribute) in runs {
+            let mut position = start;
+            while position < end {
+                if open.is_none() {
+                    if let Some(link) = links.next_if(|l| l.char_offset <= position) {
+                        if link.text.is_empty() {
+                            html.push_str(&anchor(link));
+                            continue;
+                        }
+                        if is_safe_url(&link.url) {
+                            html.push_str(&format!(
+                                "<a href=\"{}\">",
+                                Self::escape_html(&link.url)
+                            ));
+                        }
+                        open = Some(link);
+                    }
+                }
+
+                // Stop at the end of the open link or the start of the next
+                let stop = match (open, links.peek()) {
+                    (Some(link), _) => link.end(),
+                    (None, Some(next)) => next.char_offset,
+                    (None, None) => end,
+                }
+                .clamp(position + 1, end);
+
+                let segment = Self::escape_html(&chars[position..stop].iter().collect::<String>());
+                if attribute.is_empty() {
+                    html.push_str(&segment);
+                } else {
+                    html.push_str(&format!("<span {}>{}</span>", attribute, segment));
+                }
+                position = stop;
+
+                if let Some(link) = open.filter(|link| link.end() <= position) {
+                    if is_safe_url(&link.url) {
+                        html.push_str("</a>");
+                    }
+                    open = None;
+                }
+            }
+        }
+
+        if open.is_some_and(|link| is_safe_url(&link.url)) {
+            html.push_str("</a>");
+        }
+        for link in links.filter(|l| l.text.is_empty()) {
+            html.push_str(&anchor(link));
+        }
+        html
+    }
+
+    /// `<img>` element for a picture
+    ///
+    /// The image is embedded as a data URI when its data is among `images`;
+    /// otherwise only a placeholder naming the BIN_DATA ID is emitted.
+    pub fn picture_element(picture: &Picture, images: &[EmbeddedImage]) -> String {
+        let alt = format!("[image {}]", picture.bin_data_id);
+        match images.iter().find(|image| image.id == picture.bin_data_id) {
+            Some(image) => format!(
+                "<img src=\"data:{};base64,{}\" alt=\"{}\">",
+                image.mime_type(),
+                BASE64.encode(&image.data),
+                alt
+            ),
+            None => format!("<img alt=\"{}\">", alt),
+        }
+    }
+
+    /// HTML comment listing every font the document references
+    ///
+    /// Fonts are listed in first-use order, each with the number of
+    /// characters drawn in it when the text uses it at all.
+    pub fn required_fonts_comment(document: &HwpDocument) -> String {
+        let usage = document.font_usage();
+        let mut comment = String::from("    <!-- Required fonts:\n");
+
+        for name in document.doc_info.unique_font_names() {
+            // "--" may not appear inside an HTML comment
+            let name = name.replace("--", "- -");
+            match usage.iter().find(|(used, _)| *used == name) {
+                Some((_, count)) => {
+                    comment.push_str(&format!("         {} ({} characters)\n", name, count))
+                }
+                None => comment.push_str(&format!("         {}\n", name)),
+            }
+        }
+
+        comment.push_str("    -->\n");
+        comment
+    }
+
+    pub fn escape_html(text: &str) -> String {
         text.chars()
             .map(|c| match c {
                 '&' => "&amp;".to_string(),
@@ -27,8 +208,71 @@ impl HtmlFormatter {
     }
 }
 
+/// An `<a href>` element showing its own URL, or just the URL when unsafe
+fn anchor(link: &Hyperlink) -> String {
+    let url = HtmlFormatter::escape_html(&link.url);
+    if !is_safe_url(&link.url) {
+        return url;
+    }
+    format!("<a href=\"{}\">{}</a>", url, url)
+}
+
+/// Whether a document URL may go into an `href`
+///
+/// Only http(s), mailto and relative URLs are allowed, so links such as
+/// `javascript:` from an untrusted file are rendered as plain text.
+fn is_safe_url(url: &str) -> bool {
+    // A colon before any '/', '?' or '#' starts a scheme; browsers ignore
+    // whitespace and control characters inside it
+    let Some(colon) = url.find(':') else {
+        return true;
+    };
+    let prefix = &url[..colon];
+    if prefix.contains(['/', '?', '#']) {
+        return true;
+    }
+    let scheme: String = prefix
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    matches!(scheme.as_str(), "http" | "https" | "mailto")
+}
+
+/// Inline CSS for a character shape, empty when it needs no styling
+fn span_style(shape: &CharShape) -> String {
+    let mut style = Vec::new();
+
+    if shape.is_bold() {
+        style.push("font-weight:bold".to_string());
+    }
+    if shape.is_italic() {
+        style.push("font-style:italic".to_string());
+    }
+    if shape.is_underlined() {
+        style.push("text-decoration:underline".to_string());
+        if shape.underline_color != 0 && shape.underline_color != shape.text_color {
+            style.push(format!(
+                "text-decoration-color:{}",
+                css_color(shape.underline_color)
+            ));
+        }
+    }
+    if shape.text_color != 0 {
+        style.push(format!("color:{}", css_color(shape.text_color)));
+    }
+
+    style.join(";")
+}
+
+/// Convert an HWP color (0x00BBGGRR) to a CSS hex color
+fn css_color(color: u32) -> String {
+    let [r, g, b, _] = color.to_le_bytes();
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
 impl HtmlFormatter {
-    fn get_default_css() -> &'static str {
+    pub fn get_default_css() -> &'static str {
         r#"
         body {
             font-family: 'Malgun Gothic', '맑은 고딕', sans-serif;
@@ -141,9 +385,19 @@ impl OutputFormatter for HtmlFormatter {
         );
         html.push_str("    <title>HWP Document</title>\n");
 
+        if self.options.list_required_fonts {
+            html.push_str(&Self::required_fonts_comment(document));
+        }
+
         // Add CSS styles
         html.push_str("    <style>\n");
         html.push_str(HtmlFormatter::get_default_css());
+        if !self.options.html_css_inline {
+            html.push_str(&Self::char_shape_css(
+                &document.doc_info,
+                &self.options.html_css_prefix,
+            ));
+        }
         html.push_str("    </style>\n");
 
         html.push_str("</head>\n");
@@ -157,6 +411,12 @@ impl OutputFormatter for HtmlFormatter {
         // Main content container
         html.push_str("    <div class=\"hwp-content\">\n");
 
+        let images = if self.options.html_embed_images {
+            extract_images(document)
+        } else {
+            Vec::new()
+        };
+
         // Format sections
         for (idx, section) in document.sections.iter().enumerate() {
             html.push_str(&format!(
@@ -165,13 +425,30 @@ impl OutputFormatter for HtmlFormatter {
             ));
 
             // Format paragraphs
-            for paragraph in &section.paragraphs {
-                if !paragraph.text.is_empty() {
-                    let escaped_text = Self::escape_html(&paragraph.text);
+            for (para_index, paragraph) in section.paragraphs.iter().enumerate() {
+                let pictures = section
+                    .pictures
+                    .iter()
+                    .filter(|picture| picture.paragraph_index == para_index);
+                let links = section.paragraph_links(para_index);
+                let mut styled_text = if self.options.html_css_inline {
+                    Self::linked_text(paragraph, &document.doc_info, &links)
+                } else {
+                    Self::classed_text(
+                        paragraph,
+                        &document.doc_info,
+                        &links,
+                        &self.options.html_css_prefix,
+                    )
+                };
+                for picture in pictures {
+                    styled_text.push_str(&Self::picture_element(picture, &images));
+                }
 
+                if !styled_text.is_empty() {
                     html.push_str(&format!(
                         "            <p class=\"hwp-paragraph\">{}</p>\n",
-                        escaped_text
+                        styled_text
                     ));
                 }
             }
@@ -236,9 +513,14 @@ impl OutputFormatter for HtmlFormatter {
             index
         ));
 
-        for paragraph in &section.paragraphs {
+        for (para_index, paragraph) in section.paragraphs.iter().enumerate() {
             if !paragraph.text.is_empty() {
-                let escaped_text = Self::escape_html(&paragraph.text);
+                // Without the document's DocInfo only the links are marked up
+                let escaped_text = Self::linked_text(
+                    paragraph,
+                    &DocInfo::default(),
+                    &section.paragraph_links(para_index),
+                );
                 html.push_str(&format!(
                     "    <p class=\"hwp-paragraph\">{}</p>\n",
                     escaped_text
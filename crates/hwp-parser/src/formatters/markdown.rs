@@ -1,8 +1,45 @@
-use super::{FormatOptions, OutputFormatter};
-use hwp_core::models::document::DocInfo;
+use super::html::HtmlFormatter;
+use super::{DocumentMetadata, FormatOptions, MarkdownFlavor, MarkdownTableStyle, OutputFormatter};
+use hwp_core::models::document::{DocInfo, ListItem, ListKind};
+use hwp_core::models::section::{BodyItem, Hyperlink, Table};
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::{HwpDocument, Result};
 
+/// Front matter written when `include_metadata` is set: the resolved
+/// metadata, then the version and page and section counts
+///
+/// The creation date is written as `date`, the key static site generators
+/// read.
+#[derive(serde::Serialize)]
+struct FrontMatter {
+    #[serde(flatten)]
+    metadata: DocumentMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    version: String,
+    pages: usize,
+    sections: usize,
+}
+
+impl FrontMatter {
+    /// The page count is the one stored in DocInfo, or counted from the
+    /// sections when the document leaves it at zero
+    fn new(doc: &HwpDocument, mut metadata: DocumentMetadata) -> Self {
+        let pages = match doc.doc_info.properties.total_page_count {
+            0 => doc.page_count(),
+            count => count as usize,
+        };
+
+        Self {
+            date: metadata.created.take(),
+            metadata,
+            version: doc.header.version.to_string(),
+            pages,
+            sections: doc.sections.len(),
+        }
+    }
+}
+
 /// Markdown formatter - converts HWP to Markdown format
 pub struct MarkdownFormatter {
     options: FormatOptions,
@@ -29,6 +66,22 @@ impl MarkdownFormatter {
         result
     }
 
+    /// Generate YAML front matter for static site generators
+    ///
+    /// With `include_metadata` the block also describes the file itself:
+    /// its format version and page and section counts.
+    fn generate_front_matter(&self, doc: &HwpDocument) -> Result<String> {
+        let metadata = DocumentMetadata::resolve(doc, &self.options);
+        let yaml = if self.options.include_metadata {
+            serde_yaml::to_string(&FrontMatter::new(doc, metadata))
+        } else {
+            serde_yaml::to_string(&metadata)
+        }
+        .map_err(|e| hwp_core::HwpError::EncodingError(e.to_string()))?;
+
+        Ok(format!("---\n{}---\n\n", yaml))
+    }
+
     /// Generate table of contents
     fn generate_toc(&self, doc: &HwpDocument) -> String {
         let mut toc = String::from("## Table of Contents\n\n");
@@ -48,15 +101,15 @@ impl MarkdownFormatter {
         toc
     }
 
-    /// Convert paragraph to Markdown with basic formatting
-    fn format_paragraph_markdown(&self, paragraph: &Paragraph) -> String {
-        if paragraph.text.is_empty() {
+    /// Convert paragraph text to Markdown with basic formatting
+    fn format_paragraph_markdown(&self, text: &str) -> String {
+        if text.is_empty() {
             return String::new();
         }
 
         // For now, return plain text
         // TODO: Detect and apply formatting (bold, italic, etc.)
-        let text = paragraph.text.trim();
+        let text = text.trim();
 
         // Check if it looks like a heading (simple heuristic)
         if text.len() < 100 && !text.contains('\n') {
@@ -68,6 +121,66 @@ impl MarkdownFormatter {
         }
     }
 
+    /// Paragraph text with its hyperlinks written as `[text](url)`
+    ///
+    /// Links are placed by character position, so this works on the
+    /// untrimmed paragraph text; a link without display text becomes an
+    /// autolink.
+    fn linked_text(text: &str, links: &[&Hyperlink]) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut markdown = String::with_capacity(text.len());
+        let mut position = 0;
+
+        for link in links {
+            let start = link.char_offset.min(chars.len());
+            if start < position {
+                continue;
+            }
+            markdown.extend(&chars[position..start]);
+
+            let url = link_destination(&link.url);
+            if link.text.is_empty() {
+                markdown.push_str(&format!("<{}>", url));
+                position = start;
+                continue;
+            }
+
+            let end = link.end().min(chars.len());
+            markdown.push('[');
+            for &c in &chars[start..end] {
+                if matches!(c, '[' | ']' | '\\') {
+                    markdown.push('\\');
+                }
+                markdown.push(c);
+            }
+            markdown.push_str(&format!("]({})", url));
+            position = end;
+        }
+
+        markdown.extend(&chars[position..]);
+        markdown
+    }
+
+    /// Render a table in the configured style, followed by a blank line
+    fn render_table(&self, table: &Table) -> String {
+        let pipe = match self.options.markdown_table_style {
+            MarkdownTableStyle::Pipe => true,
+            MarkdownTableStyle::Html => false,
+            MarkdownTableStyle::Auto => self.options.markdown_flavor != MarkdownFlavor::CommonMark,
+        };
+        let rendered = if pipe {
+            pipe_table(table)
+        } else {
+            html_table(table)
+        };
+
+        if rendered.is_empty() {
+            rendered
+        } else {
+            format!("{}\n", rendered)
+        }
+    }
+
     /// Check if text looks like a list item
     fn is_list_item(&self, text: &str) -> bool {
         let trimmed = text.trim_start();
@@ -108,10 +221,140 @@ impl MarkdownFormatter {
     }
 }
 
+/// Item numbers of the numbered list being written, one per level
+#[derive(Default)]
+struct ListNumbers {
+    /// NUMBERING id of the list, if one is open
+    id: Option<u16>,
+    /// Last number written at each level, `None` for levels not entered
+    numbers: Vec<Option<u32>>,
+}
+
+impl ListNumbers {
+    /// Number of the next item at `item`'s level
+    ///
+    /// A different numbering, or a level not entered yet, starts at the
+    /// level's start number; going back out to a level closes the levels
+    /// below it, so they start over when next entered.
+    fn next(&mut self, item: &ListItem, doc_info: &DocInfo) -> u32 {
+        if self.id != Some(item.id) {
+            self.reset();
+            self.id = Some(item.id);
+        }
+
+        let level = item.level as usize;
+        self.numbers.truncate(level + 1);
+        self.numbers.resize(level + 1, None);
+        let number = match self.numbers[level] {
+            Some(previous) => previous + 1,
+            None => doc_info.list_start_number(item.id, item.level),
+        };
+        self.numbers[level] = Some(number);
+        number
+    }
+
+    /// End the list, so the next one starts over
+    fn reset(&mut self) {
+        self.id = None;
+        self.numbers.clear();
+    }
+}
+
+/// Render a table as a pipe table, its first row as the header
+///
+/// Pipe tables cannot merge cells, so a merged cell's text is in its
+/// top-left position and the positions it covers are empty. Nested tables
+/// are left out.
+fn pipe_table(table: &Table) -> String {
+    let grid = table.text_grid();
+    let (header, body) = match grid.split_first() {
+        Some((header, body)) if !header.is_empty() => (header, body),
+        _ => return String::new(),
+    };
+
+    let row = |cells: &[String]| {
+        let cells: Vec<String> = cells.iter().map(|c| c.trim().replace('|', "\\|")).collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+    let mut markdown = row(header);
+    markdown.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+    for cells in body {
+        markdown.push_str(&row(cells));
+    }
+    markdown
+}
+
+/// Render a table as an HTML block, which CommonMark passes through
+///
+/// Merged cells keep their spans and nested tables are rendered inside
+/// their cells. The block has no blank lines, which would end it.
+fn html_table(table: &Table) -> String {
+    if table.cells.is_empty() {
+        return String::new();
+    }
+
+    let mut cells: Vec<_> = table.cells.iter().collect();
+    cells.sort_by_key(|cell| (cell.row, cell.col));
+
+    let mut html = String::from("<table>\n");
+    let mut row = None;
+    for cell in cells {
+        if row != Some(cell.row) {
+            if row.is_some() {
+                html.push_str("</tr>\n");
+            }
+            html.push_str("<tr>");
+            row = Some(cell.row);
+        }
+        let mut attributes = String::new();
+        if cell.row_span > 1 {
+            attributes.push_str(&format!(" rowspan=\"{}\"", cell.row_span));
+        }
+        if cell.col_span > 1 {
+            attributes.push_str(&format!(" colspan=\"{}\"", cell.col_span));
+        }
+        let text: Vec<String> = cell
+            .paragraphs
+            .iter()
+            .map(|p| HtmlFormatter::escape_html(p.text.trim()))
+            .filter(|text| !text.is_empty())
+            .collect();
+        html.push_str(&format!("<td{}>{}", attributes, text.join("<br>")));
+        for nested in &cell.tables {
+            html.push('\n');
+            html.push_str(&html_table(nested));
+        }
+        html.push_str("</td>");
+    }
+    html.push_str("</tr>\n</table>\n");
+    html
+}
+
+/// Percent-encode the characters that would end a Markdown link destination
+fn link_destination(url: &str) -> String {
+    let mut destination = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            ' ' => destination.push_str("%20"),
+            '(' => destination.push_str("%28"),
+            ')' => destination.push_str("%29"),
+            '<' => destination.push_str("%3C"),
+            '>' => destination.push_str("%3E"),
+            c => destination.push(c),
+        }
+    }
+    destination
+}
+
 impl OutputFormatter for MarkdownFormatter {
     fn format_document(&self, doc: &HwpDocument) -> Result<String> {
         let mut markdown = String::new();
 
+        // Add front matter if requested
+        if self.options.markdown_front_matter || self.options.include_metadata {
+            markdown.push_str(&self.generate_front_matter(doc)?);
+        }
+
         // Add document title if available
         // TODO: Extract from DocInfo when available
         markdown.push_str("# Document\n\n");
@@ -129,9 +372,23 @@ impl OutputFormatter for MarkdownFormatter {
                     markdown.push_str(&format!("## Section {}\n\n", index + 1));
                 }
 
-                // Process paragraphs
+                // Process paragraphs, with tables after their anchors
                 let mut in_list = false;
-                for paragraph in &section.paragraphs {
+                let mut numbers = ListNumbers::default();
+                for item in section.body_items() {
+                    let (para_index, paragraph) = match item {
+                        BodyItem::Paragraph(para_index, paragraph) => (para_index, paragraph),
+                        BodyItem::Table(table) => {
+                            if in_list {
+                                markdown.push('\n');
+                                in_list = false;
+                            }
+                            numbers.reset();
+                            markdown.push_str(&self.render_table(table));
+                            continue;
+                        }
+                    };
+
                     if paragraph.text.is_empty() {
                         if in_list {
                             markdown.push('\n');
@@ -140,7 +397,25 @@ impl OutputFormatter for MarkdownFormatter {
                         continue;
                     }
 
-                    let text = paragraph.text.trim();
+                    let linked =
+                        Self::linked_text(&paragraph.text, &section.paragraph_links(para_index));
+                    let text = linked.trim();
+
+                    // Lists defined by the paragraph shape; HWP generates
+                    // the numbers and bullets, so the text has none
+                    if let Some(item) = doc.doc_info.paragraph_list(paragraph) {
+                        let marker = match item.kind {
+                            ListKind::Numbered => {
+                                format!("{}.", numbers.next(&item, &doc.doc_info))
+                            }
+                            ListKind::Bulleted => "-".to_string(),
+                        };
+                        markdown.push_str(&"    ".repeat(item.level as usize));
+                        markdown.push_str(&format!("{} {}\n", marker, text));
+                        in_list = true;
+                        continue;
+                    }
+                    numbers.reset();
 
                     // Check if this is a list item
                     if self.is_list_item(text) {
@@ -152,7 +427,7 @@ impl OutputFormatter for MarkdownFormatter {
                             markdown.push('\n');
                             in_list = false;
                         }
-                        markdown.push_str(&self.format_paragraph_markdown(paragraph));
+                        markdown.push_str(&self.format_paragraph_markdown(text));
                         markdown.push('\n');
                     }
                 }
@@ -183,9 +458,11 @@ impl OutputFormatter for MarkdownFormatter {
         markdown.push_str(&format!("## Section {}\n\n", index + 1));
 
         // Process paragraphs
-        for paragraph in &section.paragraphs {
+        for (para_index, paragraph) in section.paragraphs.iter().enumerate() {
             if !paragraph.text.is_empty() {
-                markdown.push_str(&self.format_paragraph_markdown(paragraph));
+                let linked =
+                    Self::linked_text(&paragraph.text, &section.paragraph_links(para_index));
+                markdown.push_str(&self.format_paragraph_markdown(&linked));
                 markdown.push('\n');
             }
         }
@@ -194,6 +471,6 @@ impl OutputFormatter for MarkdownFormatter {
     }
 
     fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
-        Ok(self.format_paragraph_markdown(paragraph))
+        Ok(self.format_paragraph_markdown(&paragraph.text))
     }
 }
@@ -1,10 +1,14 @@
+pub mod csv;
+pub mod docx;
 pub mod html;
 pub mod json;
 pub mod markdown;
 pub mod plain_text;
+pub mod rtf;
 pub mod yaml;
 
 use hwp_core::models::document::DocInfo;
+use hwp_core::models::section::SectionPart;
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::{HwpDocument, Result};
 
@@ -21,6 +25,16 @@ pub trait OutputFormatter {
 
     /// Format a paragraph
     fn format_paragraph(&self, paragraph: &Paragraph, index: usize) -> Result<String>;
+
+    /// Format the entire document as the bytes of an output file
+    ///
+    /// Text formats return the UTF-8 encoding of [`format_document`];
+    /// binary formats such as DOCX override this.
+    ///
+    /// [`format_document`]: OutputFormatter::format_document
+    fn format_bytes(&self, doc: &HwpDocument) -> Result<Vec<u8>> {
+        Ok(self.format_document(doc)?.into_bytes())
+    }
 }
 
 /// Options for controlling output formatting
@@ -32,12 +46,38 @@ pub struct FormatOptions {
     pub json_include_styles: bool,
     /// Line wrap width for plain text
     pub text_width: Option<usize>,
-    /// Preserve page breaks in plain text
+    /// Write a form feed (U+000C) before paragraphs that start with a hard
+    /// page break in plain text
     pub text_page_breaks: bool,
+    /// Render superscript/subscript runs with Unicode script characters
+    pub text_unicode_scripts: bool,
+    /// Parts of each section in plain text output, in order
+    pub text_section_order: Vec<SectionPart>,
     /// Markdown flavor (CommonMark, GFM, etc.)
     pub markdown_flavor: MarkdownFlavor,
     /// Generate table of contents for Markdown
     pub markdown_toc: bool,
+    /// Prepend YAML front matter to Markdown output
+    pub markdown_front_matter: bool,
+    /// How Markdown output writes tables
+    pub markdown_table_style: MarkdownTableStyle,
+    /// Field delimiter for CSV output
+    pub csv_delimiter: char,
+    /// Quote character for CSV fields that need quoting
+    pub csv_quote_char: char,
+    /// List the fonts the document references in HTML output
+    pub list_required_fonts: bool,
+    /// Embed pictures in HTML output as base64 data URIs instead of
+    /// placeholders
+    pub html_embed_images: bool,
+    /// Style HTML character runs with inline `style` attributes; when
+    /// false, runs reference classes defined in the document's `<style>`
+    /// block instead
+    pub html_css_inline: bool,
+    /// Prefix of the CSS classes generated for character shapes
+    pub html_css_prefix: String,
+    /// Source file name, used as a fallback document title
+    pub source_name: Option<String>,
     /// Include metadata in output
     pub include_metadata: bool,
     /// Include style information
@@ -51,14 +91,80 @@ impl Default for FormatOptions {
             json_include_styles: false,
             text_width: None,
             text_page_breaks: false,
+            text_unicode_scripts: false,
+            text_section_order: SectionPart::DEFAULT_ORDER.to_vec(),
             markdown_flavor: MarkdownFlavor::CommonMark,
             markdown_toc: false,
+            markdown_front_matter: false,
+            markdown_table_style: MarkdownTableStyle::Auto,
+            csv_delimiter: ',',
+            csv_quote_char: '"',
+            list_required_fonts: false,
+            html_embed_images: false,
+            html_css_inline: true,
+            html_css_prefix: "hwp-".to_string(),
+            source_name: None,
             include_metadata: false,
             include_styles: false,
         }
     }
 }
 
+/// Document metadata resolved from content and format options
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct DocumentMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fonts: Vec<String>,
+}
+
+impl DocumentMetadata {
+    /// Resolve metadata for a document
+    ///
+    /// Title, author and creation date come from the document summary when
+    /// present. Otherwise the title is the first non-empty paragraph (the de
+    /// facto heading), falling back to `options.source_name`.
+    pub fn resolve(doc: &HwpDocument, options: &FormatOptions) -> Self {
+        let summary = doc.summary.as_ref();
+        let summary_title = summary
+            .and_then(|s| s.title.as_deref())
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .map(str::to_string);
+
+        let title = summary_title
+            .or_else(|| {
+                doc.sections
+                    .iter()
+                    .flat_map(|s| &s.paragraphs)
+                    .map(|p| p.text.trim())
+                    .find(|text| !text.is_empty())
+                    .and_then(|text| text.lines().next())
+                    .map(|line| line.trim().to_string())
+            })
+            .or_else(|| options.source_name.clone());
+
+        let mut fonts = Vec::new();
+        for face_name in &doc.doc_info.face_names {
+            if !fonts.contains(&face_name.name) {
+                fonts.push(face_name.name.clone());
+            }
+        }
+
+        Self {
+            title,
+            author: summary.and_then(|s| s.author.clone()),
+            created: summary.and_then(|s| s.created.clone()),
+            fonts,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MarkdownFlavor {
     CommonMark,
@@ -66,6 +172,17 @@ pub enum MarkdownFlavor {
     MultiMarkdown,
 }
 
+/// How the Markdown formatter writes tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkdownTableStyle {
+    /// Pipe tables for flavors that have them, HTML for CommonMark
+    Auto,
+    /// Pipe tables, with the first row as the header
+    Pipe,
+    /// HTML `<table>` blocks, which keep merged cells and nested tables
+    Html,
+}
+
 /// Available output formats
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -74,6 +191,9 @@ pub enum OutputFormat {
     Markdown,
     Html,
     Yaml,
+    Rtf,
+    Csv,
+    Docx,
 }
 
 impl OutputFormat {
@@ -85,19 +205,9 @@ impl OutputFormat {
             OutputFormat::Markdown => Box::new(markdown::MarkdownFormatter::new(options)),
             OutputFormat::Html => Box::new(html::HtmlFormatter::new(options)),
             OutputFormat::Yaml => Box::new(yaml::YamlFormatter::new(options)),
-        }
-    }
-
-    /// Parse format from string
-    #[allow(clippy::should_implement_trait)]
-    pub fn from_str(s: &str) -> Option<Self> {
-        match s.to_lowercase().as_str() {
-            "json" => Some(OutputFormat::Json),
-            "text" | "txt" | "plain" => Some(OutputFormat::PlainText),
-            "markdown" | "md" => Some(OutputFormat::Markdown),
-            "html" | "htm" => Some(OutputFormat::Html),
-            "yaml" | "yml" => Some(OutputFormat::Yaml),
-            _ => None,
+            OutputFormat::Rtf => Box::new(rtf::RtfFormatter::new(options)),
+            OutputFormat::Csv => Box::new(csv::CsvFormatter::new(options)),
+            OutputFormat::Docx => Box::new(docx::DocxFormatter::new(options)),
         }
     }
 
@@ -109,6 +219,55 @@ impl OutputFormat {
             OutputFormat::Markdown => "md",
             OutputFormat::Html => "html",
             OutputFormat::Yaml => "yaml",
+            OutputFormat::Rtf => "rtf",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Docx => "docx",
         }
     }
 }
+
+/// Error returned when a name matches no [`OutputFormat`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown output format '{name}'")]
+pub struct ParseOutputFormatError {
+    /// The name that was not recognized
+    pub name: String,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = ParseOutputFormatError;
+
+    /// Parse a format name or one of its aliases, ignoring case
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "text" | "txt" | "plain" => Ok(OutputFormat::PlainText),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            "html" | "htm" => Ok(OutputFormat::Html),
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            "rtf" => Ok(OutputFormat::Rtf),
+            "csv" => Ok(OutputFormat::Csv),
+            "docx" => Ok(OutputFormat::Docx),
+            _ => Err(ParseOutputFormatError {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    /// The format's canonical name, as accepted by `FromStr`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            OutputFormat::Json => "json",
+            OutputFormat::PlainText => "text",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Html => "html",
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Rtf => "rtf",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Docx => "docx",
+        };
+        f.write_str(name)
+    }
+}
@@ -1,6 +1,7 @@
 use super::{FormatOptions, OutputFormatter};
 use crate::text_extractor::TextExtractor;
 use hwp_core::models::document::DocInfo;
+use hwp_core::models::section::{display_width, BodyItem, SectionPart};
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::{HwpDocument, Result};
 
@@ -16,55 +17,92 @@ impl PlainTextFormatter {
 
     fn wrap_text(&self, text: &str) -> String {
         if let Some(width) = self.options.text_width {
-            // Simple word wrapping
             let mut result = String::new();
             for line in text.lines() {
-                if line.len() <= width {
-                    result.push_str(line);
-                    result.push('\n');
-                } else {
-                    let mut current_line = String::new();
-                    for word in line.split_whitespace() {
-                        if current_line.is_empty() {
-                            current_line = word.to_string();
-                        } else if current_line.len() + 1 + word.len() <= width {
-                            current_line.push(' ');
-                            current_line.push_str(word);
-                        } else {
-                            result.push_str(&current_line);
-                            result.push('\n');
-                            current_line = word.to_string();
-                        }
-                    }
-                    if !current_line.is_empty() {
-                        result.push_str(&current_line);
-                        result.push('\n');
-                    }
-                }
+                wrap_line(line, width, &mut result);
             }
             result
         } else {
             text.to_string()
         }
     }
+
+    /// Render a section's parts in the configured order, separated by a
+    /// blank line
+    fn render_section(&self, section: &Section, doc_info: Option<&DocInfo>) -> String {
+        let mut text = String::new();
+
+        for part in &self.options.text_section_order {
+            let part_text = match part {
+                SectionPart::Body => self.render_body(section, doc_info),
+                SectionPart::Notes => self.wrap_text(section.notes_text().trim_start_matches('\n')),
+                SectionPart::HeadersFooters => self.wrap_text(&section.headers_footers_text()),
+            };
+            if part_text.is_empty() {
+                continue;
+            }
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&part_text);
+        }
+
+        text
+    }
+
+    /// Render a section's paragraphs with tables drawn after their anchors.
+    /// Only paragraph text is wrapped; wrapping would break the table grid.
+    fn render_body(&self, section: &Section, doc_info: Option<&DocInfo>) -> String {
+        let mut text = String::new();
+
+        for item in section.body_items() {
+            let (index, paragraph) = match item {
+                BodyItem::Paragraph(index, paragraph) => (index, paragraph),
+                BodyItem::Table(table) => {
+                    text.push_str(&table.render_ascii());
+                    continue;
+                }
+            };
+            if self.options.text_page_breaks && paragraph.starts_new_page() {
+                text.push('\u{000C}');
+            }
+            if !paragraph.text.is_empty() {
+                let paragraph_text = match doc_info {
+                    Some(doc_info) if self.options.text_unicode_scripts => {
+                        TextExtractor::text_with_scripts(paragraph, doc_info)
+                    }
+                    _ => paragraph.text.clone(),
+                };
+                let paragraph_text = section.with_note_markers(index, &paragraph_text);
+                text.push_str(&self.wrap_text(&paragraph_text));
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+            }
+        }
+
+        text
+    }
 }
 
 impl OutputFormatter for PlainTextFormatter {
     fn format_document(&self, doc: &HwpDocument) -> Result<String> {
-        // Use existing TextExtractor for plain text
-        let text = TextExtractor::extract_from_document(doc)?;
-
-        // Apply text wrapping if configured
-        let formatted = self.wrap_text(&text);
-
-        // Add page breaks if configured
-        if self.options.text_page_breaks {
-            // TODO: Detect and preserve page breaks from the document
-            // For now, just return the formatted text
-            Ok(formatted)
-        } else {
-            Ok(formatted)
+        // Without wrapping, script conversion, page breaks or a custom part
+        // order, the TextExtractor output is already final
+        if self.options.text_width.is_none()
+            && !self.options.text_unicode_scripts
+            && !self.options.text_page_breaks
+            && self.options.text_section_order == SectionPart::DEFAULT_ORDER
+        {
+            return TextExtractor::extract_with_tables(doc);
         }
+
+        let text: String = doc
+            .sections
+            .iter()
+            .map(|s| self.render_section(s, Some(&doc.doc_info)))
+            .collect();
+        Ok(text.trim().to_string())
     }
 
     fn format_metadata(&self, _doc_info: &DocInfo) -> Result<String> {
@@ -73,19 +111,61 @@ impl OutputFormatter for PlainTextFormatter {
     }
 
     fn format_section(&self, section: &Section, _index: usize) -> Result<String> {
-        let mut text = String::new();
+        Ok(self.render_section(section, None))
+    }
 
-        for paragraph in &section.paragraphs {
-            if !paragraph.text.is_empty() {
-                text.push_str(&self.wrap_text(&paragraph.text));
-                text.push('\n');
-            }
+    fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
+        Ok(self.wrap_text(&paragraph.text))
+    }
+}
+
+/// Word-wrap one line to `width` terminal columns, appending it to `out`
+///
+/// Lines break on whitespace. A word wider than the line (a long URL, or
+/// Hangul written without spaces) is split between characters instead.
+fn wrap_line(line: &str, width: usize, out: &mut String) {
+    let width = width.max(1);
+    if display_width(line) <= width {
+        out.push_str(line);
+        out.push('\n');
+        return;
+    }
+
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in line.split_whitespace() {
+        let word_width = display_width(word);
+
+        if current_width > 0 && current_width + 1 + word_width <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_width += 1 + word_width;
+            continue;
         }
 
-        Ok(text)
+        if current_width > 0 {
+            out.push_str(&current);
+            out.push('\n');
+            current.clear();
+            current_width = 0;
+        }
+
+        for ch in word.chars() {
+            let ch_width = display_width(ch.encode_utf8(&mut [0; 4]));
+            if current_width > 0 && current_width + ch_width > width {
+                out.push_str(&current);
+                out.push('\n');
+                current.clear();
+                current_width = 0;
+            }
+            current.push(ch);
+            current_width += ch_width;
+        }
     }
 
-    fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
-        Ok(self.wrap_text(&paragraph.text))
+    if current_width > 0 {
+        out.push_str(&current);
+        out.push('\n');
     }
 }
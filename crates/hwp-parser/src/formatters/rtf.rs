@@ -0,0 +1,189 @@
+use crate::formatters::{FormatOptions, OutputFormatter};
+use crate::text_extractor::TextRun;
+use hwp_core::models::document::{CharShape, DocInfo, HwpUnit};
+use hwp_core::models::{Paragraph, Section};
+use hwp_core::{HwpDocument, Result};
+
+/// RTF formatter for HWP documents
+///
+/// Non-ASCII text is written as `\uN?` escapes, so the output is plain
+/// ASCII and opens in Word and other RTF readers without a code page.
+pub struct RtfFormatter {
+    options: FormatOptions,
+}
+
+impl RtfFormatter {
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escape text for an RTF body
+    ///
+    /// Characters outside ASCII become `\uN?` with N the signed UTF-16 code
+    /// unit; characters outside the BMP are written as a surrogate pair.
+    pub fn escape_rtf(text: &str) -> String {
+        let mut rtf = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\\' => rtf.push_str("\\\\"),
+                '{' => rtf.push_str("\\{"),
+                '}' => rtf.push_str("\\}"),
+                '\t' => rtf.push_str("\\tab "),
+                '\n' => rtf.push_str("\\line "),
+                c if c.is_ascii_control() => {}
+                c if c.is_ascii() => rtf.push(c),
+                c => {
+                    for unit in c.encode_utf16(&mut [0; 2]) {
+                        rtf.push_str(&format!("\\u{}?", *unit as i16));
+                    }
+                }
+            }
+        }
+        rtf
+    }
+
+    /// Write a paragraph as a `\pard ... \par` group
+    ///
+    /// The left margin and first-line indent come from the paragraph's
+    /// shape; bold, italic, underline and text color come from its char
+    /// shape runs. `colors` maps each color in the color table to its
+    /// index, as built by [`color_table`].
+    fn paragraph_rtf(paragraph: &Paragraph, doc_info: Option<&DocInfo>, colors: &[u32]) -> String {
+        let mut rtf = String::from("\\pard");
+
+        let para_shape =
+            doc_info.and_then(|d| d.para_shapes.get(paragraph.header.para_shape_id as usize));
+        if let Some(shape) = para_shape {
+            if shape.left_margin != HwpUnit(0) {
+                rtf.push_str(&format!("\\li{}", twips(shape.left_margin)));
+            }
+            if shape.indent != HwpUnit(0) {
+                rtf.push_str(&format!("\\fi{}", twips(shape.indent)));
+            }
+        }
+        rtf.push(' ');
+
+        let doc_info = match doc_info {
+            Some(doc_info) if !paragraph.char_shapes.is_empty() => doc_info,
+            _ => {
+                rtf.push_str(&Self::escape_rtf(&paragraph.text));
+                rtf.push_str("\\par\n");
+                return rtf;
+            }
+        };
+
+        for run in TextRun::split(paragraph) {
+            let segment = Self::escape_rtf(&run.text);
+            let control_words = doc_info
+                .char_shapes
+                .get(run.char_shape_id as usize)
+                .map(|shape| run_control_words(shape, colors))
+                .unwrap_or_default();
+
+            if control_words.is_empty() {
+                rtf.push_str(&segment);
+            } else {
+                rtf.push_str(&format!("{{{} {}}}", control_words, segment));
+            }
+        }
+
+        rtf.push_str("\\par\n");
+        rtf
+    }
+}
+
+/// Control words for a character shape, empty when it needs no styling
+fn run_control_words(shape: &CharShape, colors: &[u32]) -> String {
+    let mut words = String::new();
+
+    if shape.is_bold() {
+        words.push_str("\\b");
+    }
+    if shape.is_italic() {
+        words.push_str("\\i");
+    }
+    if shape.is_underlined() {
+        words.push_str("\\ul");
+    }
+    if shape.text_color != 0 {
+        if let Some(index) = colors.iter().position(|&c| c == shape.text_color) {
+            // Index 0 of the table is the default color
+            words.push_str(&format!("\\cf{}", index + 1));
+        }
+    }
+
+    words
+}
+
+/// Distinct non-black text colors used by the document's char shapes
+fn color_table(doc_info: &DocInfo) -> Vec<u32> {
+    let mut colors = Vec::new();
+    for shape in &doc_info.char_shapes {
+        if shape.text_color != 0 && !colors.contains(&shape.text_color) {
+            colors.push(shape.text_color);
+        }
+    }
+    colors
+}
+
+/// Convert HWPUNIT (1/7200 inch) to twips (1/1440 inch)
+fn twips(value: HwpUnit) -> i32 {
+    value.0 / 5
+}
+
+impl OutputFormatter for RtfFormatter {
+    fn format_document(&self, document: &HwpDocument) -> Result<String> {
+        let doc_info = &document.doc_info;
+        let colors = color_table(doc_info);
+
+        let mut rtf = String::from("{\\rtf1\\ansi\\ansicpg949\\deff0\\uc1\n");
+
+        let font = doc_info
+            .face_names
+            .first()
+            .map_or("Malgun Gothic", |f| f.name.as_str());
+        rtf.push_str(&format!(
+            "{{\\fonttbl{{\\f0\\fnil\\fcharset129 {};}}}}\n",
+            Self::escape_rtf(font)
+        ));
+
+        rtf.push_str("{\\colortbl;");
+        for color in &colors {
+            let [r, g, b, _] = color.to_le_bytes();
+            rtf.push_str(&format!("\\red{}\\green{}\\blue{};", r, g, b));
+        }
+        rtf.push_str("}\n");
+
+        if self.options.include_metadata {
+            rtf.push_str(&self.format_metadata(doc_info)?);
+        }
+
+        for section in &document.sections {
+            for paragraph in &section.paragraphs {
+                rtf.push_str(&Self::paragraph_rtf(paragraph, Some(doc_info), &colors));
+            }
+        }
+
+        rtf.push_str("}\n");
+        Ok(rtf)
+    }
+
+    fn format_metadata(&self, doc_info: &DocInfo) -> Result<String> {
+        Ok(format!(
+            "{{\\info{{\\nofpages{}}}{{\\nofchars{}}}}}\n",
+            doc_info.properties.total_page_count, doc_info.properties.total_character_count
+        ))
+    }
+
+    fn format_section(&self, section: &Section, _index: usize) -> Result<String> {
+        Ok(section
+            .paragraphs
+            .iter()
+            .map(|p| Self::paragraph_rtf(p, None, &[]))
+            .collect())
+    }
+
+    fn format_paragraph(&self, paragraph: &Paragraph, _index: usize) -> Result<String> {
+        Ok(Self::paragraph_rtf(paragraph, None, &[]))
+    }
+}
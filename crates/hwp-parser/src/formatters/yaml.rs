@@ -180,7 +180,7 @@ impl OutputFormatter for YamlFormatter {
             .sections
             .iter()
             .flat_map(|s| &s.paragraphs)
-            .map(|p| p.text.len())
+            .map(|p| p.char_len())
             .sum();
 
         doc_value["statistics"] = json!({
@@ -0,0 +1,140 @@
+use crate::cfb::{parse_cfb_bytes, CfbHeader};
+use crate::compression::{decompress_as, detect_compression};
+use crate::parser::header;
+use crate::reader::ByteReader;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+
+/// Streams every HWP 5.0 file must contain
+pub const REQUIRED_STREAMS: &[&str] = &["FileHeader", "DocInfo", "BodyText/Section0"];
+
+/// Result of checking one stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityStatus {
+    Ok,
+    Corrupt,
+    Missing,
+}
+
+/// Integrity finding for one stream, or for the container itself
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub stream_name: String,
+    pub status: IntegrityStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+}
+
+impl IntegrityReport {
+    fn ok(stream_name: &str) -> Self {
+        Self {
+            stream_name: stream_name.to_string(),
+            status: IntegrityStatus::Ok,
+            issue: None,
+        }
+    }
+
+    fn corrupt(stream_name: &str, issue: impl ToString) -> Self {
+        Self {
+            stream_name: stream_name.to_string(),
+            status: IntegrityStatus::Corrupt,
+            issue: Some(issue.to_string()),
+        }
+    }
+
+    /// Whether the stream passed its checks
+    pub fn is_ok(&self) -> bool {
+        self.status == IntegrityStatus::Ok
+    }
+}
+
+/// Check the container structure and every stream of an HWP 5.0 file
+///
+/// The CFB header is validated, every stream's sector chain is checked
+/// against its declared size, the FileHeader signature is verified and,
+/// for compressed documents, DocInfo and each BodyText section must
+/// decompress. Every problem found is reported rather than stopping at the
+/// first; the container header is reported as `"CFB"`.
+pub fn check_integrity(data: &[u8]) -> Vec<IntegrityReport> {
+    let mut reports = Vec::new();
+
+    match CfbHeader::from_reader(&mut Cursor::new(data)).and_then(|h| h.validate()) {
+        Ok(()) => reports.push(IntegrityReport::ok("CFB")),
+        Err(e) => reports.push(IntegrityReport::corrupt("CFB", e)),
+    }
+
+    // Without a directory there are no streams to check
+    let mut container = match parse_cfb_bytes(data) {
+        Ok(container) => container,
+        Err(e) => {
+            if reports[0].is_ok() {
+                reports[0] = IntegrityReport::corrupt("CFB", e);
+            }
+            return reports;
+        }
+    };
+
+    for name in REQUIRED_STREAMS {
+        if !container.has_stream(name) {
+            reports.push(IntegrityReport {
+                stream_name: name.to_string(),
+                status: IntegrityStatus::Missing,
+                issue: Some("Stream not found".to_string()),
+            });
+        }
+    }
+
+    let mut cursor = Cursor::new(data);
+    let mut compressed = None;
+    for name in container.list_streams() {
+        if let Err(e) = container.verify_chain(&name) {
+            reports.push(IntegrityReport::corrupt(&name, e));
+            continue;
+        }
+
+        let stream = match container.read_stream(&mut cursor, &name) {
+            Ok(stream) => stream,
+            Err(e) => {
+                reports.push(IntegrityReport::corrupt(&name, e));
+                continue;
+            }
+        };
+
+        let result = if name == "FileHeader" {
+            header::parse_header(&mut ByteReader::new(stream.as_bytes())).map(|header| {
+                compressed = Some(header.is_compressed());
+            })
+        } else {
+            Ok(())
+        };
+        reports.push(match result {
+            Ok(()) => IntegrityReport::ok(&name),
+            Err(e) => IntegrityReport::corrupt(&name, e),
+        });
+    }
+
+    // Record streams can only be checked once the FileHeader says whether
+    // they are compressed
+    if compressed == Some(true) {
+        for report in reports.iter_mut().filter(|r| r.is_ok()) {
+            let name = &report.stream_name;
+            if name != "DocInfo" && !name.starts_with("BodyText/Section") {
+                continue;
+            }
+            let result = match container.get_stream(name) {
+                Some(stream) => decompress_as(
+                    stream.as_bytes(),
+                    detect_compression(stream.as_bytes(), true),
+                ),
+                None => continue,
+            };
+            if let Err(e) = result {
+                report.status = IntegrityStatus::Corrupt;
+                report.issue = Some(e.to_string());
+            }
+        }
+    }
+
+    reports
+}
@@ -1,22 +1,91 @@
+pub mod annotations;
+pub mod bin_data;
 pub mod cfb;
 pub mod compression;
+pub mod file;
 pub mod formatters;
+pub mod integrity;
 pub mod parser;
 pub mod reader;
+pub mod redact;
+pub mod search;
 pub mod text_extractor;
 pub mod validator;
 
-pub use formatters::{FormatOptions, MarkdownFlavor, OutputFormat, OutputFormatter};
-use hwp_core::{HwpDocument, Result};
-pub use text_extractor::{FormattedParagraph, FormattedText, TextExtractor};
+pub use file::HwpFile;
+pub use formatters::{
+    DocumentMetadata, FormatOptions, MarkdownFlavor, MarkdownTableStyle, OutputFormat,
+    OutputFormatter, ParseOutputFormatError,
+};
+pub use hwp_core::models::record::{Record, RecordHeader, RecordNode};
+use hwp_core::{HwpDocument, HwpError, Result};
+pub use parser::record::{iter_records, RecordIter, RecordParser};
+pub use parser::ParseOptions;
+use std::io::{Read, Seek};
+use std::path::Path;
+pub use text_extractor::{FormattedParagraph, FormattedText, TextExtractor, TextRun};
+pub use validator::RecordContext;
 
 /// Parse an HWP file from raw bytes
 pub fn parse(data: &[u8]) -> Result<HwpDocument> {
     parser::parse(data)
 }
 
+/// Parse an HWP file from raw bytes with explicit parse limits
+pub fn parse_with_options(data: &[u8], options: &ParseOptions) -> Result<HwpDocument> {
+    parser::parse_with_options(data, options)
+}
+
+/// Parse an HWP file from a reader without loading it into memory first
+///
+/// Streams are read from the reader as the parser needs them, which suits
+/// large files opened from disk or fetched over the network.
+pub fn parse_reader<R: Read + Seek>(reader: R) -> Result<HwpDocument> {
+    parse_reader_with_options(reader, &ParseOptions::default())
+}
+
+/// Parse an HWP file from a reader with explicit parse limits
+pub fn parse_reader_with_options<R: Read + Seek>(
+    reader: R,
+    options: &ParseOptions,
+) -> Result<HwpDocument> {
+    parser::parse_reader_with_options(reader, options)
+}
+
 /// Parse an HWP file from a file path
-pub fn parse_file(path: &str) -> Result<HwpDocument> {
-    let data = std::fs::read(path)?;
-    parse(&data)
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<HwpDocument> {
+    let file = std::fs::File::open(path)?;
+    parse_reader(std::io::BufReader::new(file))
+}
+
+/// Load a document from the JSON its `Serialize` implementation writes
+///
+/// This reloads documents cached with `serde_json::to_string(&document)`
+/// without parsing the binary file again. The output of the JSON
+/// [`OutputFormat`] is a different, summarized layout and cannot be loaded.
+pub fn from_json(json: &str) -> Result<HwpDocument> {
+    serde_json::from_str(json).map_err(|e| HwpError::InvalidFormat {
+        reason: format!("Invalid document JSON: {}", e),
+    })
+}
+
+/// Parse an HWP file from raw bytes on tokio's blocking thread pool
+///
+/// Parsing is CPU-bound, so it runs under `spawn_blocking` rather than on
+/// the async worker threads. The data is copied for the blocking task.
+#[cfg(feature = "tokio")]
+pub async fn parse_async(data: &[u8]) -> Result<HwpDocument> {
+    let data = data.to_vec();
+    tokio::task::spawn_blocking(move || parse(&data))
+        .await
+        .map_err(std::io::Error::from)?
+}
+
+/// Read and parse an HWP file without blocking the async runtime
+#[cfg(feature = "tokio")]
+pub async fn parse_file_async<P: AsRef<Path>>(path: P) -> Result<HwpDocument> {
+    let data = tokio::fs::read(path).await?;
+    tokio::task::spawn_blocking(move || parse(&data))
+        .await
+        .map_err(std::io::Error::from)?
 }
@@ -1,20 +1,41 @@
 use crate::parser::doc_info_records::*;
 use crate::parser::record::RecordParser;
+use crate::parser::ParseOptions;
 use crate::reader::ByteReader;
-use crate::validator::RecordContext;
+use crate::validator::{DefaultRecordValidator, RecordContext};
 use hwp_core::constants::tag_id::doc_info;
-use hwp_core::models::document::DocInfo;
+use hwp_core::models::document::{DocInfo, ValidationWarning};
 use hwp_core::{HwpError, Result};
 
 /// Parse the DocInfo section from decompressed data
 pub fn parse_doc_info(data: &[u8]) -> Result<DocInfo> {
+    parse_doc_info_with_options(data, &ParseOptions::default())
+}
+
+/// Parse the DocInfo section from decompressed data, bounded by `options`
+pub fn parse_doc_info_with_options(data: &[u8], options: &ParseOptions) -> Result<DocInfo> {
     let mut parser = RecordParser::new_with_context(data, RecordContext::DocInfo);
+    parser.set_max_records(Some(options.max_records_per_stream));
+    if !options.strict {
+        // Unknown tags are skipped below; size and boundary checks still apply
+        parser.set_validator(Box::new(DefaultRecordValidator::lenient()));
+    }
     let mut doc_info = DocInfo::default();
+    let mut seen_properties = false;
 
     // Parse all records in the DocInfo section
     while let Some(record) = parser.parse_next_record()? {
         match record.tag_id {
+            doc_info::DOCUMENT_PROPERTIES if seen_properties => {
+                // Keep the first properties record; later ones are likely trailing junk
+                doc_info.warnings.push(ValidationWarning {
+                    tag_id: Some(record.tag_id),
+                    message: "Duplicate document properties record ignored".to_string(),
+                });
+            }
+
             doc_info::DOCUMENT_PROPERTIES => {
+                seen_properties = true;
                 doc_info.properties =
                     parse_document_properties(&record.data).map_err(|e| HwpError::ParseError {
                         offset: 0,
@@ -78,10 +99,16 @@ pub fn parse_doc_info(data: &[u8]) -> Result<DocInfo> {
 
             doc_info::BIN_DATA => {
                 // Binary data storage - typically images or embedded objects
-                let bin_data = parse_bin_data(&record.data).map_err(|e| HwpError::ParseError {
-                    offset: 0,
-                    message: format!("Failed to parse binary data: {}", e),
-                })?;
+                let mut bin_data =
+                    parse_bin_data(&record.data).map_err(|e| HwpError::ParseError {
+                        offset: 0,
+                        message: format!("Failed to parse binary data: {}", e),
+                    })?;
+                // Linked files are numbered by their place among the
+                // BIN_DATA records
+                if bin_data.link_type == crate::bin_data::LINK_TYPE_LINK {
+                    bin_data.id = doc_info.bin_data_entries.len() as u16 + 1;
+                }
                 doc_info.bin_data_entries.push(bin_data);
             }
 
@@ -91,6 +118,7 @@ pub fn parse_doc_info(data: &[u8]) -> Result<DocInfo> {
                     offset: 0,
                     message: format!("Failed to parse document data: {}", e),
                 })?;
+                doc_info.doc_variables = parse_doc_data_set(&doc_data).ok();
                 doc_info.doc_data = doc_data;
             }
 
@@ -192,11 +220,12 @@ pub fn parse_doc_info(data: &[u8]) -> Result<DocInfo> {
             }
 
             _ => {
-                // Unknown record type - log and skip
-                eprintln!(
-                    "Unknown DocInfo record: tag_id=0x{:04X}, size={}",
-                    record.tag_id, record.size
-                );
+                // Well-formed but unknown; only reachable in non-strict mode
+                // for tags the validator does not recognise
+                doc_info.warnings.push(ValidationWarning {
+                    tag_id: Some(record.tag_id),
+                    message: format!("Unknown DocInfo record skipped ({} bytes)", record.size),
+                });
             }
         }
     }
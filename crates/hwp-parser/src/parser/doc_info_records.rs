@@ -1,15 +1,30 @@
+//! Readers for DocInfo records
+//!
+//! Each `read_*` function reads one record from a [`RecordDataParser`],
+//! leaving any bytes past its known fields unread.
+
+use crate::bin_data::LINK_TYPE_LINK;
 use crate::parser::record::RecordDataParser;
 use crate::reader::ByteReader;
 use hwp_core::models::document::{
     BinDataEntry, BorderFill, BorderLine, Bullet, CharShape, CompatibleDocument, DistributeDocData,
-    DocumentProperties, FaceName, FaceNameType, ForbiddenChar, LayoutCompatibility, MemoShape,
-    Numbering, NumberingLevel, ParaShape, Style, TabDef, TabInfo, TrackChange, TrackChangeAuthor,
+    DocData, DocumentProperties, FaceName, FaceNameType, ForbiddenChar, LayoutCompatibility,
+    MemoShape, Numbering, NumberingLevel, ParaShape, Style, TabDef, TabInfo, TrackChange,
+    TrackChangeAuthor,
 };
-use hwp_core::Result;
+use hwp_core::{HwpError, Result};
+use std::collections::HashMap;
+
+/// Deepest nesting of parameter sets accepted in DOC_DATA
+const MAX_PARAMETER_SET_DEPTH: usize = 16;
 
 /// Parse DOCUMENT_PROPERTIES record (tag 0x0010)
 pub fn parse_document_properties(data: &[u8]) -> Result<DocumentProperties> {
-    let mut parser = RecordDataParser::new(data);
+    read_document_properties(&mut RecordDataParser::new(data))
+}
+
+/// Read a DOCUMENT_PROPERTIES record
+pub fn read_document_properties(parser: &mut RecordDataParser) -> Result<DocumentProperties> {
     let reader = parser.reader();
 
     Ok(DocumentProperties {
@@ -27,8 +42,11 @@ pub fn parse_document_properties(data: &[u8]) -> Result<DocumentProperties> {
 
 /// Parse FACE_NAME record (tag 0x0013)
 pub fn parse_face_name(data: &[u8]) -> Result<FaceName> {
-    let mut parser = RecordDataParser::new(data);
+    read_face_name(&mut RecordDataParser::new(data))
+}
 
+/// Read a FACE_NAME record
+pub fn read_face_name(parser: &mut RecordDataParser) -> Result<FaceName> {
     let properties = parser.reader().read_u8()?;
     let name = parser.read_hwp_string()?;
 
@@ -90,8 +108,11 @@ pub fn parse_face_name(data: &[u8]) -> Result<FaceName> {
 
 /// Parse CHAR_SHAPE record (tag 0x0015)
 pub fn parse_char_shape(data: &[u8]) -> Result<CharShape> {
-    let mut parser = RecordDataParser::new(data);
+    read_char_shape(&mut RecordDataParser::new(data))
+}
 
+/// Read a CHAR_SHAPE record
+pub fn read_char_shape(parser: &mut RecordDataParser) -> Result<CharShape> {
     // Read face name IDs (array of 7 u16 values)
     let mut face_name_ids = Vec::with_capacity(7);
     for _ in 0..7 {
@@ -159,14 +180,17 @@ pub fn parse_char_shape(data: &[u8]) -> Result<CharShape> {
 
 /// Parse PARA_SHAPE record (tag 0x0019)
 pub fn parse_para_shape(data: &[u8]) -> Result<ParaShape> {
-    let mut parser = RecordDataParser::new(data);
+    read_para_shape(&mut RecordDataParser::new(data))
+}
 
+/// Read a PARA_SHAPE record
+pub fn read_para_shape(parser: &mut RecordDataParser) -> Result<ParaShape> {
     let properties1 = parser.reader().read_u32()?;
-    let left_margin = parser.reader().read_i32()?;
-    let right_margin = parser.reader().read_i32()?;
-    let indent = parser.reader().read_i32()?;
-    let prev_spacing = parser.reader().read_i32()?;
-    let next_spacing = parser.reader().read_i32()?;
+    let left_margin = parser.reader().read_hwpunit()?;
+    let right_margin = parser.reader().read_hwpunit()?;
+    let indent = parser.reader().read_hwpunit()?;
+    let prev_spacing = parser.reader().read_hwpunit()?;
+    let next_spacing = parser.reader().read_hwpunit()?;
     let line_spacing = parser.reader().read_i32()?;
     let tab_def_id = parser.reader().read_u16()?;
     let numbering_id = parser.reader().read_u16()?;
@@ -215,8 +239,11 @@ pub fn parse_para_shape(data: &[u8]) -> Result<ParaShape> {
 
 /// Parse STYLE record (tag 0x001A)
 pub fn parse_style(data: &[u8]) -> Result<Style> {
-    let mut parser = RecordDataParser::new(data);
+    read_style(&mut RecordDataParser::new(data))
+}
 
+/// Read a STYLE record
+pub fn read_style(parser: &mut RecordDataParser) -> Result<Style> {
     let name = parser.read_hwp_string()?;
     let english_name = parser.read_hwp_string()?;
 
@@ -239,8 +266,11 @@ pub fn parse_style(data: &[u8]) -> Result<Style> {
 
 /// Parse BORDER_FILL record (tag 0x0014)
 pub fn parse_border_fill(data: &[u8]) -> Result<BorderFill> {
-    let mut parser = RecordDataParser::new(data);
+    read_border_fill(&mut RecordDataParser::new(data))
+}
 
+/// Read a BORDER_FILL record
+pub fn read_border_fill(parser: &mut RecordDataParser) -> Result<BorderFill> {
     let properties = parser.reader().read_u16()?;
 
     // Parse border lines
@@ -283,8 +313,11 @@ fn parse_border_line(reader: &mut ByteReader) -> Result<BorderLine> {
 
 /// Parse ID_MAPPINGS record (tag 0x0011)
 pub fn parse_id_mappings(data: &[u8]) -> Result<Vec<u32>> {
-    let mut parser = RecordDataParser::new(data);
+    read_id_mappings(&mut RecordDataParser::new(data))
+}
 
+/// Read an ID_MAPPINGS record
+pub fn read_id_mappings(parser: &mut RecordDataParser) -> Result<Vec<u32>> {
     let count = parser.reader().read_u32()? as usize;
     let mut mappings = Vec::with_capacity(count);
 
@@ -297,19 +330,27 @@ pub fn parse_id_mappings(data: &[u8]) -> Result<Vec<u32>> {
 
 /// Parse BIN_DATA record (tag 0x0012)
 pub fn parse_bin_data(data: &[u8]) -> Result<BinDataEntry> {
-    let mut parser = RecordDataParser::new(data);
+    read_bin_data(&mut RecordDataParser::new(data))
+}
 
-    // Read BIN_DATA properties
+/// Read a BIN_DATA record
+///
+/// Bits 0-3 of the properties give the storage type and bits 4-5 the
+/// compression. Embedded and storage items go on with the ID of their
+/// `BinData` stream; linked files have no ID of their own, so `id` is left
+/// at 0 for the caller to number. The rest of the record, a link's paths
+/// or an embedded item's extension, is kept in `data`.
+pub fn read_bin_data(parser: &mut RecordDataParser) -> Result<BinDataEntry> {
     let properties = parser.reader().read_u16()?;
+    let link_type = (properties & 0x000F) as u8;
+    let compression_type = ((properties >> 4) & 0x0003) as u8;
 
-    // Extract ID from properties (lower 16 bits)
-    let id = properties;
-
-    // Link type and compression type
-    let link_type = parser.reader().read_u8()?;
-    let compression_type = parser.reader().read_u8()?;
+    let id = if link_type == LINK_TYPE_LINK {
+        0
+    } else {
+        parser.reader().read_u16()?
+    };
 
-    // Read the actual binary data
     let data_size = parser.remaining();
     let data = parser.reader().read_bytes(data_size)?;
 
@@ -323,13 +364,113 @@ pub fn parse_bin_data(data: &[u8]) -> Result<BinDataEntry> {
 
 /// Parse DOC_DATA record (tag 0x001B)
 pub fn parse_doc_data(data: &[u8]) -> Result<Vec<u8>> {
-    // DOC_DATA is application-specific data
-    Ok(data.to_vec())
+    read_doc_data(&mut RecordDataParser::new(data))
+}
+
+/// Read a DOC_DATA record, which is application-specific data kept whole
+pub fn read_doc_data(parser: &mut RecordDataParser) -> Result<Vec<u8>> {
+    let remaining = parser.remaining();
+    parser.reader().read_bytes(remaining)
+}
+
+/// Decode a DOC_DATA record as a parameter set of document variables
+///
+/// Fails unless the whole record is one well-formed parameter set, so
+/// application data in other layouts is left to [`parse_doc_data`].
+pub fn parse_doc_data_set(data: &[u8]) -> Result<DocData> {
+    let mut parser = RecordDataParser::new(data);
+    let doc_data = read_doc_data_set(&mut parser)?;
+
+    if parser.remaining() > 0 {
+        return Err(HwpError::InvalidFormat {
+            reason: format!(
+                "{} bytes left after DOC_DATA parameter set",
+                parser.remaining()
+            ),
+        });
+    }
+
+    Ok(doc_data)
+}
+
+/// Read one parameter set of document variables from a DOC_DATA record
+pub fn read_doc_data_set(parser: &mut RecordDataParser) -> Result<DocData> {
+    let mut variables = HashMap::new();
+    read_parameter_set(parser.reader(), "", 0, &mut variables)?;
+    Ok(DocData { variables })
+}
+
+/// Read a parameter set: a set ID, an item count, then the items
+fn read_parameter_set(
+    reader: &mut ByteReader,
+    prefix: &str,
+    depth: usize,
+    variables: &mut HashMap<String, String>,
+) -> Result<()> {
+    if depth > MAX_PARAMETER_SET_DEPTH {
+        return Err(HwpError::InvalidFormat {
+            reason: "Parameter sets nested too deeply".to_string(),
+        });
+    }
+
+    let _set_id = reader.read_u16()?;
+    let count = reader.read_i16()?;
+    if count < 0 {
+        return Err(HwpError::InvalidFormat {
+            reason: format!("Negative parameter item count {}", count),
+        });
+    }
+
+    for _ in 0..count {
+        let item_id = reader.read_u16()?;
+        let item_type = reader.read_u16()?;
+        let key = format!("{}{}", prefix, item_id);
+
+        let value = match item_type {
+            0x0000 => String::new(),
+            0x0001 => {
+                let length = reader.read_u16()? as usize;
+                reader.read_utf16_string_n(length)?
+            }
+            0x0002 => reader.read_i8()?.to_string(),
+            0x0003 => reader.read_i16()?.to_string(),
+            0x0004 | 0x0005 => reader.read_i32()?.to_string(),
+            0x0006 => reader.read_u8()?.to_string(),
+            0x0007 => reader.read_u16()?.to_string(),
+            0x0008 | 0x0009 => reader.read_u32()?.to_string(),
+            0x8000 => {
+                read_parameter_set(reader, &format!("{}.", key), depth + 1, variables)?;
+                continue;
+            }
+            0x8001 => {
+                let length = reader.read_i16()?;
+                for index in 0..length.max(0) {
+                    let prefix = format!("{}.{}.", key, index);
+                    read_parameter_set(reader, &prefix, depth + 1, variables)?;
+                }
+                continue;
+            }
+            // Binary data item: the ID of a BinData entry
+            0x8002 => reader.read_u16()?.to_string(),
+            other => {
+                return Err(HwpError::InvalidFormat {
+                    reason: format!("Unknown parameter item type 0x{:04X}", other),
+                })
+            }
+        };
+        variables.insert(key, value);
+    }
+
+    Ok(())
 }
 
 /// Parse TAB_DEF record (tag 0x0016)
 pub fn parse_tab_def(data: &[u8]) -> Result<TabDef> {
-    let mut parser = RecordDataParser::new(data);
+    read_tab_def(&mut RecordDataParser::new(data))
+}
+
+/// Read a TAB_DEF record
+pub fn read_tab_def(parser: &mut RecordDataParser) -> Result<TabDef> {
     let reader = parser.reader();
 
     let properties = reader.read_u32()?;
@@ -359,8 +500,11 @@ pub fn parse_tab_def(data: &[u8]) -> Result<TabDef> {
 
 /// Parse NUMBERING record (tag 0x0017)
 pub fn parse_numbering(data: &[u8]) -> Result<Numbering> {
-    let mut parser = RecordDataParser::new(data);
+    read_numbering(&mut RecordDataParser::new(data))
+}
 
+/// Read a NUMBERING record
+pub fn read_numbering(parser: &mut RecordDataParser) -> Result<Numbering> {
     let mut levels = Vec::new();
 
     // HWP supports up to 7 levels of numbering
@@ -394,7 +538,11 @@ pub fn parse_numbering(data: &[u8]) -> Result<Numbering> {
 
 /// Parse BULLET record (tag 0x0018)
 pub fn parse_bullet(data: &[u8]) -> Result<Bullet> {
-    let mut parser = RecordDataParser::new(data);
+    read_bullet(&mut RecordDataParser::new(data))
+}
+
+/// Read a BULLET record
+pub fn read_bullet(parser: &mut RecordDataParser) -> Result<Bullet> {
     let reader = parser.reader();
 
     let properties = reader.read_u32()?;
@@ -423,14 +571,24 @@ pub fn parse_bullet(data: &[u8]) -> Result<Bullet> {
 
 /// Parse DISTRIBUTE_DOC_DATA record (tag 0x001C)
 pub fn parse_distribute_doc_data(data: &[u8]) -> Result<DistributeDocData> {
+    read_distribute_doc_data(&mut RecordDataParser::new(data))
+}
+
+/// Read a DISTRIBUTE_DOC_DATA record, keeping its encrypted payload whole
+pub fn read_distribute_doc_data(parser: &mut RecordDataParser) -> Result<DistributeDocData> {
+    let remaining = parser.remaining();
     Ok(DistributeDocData {
-        data: data.to_vec(),
+        data: parser.reader().read_bytes(remaining)?,
     })
 }
 
 /// Parse COMPATIBLE_DOCUMENT record (tag 0x0020)
 pub fn parse_compatible_document(data: &[u8]) -> Result<CompatibleDocument> {
-    let mut parser = RecordDataParser::new(data);
+    read_compatible_document(&mut RecordDataParser::new(data))
+}
+
+/// Read a COMPATIBLE_DOCUMENT record
+pub fn read_compatible_document(parser: &mut RecordDataParser) -> Result<CompatibleDocument> {
     let reader = parser.reader();
 
     let target_program = reader.read_u32()?;
@@ -440,7 +598,11 @@ pub fn parse_compatible_document(data: &[u8]) -> Result<CompatibleDocument> {
 
 /// Parse LAYOUT_COMPATIBILITY record (tag 0x0021)
 pub fn parse_layout_compatibility(data: &[u8]) -> Result<LayoutCompatibility> {
-    let mut parser = RecordDataParser::new(data);
+    read_layout_compatibility(&mut RecordDataParser::new(data))
+}
+
+/// Read a LAYOUT_COMPATIBILITY record
+pub fn read_layout_compatibility(parser: &mut RecordDataParser) -> Result<LayoutCompatibility> {
     let reader = parser.reader();
 
     let letter_spacing = reader.read_u32()?;
@@ -460,8 +622,11 @@ pub fn parse_layout_compatibility(data: &[u8]) -> Result<LayoutCompatibility> {
 
 /// Parse TRACK_CHANGE record (tag 0x0022)
 pub fn parse_track_change(data: &[u8]) -> Result<TrackChange> {
-    let mut parser = RecordDataParser::new(data);
+    read_track_change(&mut RecordDataParser::new(data))
+}
 
+/// Read a TRACK_CHANGE record
+pub fn read_track_change(parser: &mut RecordDataParser) -> Result<TrackChange> {
     let properties = parser.reader().read_u32()?;
     let author_id = parser.reader().read_u16()?;
     let timestamp = parser.reader().read_u64()?;
@@ -486,8 +651,11 @@ pub fn parse_track_change(data: &[u8]) -> Result<TrackChange> {
 
 /// Parse TRACK_CHANGE_AUTHOR record (tag 0x0050)
 pub fn parse_track_change_author(data: &[u8]) -> Result<TrackChangeAuthor> {
-    let mut parser = RecordDataParser::new(data);
+    read_track_change_author(&mut RecordDataParser::new(data))
+}
 
+/// Read a TRACK_CHANGE_AUTHOR record
+pub fn read_track_change_author(parser: &mut RecordDataParser) -> Result<TrackChangeAuthor> {
     let id = parser.reader().read_u16()?;
     let name = parser.read_hwp_string()?;
 
@@ -496,7 +664,11 @@ pub fn parse_track_change_author(data: &[u8]) -> Result<TrackChangeAuthor> {
 
 /// Parse MEMO_SHAPE record (tag 0x004C)
 pub fn parse_memo_shape(data: &[u8]) -> Result<MemoShape> {
-    let mut parser = RecordDataParser::new(data);
+    read_memo_shape(&mut RecordDataParser::new(data))
+}
+
+/// Read a MEMO_SHAPE record
+pub fn read_memo_shape(parser: &mut RecordDataParser) -> Result<MemoShape> {
     let reader = parser.reader();
 
     let properties = reader.read_u32()?;
@@ -520,8 +692,11 @@ pub fn parse_memo_shape(data: &[u8]) -> Result<MemoShape> {
 
 /// Parse FORBIDDEN_CHAR record (tag 0x004E)
 pub fn parse_forbidden_char(data: &[u8]) -> Result<ForbiddenChar> {
-    let mut parser = RecordDataParser::new(data);
+    read_forbidden_char(&mut RecordDataParser::new(data))
+}
 
+/// Read a FORBIDDEN_CHAR record
+pub fn read_forbidden_char(parser: &mut RecordDataParser) -> Result<ForbiddenChar> {
     let forbidden_chars = parser.read_hwp_string()?;
     let allowed_chars = if parser.has_more_data() {
         parser.read_hwp_string()?
@@ -538,6 +713,7 @@ pub fn parse_forbidden_char(data: &[u8]) -> Result<ForbiddenChar> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hwp_core::models::document::HwpUnit;
 
     #[test]
     fn test_parse_document_properties() {
@@ -656,11 +832,11 @@ mod tests {
 
         let para_shape = parse_para_shape(&data).unwrap();
         assert_eq!(para_shape.properties1, 1);
-        assert_eq!(para_shape.left_margin, 1280);
-        assert_eq!(para_shape.right_margin, 1280);
-        assert_eq!(para_shape.indent, 512);
-        assert_eq!(para_shape.prev_spacing, 256);
-        assert_eq!(para_shape.next_spacing, 256);
+        assert_eq!(para_shape.left_margin, HwpUnit(1280));
+        assert_eq!(para_shape.right_margin, HwpUnit(1280));
+        assert_eq!(para_shape.indent, HwpUnit(512));
+        assert_eq!(para_shape.prev_spacing, HwpUnit(256));
+        assert_eq!(para_shape.next_spacing, HwpUnit(256));
         assert_eq!(para_shape.line_spacing, 512);
         assert_eq!(para_shape.tab_def_id, 0);
         assert_eq!(para_shape.numbering_id, 0);
@@ -726,6 +902,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_doc_data_set() {
+        let title: Vec<u8> = "Annual Report"
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+        let mut data = vec![
+            0x01, 0x00, // set ID
+            0x02, 0x00, // 2 items
+            0x01, 0x00, // item 1
+            0x01, 0x00, // PIT_BSTR
+            0x0D, 0x00, // 13 characters
+        ];
+        data.extend(title);
+        data.extend([
+            0x02, 0x00, // item 2
+            0x08, 0x00, // PIT_UI4
+            0x2A, 0x00, 0x00, 0x00, // 42
+        ]);
+
+        let doc_data = parse_doc_data_set(&data).unwrap();
+        assert_eq!(doc_data.variables.len(), 2);
+        assert_eq!(doc_data.variables["1"], "Annual Report");
+        assert_eq!(doc_data.variables["2"], "42");
+
+        // Application data that is not a parameter set stays undecoded
+        assert!(parse_doc_data_set(&[0x01, 0x02, 0x03, 0x04, 0x05]).is_err());
+        assert!(parse_doc_data_set(&[0x01, 0x00, 0x00, 0x00, 0xFF]).is_err());
+    }
+
     #[test]
     fn test_parse_style() {
         let data = vec![
@@ -775,17 +981,27 @@ mod tests {
     #[test]
     fn test_parse_bin_data() {
         let data = vec![
-            0x01, 0x00, // properties/id: 1
-            0x00, // link_type: 0 (embedded)
-            0x01, // compression_type: 1 (compressed)
-            0xDE, 0xAD, 0xBE, 0xEF, // binary data
+            0x11, 0x00, // properties: embedding (1), compressed (1 << 4)
+            0x05, 0x00, // BinData ID: 5
+            0x03, 0x00, b'p', 0x00, b'n', 0x00, b'g', 0x00, // extension "png"
         ];
 
         let bin_data = parse_bin_data(&data).unwrap();
-        assert_eq!(bin_data.id, 1);
-        assert_eq!(bin_data.link_type, 0);
+        assert_eq!(bin_data.id, 5);
+        assert_eq!(bin_data.link_type, 1);
         assert_eq!(bin_data.compression_type, 1);
-        assert_eq!(bin_data.data, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(bin_data.data, data[4..]);
+
+        // Links have no ID; their paths follow the properties
+        let data = vec![
+            0x20, 0x00, // properties: link (0), not compressed (2 << 4)
+            0x00, 0x00, 0x00, 0x00, // empty absolute and relative paths
+        ];
+        let bin_data = parse_bin_data(&data).unwrap();
+        assert_eq!(bin_data.id, 0);
+        assert_eq!(bin_data.link_type, 0);
+        assert_eq!(bin_data.compression_type, 2);
+        assert_eq!(bin_data.data, data[2..]);
     }
 
     #[test]
@@ -82,6 +82,20 @@ mod tests {
         assert!(matches!(result, Err(HwpError::InvalidSignature)));
     }
 
+    #[test]
+    fn test_version_ordering() {
+        let version = HwpVersion::from_u32(0x05000300);
+
+        assert!(version > HwpVersion::new(5, 0, 2, 9));
+        assert!(version < HwpVersion::new(5, 1, 0, 0));
+        assert!(version.is_at_least(5, 0, 3, 0));
+        assert!(!version.is_at_least(5, 0, 3, 1));
+        assert!(version.is_supported());
+        assert!(!HwpVersion::new(4, 255, 255, 255).is_supported());
+        assert!(HwpVersion::new(3, 0, 0, 0).is_supported());
+        assert!(!HwpVersion::new(3, 0, 0, 0).is_hwp5());
+    }
+
     #[test]
     fn test_header_size() {
         assert_eq!(HwpHeader::SIZE, 256);
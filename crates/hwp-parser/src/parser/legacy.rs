@@ -0,0 +1,498 @@
+use crate::reader::ByteReader;
+use hwp_core::constants::HWP3_SIGNATURE;
+use hwp_core::models::document::{
+    DocInfo, DocumentSummary, FaceName, FaceNameType, Style, ValidationWarning,
+};
+use hwp_core::models::header::{HwpHeader, HwpProperties};
+use hwp_core::models::Paragraph;
+use hwp_core::{HwpDocument, HwpError, HwpVersion, Result, Section};
+
+/// Size of the document information block following the signature
+const DOC_INFO_SIZE: usize = 128;
+/// Size of the document summary block (title, subject, author, ...)
+const SUMMARY_SIZE: usize = 1008;
+/// Length in hchar units of each summary field
+const SUMMARY_FIELD_LEN: usize = 56;
+/// Number of font language groups
+const FONT_LANGUAGES: usize = 7;
+/// Size of one face name entry
+const FACE_NAME_SIZE: usize = 40;
+/// Size of a style name; a char shape and a para shape follow it
+const STYLE_NAME_SIZE: usize = 20;
+const CHAR_SHAPE_SIZE: usize = 31;
+const PARA_SHAPE_SIZE: usize = 187;
+/// Size of one line information entry
+const LINE_INFO_SIZE: usize = 14;
+
+/// Check whether the data starts with the HWP 3.x signature
+pub fn is_hwp3(data: &[u8]) -> bool {
+    data.starts_with(HWP3_SIGNATURE)
+}
+
+/// Parse an HWP 3.x document
+///
+/// HWP 3.x is a flat layout: signature, document information, summary, an
+/// optional information block, then the (possibly deflated) body holding
+/// fonts, styles and the paragraph list. Only the summary, face and style
+/// names and paragraph text are extracted; a control that embeds its own paragraph list
+/// (tables, pictures, notes, headers) ends the body early and is reported
+/// as a warning.
+pub fn parse_hwp3(data: &[u8]) -> Result<HwpDocument> {
+    let mut reader = ByteReader::new(data);
+    reader.skip(HWP3_SIGNATURE.len())?;
+
+    let doc_info = reader.read_bytes(DOC_INFO_SIZE)?;
+    let encrypted = u16::from_le_bytes([doc_info[96], doc_info[97]]) != 0;
+    let compressed = doc_info[124] != 0;
+    let info_block_len = u16::from_le_bytes([doc_info[126], doc_info[127]]) as usize;
+
+    if encrypted {
+        return Err(HwpError::UnsupportedFeature {
+            feature: "Password-protected HWP 3.x document".to_string(),
+        });
+    }
+
+    let summary = parse_summary(&reader.read_bytes(SUMMARY_SIZE)?)?;
+    reader.skip(info_block_len)?;
+
+    let rest = reader.read_to_end()?;
+    let body = if compressed {
+        crate::compression::decompress_raw(&rest)?
+    } else {
+        rest
+    };
+
+    let mut signature = [0u8; 32];
+    signature[..HWP3_SIGNATURE.len()].copy_from_slice(HWP3_SIGNATURE);
+    let header = HwpHeader {
+        signature,
+        version: HwpVersion::new(3, 0, 0, 0),
+        properties: HwpProperties::from_u32(compressed as u32),
+        reserved: [0; 216],
+    };
+
+    let mut document = HwpDocument::new(header);
+    document.summary = summary;
+    let mut section = Section::new();
+
+    let mut reader = ByteReader::new(&body);
+    read_fonts_and_styles(&mut reader, &mut document.doc_info)?;
+
+    if let Err(message) = parse_paragraph_list(&mut reader, &mut section.paragraphs) {
+        document.doc_info.warnings.push(ValidationWarning {
+            tag_id: None,
+            message,
+        });
+    }
+
+    document.doc_info.properties.section_count = 1;
+    document.sections.push(section);
+    Ok(document)
+}
+
+/// Parse the summary block: title, subject, author, date, two keyword
+/// fields and three free-form notes, each a fixed-length hchar string
+///
+/// The date is free-form text rather than a timestamp and is not kept.
+/// Returns `None` when the title, subject, author and keywords are all blank.
+fn parse_summary(block: &[u8]) -> Result<Option<DocumentSummary>> {
+    let mut reader = ByteReader::new(block);
+    let mut fields = Vec::with_capacity(6);
+    for _ in 0..6 {
+        fields.push(read_hchar_field(&mut reader)?);
+    }
+
+    let keywords: Vec<&str> = fields[4..6]
+        .iter()
+        .map(|k| k.as_str())
+        .filter(|k| !k.is_empty())
+        .collect();
+    let field = |i: usize| Some(fields[i].clone()).filter(|f| !f.is_empty());
+
+    let summary = DocumentSummary {
+        title: field(0),
+        subject: field(1),
+        author: field(2),
+        keywords: Some(keywords.join(", ")).filter(|k| !k.is_empty()),
+        ..DocumentSummary::default()
+    };
+
+    if summary.title.is_none()
+        && summary.subject.is_none()
+        && summary.author.is_none()
+        && summary.keywords.is_none()
+    {
+        return Ok(None);
+    }
+    Ok(Some(summary))
+}
+
+/// Read one null-terminated, fixed-length hchar summary field
+fn read_hchar_field(reader: &mut ByteReader) -> Result<String> {
+    let mut text = String::new();
+    let mut ended = false;
+    for _ in 0..SUMMARY_FIELD_LEN {
+        let ch = reader.read_u16()?;
+        if ch == 0 {
+            ended = true;
+        }
+        if !ended && ch >= 32 {
+            text.push(decode_hchar(ch));
+        }
+    }
+    Ok(text.trim().to_string())
+}
+
+/// Read the face name and style tables at the start of the body
+///
+/// Face names are grouped by language, with each group's size recorded in
+/// `id_mappings` as the HWP 5.0 ID_MAPPINGS record would. Only style names
+/// are kept; their char and para shapes are skipped.
+fn read_fonts_and_styles(reader: &mut ByteReader, doc_info: &mut DocInfo) -> Result<()> {
+    doc_info.id_mappings = vec![0];
+    for _ in 0..FONT_LANGUAGES {
+        let count = reader.read_u16()?;
+        doc_info.id_mappings.push(count as u32);
+        for _ in 0..count {
+            doc_info.face_names.push(FaceName {
+                properties: 0,
+                name: read_name(reader, FACE_NAME_SIZE)?,
+                substitute_font_type: None,
+                substitute_font_name: None,
+                type_info: FaceNameType::default(),
+                base_font_name: None,
+            });
+        }
+    }
+
+    let style_count = reader.read_u16()?;
+    for _ in 0..style_count {
+        let name = read_name(reader, STYLE_NAME_SIZE)?;
+        reader.skip(CHAR_SHAPE_SIZE + PARA_SHAPE_SIZE)?;
+        doc_info.styles.push(Style {
+            name,
+            english_name: String::new(),
+            properties: 0,
+            next_style_id: 0,
+            lang_id: 0,
+            para_shape_id: 0,
+            char_shape_id: 0,
+        });
+    }
+    Ok(())
+}
+
+/// Read a fixed-length, null-padded EUC-KR name
+///
+/// A name that isn't valid EUC-KR is left empty rather than failing the
+/// body.
+fn read_name(reader: &mut ByteReader, len: usize) -> Result<String> {
+    match reader.read_euc_kr_string_n(len) {
+        Err(HwpError::EncodingError(_)) => Ok(String::new()),
+        result => result,
+    }
+}
+
+/// Parse paragraphs until the empty paragraph that ends the list
+///
+/// Stops at the first paragraph that cannot be read, keeping those parsed
+/// so far; the error message explains why.
+fn parse_paragraph_list(
+    reader: &mut ByteReader,
+    paragraphs: &mut Vec<Paragraph>,
+) -> std::result::Result<(), String> {
+    loop {
+        match parse_paragraph(reader) {
+            Ok(Some(paragraph)) => paragraphs.push(paragraph),
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                return Err(format!(
+                    "HWP 3.x body parsing stopped after {} paragraphs: {}",
+                    paragraphs.len(),
+                    e
+                ))
+            }
+        }
+    }
+}
+
+/// Parse one paragraph, or `None` at the end of the list
+fn parse_paragraph(reader: &mut ByteReader) -> Result<Option<Paragraph>> {
+    let use_prev_shape = reader.read_u8()?;
+    let char_count = reader.read_u16()? as usize;
+    let line_count = reader.read_u16()? as usize;
+    let has_char_shapes = reader.read_u8()?;
+    let _flags = reader.read_u8()?;
+    let _special_chars = reader.read_u32()?;
+    let _style = reader.read_u8()?;
+    reader.skip(CHAR_SHAPE_SIZE)?;
+
+    if use_prev_shape == 0 {
+        reader.skip(PARA_SHAPE_SIZE)?;
+    }
+
+    if char_count == 0 {
+        return Ok(None);
+    }
+
+    reader.skip(line_count * LINE_INFO_SIZE)?;
+
+    if has_char_shapes != 0 {
+        for _ in 0..char_count {
+            if reader.read_u8()? != 1 {
+                reader.skip(CHAR_SHAPE_SIZE)?;
+            }
+        }
+    }
+
+    let mut paragraph = Paragraph::new();
+    paragraph.text = parse_text(reader, char_count)?;
+    Ok(Some(paragraph))
+}
+
+/// Read `count` hchar units of paragraph text
+fn parse_text(reader: &mut ByteReader, count: usize) -> Result<String> {
+    let mut text = String::new();
+    let mut read = 0;
+
+    while read < count {
+        let ch = reader.read_u16()?;
+        read += 1;
+
+        if ch >= 32 {
+            text.push(decode_hchar(ch));
+            continue;
+        }
+
+        // Special characters carry extra hchar units after the code
+        let extra = match ch {
+            13 => break, // Paragraph end
+            9 => {
+                text.push('\t');
+                3
+            }
+            25 => {
+                text.push('-');
+                2
+            }
+            30 | 31 => {
+                text.push(' ');
+                1
+            }
+            6 => 20,
+            7 | 14 => 41,
+            8 => 47,
+            18 | 21 | 22 => 3,
+            23 => 11,
+            24 => 4,
+            26 => 2,
+            28 => 31,
+            _ => {
+                return Err(HwpError::UnsupportedFeature {
+                    feature: format!("HWP 3.x control code {}", ch),
+                })
+            }
+        };
+
+        reader.skip(extra * 2)?;
+        read += extra;
+    }
+
+    Ok(text)
+}
+
+/// Convert an HWP 3.x character code to a Unicode character
+///
+/// Codes below 0x80 are ASCII and codes with the high bit set are Johab
+/// Hangul syllables. Symbols and Hanja use HWP's own tables, which are not
+/// mapped, and come out as U+FFFD.
+fn decode_hchar(ch: u16) -> char {
+    if ch < 0x80 {
+        return ch as u8 as char;
+    }
+    if ch & 0x8000 == 0 {
+        return char::REPLACEMENT_CHARACTER;
+    }
+
+    let initial = match (ch >> 10) & 0x1F {
+        v @ 2..=20 => v - 2,
+        _ => return char::REPLACEMENT_CHARACTER,
+    };
+    let medial = match (ch >> 5) & 0x1F {
+        v @ 3..=7 => v - 3,
+        v @ 10..=15 => v - 5,
+        v @ 18..=23 => v - 7,
+        v @ 26..=29 => v - 9,
+        _ => return char::REPLACEMENT_CHARACTER,
+    };
+    let final_ = match ch & 0x1F {
+        1 => 0,
+        v @ 2..=17 => v - 1,
+        v @ 19..=29 => v - 2,
+        _ => return char::REPLACEMENT_CHARACTER,
+    };
+
+    let code = 0xAC00 + (initial as u32 * 21 + medial as u32) * 28 + final_ as u32;
+    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_core::models::FontLanguage;
+
+    /// Johab code for a Hangul syllable from its jamo indices
+    fn johab(initial: u16, medial: u16, final_: u16) -> u16 {
+        let medial = [
+            3, 4, 5, 6, 7, 10, 11, 12, 13, 14, 15, 18, 19, 20, 21, 22, 23, 26, 27, 28, 29,
+        ][medial as usize];
+        let final_ = if final_ <= 16 { final_ + 1 } else { final_ + 2 };
+        0x8000 | ((initial + 2) << 10) | (medial << 5) | final_
+    }
+
+    fn paragraph(units: &[u16]) -> Vec<u8> {
+        let mut data = vec![1]; // reuse the previous paragraph shape
+        data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes()); // no line info
+        data.push(0); // no per-character shapes
+        data.push(0);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&[0; CHAR_SHAPE_SIZE]);
+        for unit in units {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+        data
+    }
+
+    fn hwp3_file(body: &[u8]) -> Vec<u8> {
+        let mut data = HWP3_SIGNATURE.to_vec();
+        data.extend_from_slice(&[0; DOC_INFO_SIZE + SUMMARY_SIZE]);
+
+        // One font per language, no styles
+        for _ in 0..FONT_LANGUAGES {
+            data.extend_from_slice(&1u16.to_le_bytes());
+            data.extend_from_slice(&[0; FACE_NAME_SIZE]);
+        }
+        data.extend_from_slice(&0u16.to_le_bytes());
+
+        data.extend_from_slice(body);
+        data
+    }
+
+    #[test]
+    fn test_decode_johab() {
+        // 한 = ㅎ(18) ㅏ(0) ㄴ(4), 글 = ㄱ(0) ㅡ(18) ㄹ(8)
+        assert_eq!(decode_hchar(johab(18, 0, 4)), '한');
+        assert_eq!(decode_hchar(johab(0, 18, 8)), '글');
+        assert_eq!(decode_hchar(johab(0, 0, 0)), '가');
+        assert_eq!(decode_hchar(johab(18, 20, 27)), '힣');
+        assert_eq!(decode_hchar(b'A' as u16), 'A');
+    }
+
+    #[test]
+    fn test_parse_hwp3_paragraphs() {
+        let mut body = Vec::new();
+        body.extend(paragraph(&[
+            johab(18, 0, 4),
+            johab(0, 18, 8),
+            9,
+            0,
+            0,
+            9,
+            b'3' as u16,
+            13,
+        ]));
+        body.extend(paragraph(&[b'O' as u16, b'K' as u16, 13]));
+        body.extend(paragraph(&[])); // end of list
+
+        let document = parse_hwp3(&hwp3_file(&body)).unwrap();
+        assert_eq!(document.header.version.major, 3);
+        assert_eq!(document.sections.len(), 1);
+
+        let texts: Vec<&str> = document.sections[0]
+            .paragraphs
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["한글\t3", "OK"]);
+        assert!(document.doc_info.warnings.is_empty());
+        assert!(document.summary.is_none());
+    }
+
+    #[test]
+    fn test_face_and_style_names() {
+        let name = |text: &str, len: usize| {
+            let mut data = encoding_rs::EUC_KR.encode(text).0.into_owned();
+            data.resize(len, 0);
+            data
+        };
+
+        let mut data = HWP3_SIGNATURE.to_vec();
+        data.extend_from_slice(&[0; DOC_INFO_SIZE + SUMMARY_SIZE]);
+        // Two Korean fonts, one font for each other language
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend(name("바탕", FACE_NAME_SIZE));
+        data.extend(name("돋움", FACE_NAME_SIZE));
+        for _ in 1..FONT_LANGUAGES {
+            data.extend_from_slice(&1u16.to_le_bytes());
+            data.extend(name("Courier", FACE_NAME_SIZE));
+        }
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend(name("바탕글", STYLE_NAME_SIZE));
+        data.extend_from_slice(&[0; CHAR_SHAPE_SIZE + PARA_SHAPE_SIZE]);
+        data.extend(paragraph(&[b'A' as u16, 13]));
+        data.extend(paragraph(&[]));
+
+        let document = parse_hwp3(&data).unwrap();
+        let doc_info = &document.doc_info;
+        assert_eq!(doc_info.face_names.len(), 8);
+        assert_eq!(doc_info.id_mappings, [0, 2, 1, 1, 1, 1, 1, 1]);
+        let hangul = doc_info.face_name(FontLanguage::Korean, 1).unwrap();
+        assert_eq!(hangul.name, "돋움");
+        let english = doc_info.face_name(FontLanguage::English, 0).unwrap();
+        assert_eq!(english.name, "Courier");
+        assert_eq!(doc_info.styles[0].name, "바탕글");
+        assert_eq!(document.sections[0].paragraphs[0].text, "A");
+        assert!(document.header.version.is_supported());
+    }
+
+    #[test]
+    fn test_parse_summary_fields() {
+        let field = |units: &[u16]| {
+            let mut data = vec![0u8; SUMMARY_FIELD_LEN * 2];
+            for (i, unit) in units.iter().enumerate() {
+                data[i * 2..i * 2 + 2].copy_from_slice(&unit.to_le_bytes());
+            }
+            data
+        };
+
+        let mut block = Vec::new();
+        block.extend(field(&[johab(18, 0, 4), johab(0, 18, 8)])); // title: 한글
+        block.extend(field(&[]));
+        block.extend(field(&[b'K' as u16, b'i' as u16, b'm' as u16]));
+        block.extend(field(&[b'1' as u16, b'9' as u16, b'9' as u16, b'7' as u16]));
+        block.extend(field(&[b'a' as u16]));
+        block.extend(field(&[b'b' as u16]));
+        block.resize(SUMMARY_SIZE, 0);
+
+        let summary = parse_summary(&block).unwrap().unwrap();
+        assert_eq!(summary.title.as_deref(), Some("한글"));
+        assert_eq!(summary.subject, None);
+        assert_eq!(summary.author.as_deref(), Some("Kim"));
+        assert_eq!(summary.keywords.as_deref(), Some("a, b"));
+        assert_eq!(summary.created, None);
+
+        assert!(parse_summary(&[0; SUMMARY_SIZE]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_unsupported_control_keeps_earlier_paragraphs() {
+        let mut body = paragraph(&[b'A' as u16, 13]);
+        body.extend(paragraph(&[10, 0, 0, 13]));
+
+        let document = parse_hwp3(&hwp3_file(&body)).unwrap();
+        assert_eq!(document.sections[0].paragraphs.len(), 1);
+        assert_eq!(document.doc_info.warnings.len(), 1);
+    }
+}
@@ -1,31 +1,100 @@
 pub mod doc_info;
 pub mod doc_info_records;
 pub mod header;
+pub mod legacy;
 pub mod record;
 pub mod section;
+pub mod summary;
 
-use crate::cfb::parse_cfb_bytes;
 use crate::cfb::stream::Stream;
+use crate::cfb::{CfbContainer, CfbContainerOptions};
 use crate::reader::ByteReader;
+use hwp_core::models::Section;
 use hwp_core::{HwpDocument, HwpError, Result};
-use std::io::Cursor;
+use log::debug;
+use std::io::{Cursor, ErrorKind, Read, Seek, SeekFrom};
+
+/// Limits that bound the work done when parsing untrusted input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Maximum number of BodyText sections to parse
+    pub max_sections: usize,
+    /// Maximum number of records in a single DocInfo or section stream
+    pub max_records_per_stream: usize,
+    /// Maximum depth of tables nested inside table cells
+    pub max_nesting: usize,
+    /// Fail on DocInfo records with unknown tags instead of skipping them
+    pub strict: bool,
+    /// Skip reading and parsing BodyText sections, leaving one empty
+    /// section per stream; for callers that only need the header and DocInfo
+    ///
+    /// Password and DRM protected documents then parse too, with their
+    /// encrypted DocInfo left empty, so their header can still be reported.
+    pub skip_body: bool,
+    /// When a DocInfo or section stream fails to decompress by every
+    /// method, use its raw bytes instead of failing
+    pub lenient: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_sections: 1024,
+            max_records_per_stream: 1_000_000,
+            max_nesting: 32,
+            strict: false,
+            skip_body: false,
+            lenient: false,
+        }
+    }
+}
+
+/// Decompress a DocInfo or BodyText stream
+///
+/// The method is picked once from the FileHeader's compressed flag and the
+/// first bytes of the stream, so the data is usually only inflated once. A
+/// failure falls back to [`try_decompress_stream`], and with
+/// `options.lenient` then to the raw bytes.
+fn read_record_stream(
+    stream: &Stream,
+    compressed: bool,
+    options: &ParseOptions,
+) -> Result<Vec<u8>> {
+    let data = stream.as_bytes();
+    let kind = crate::compression::detect_compression(data, compressed);
+    debug!("{} compression: {:?}", stream.name, kind);
+
+    let error = match crate::compression::decompress_as(data, kind) {
+        Ok(decompressed) => return Ok(decompressed),
+        Err(e) => e,
+    };
+    debug!(
+        "{} failed to decompress ({}), trying every method",
+        stream.name, error
+    );
+    match try_decompress_stream(stream) {
+        Ok(decompressed) => Ok(decompressed),
+        Err(_) if options.lenient => Ok(data.to_vec()),
+        Err(_) => Err(error),
+    }
+}
 
 /// Try to decompress a stream using various methods
-fn try_decompress_stream(stream: &Stream) -> Result<Vec<u8>> {
+pub(crate) fn try_decompress_stream(stream: &Stream) -> Result<Vec<u8>> {
     let data = stream.as_bytes();
 
     // Try different decompression methods (prefer HWP format first)
     // 1) HWP format: 4-byte size header + raw deflate (most common for HWP v5.x)
     if crate::compression::is_hwp_compressed(data) {
         if let Ok(decompressed) = crate::compression::decompress_hwp(data) {
-            eprintln!("[DEBUG] Successfully decompressed with HWP (size + raw deflate)");
+            debug!("Successfully decompressed with HWP (size + raw deflate)");
             return Ok(decompressed);
         }
     }
 
     // 2) Raw deflate (some streams may be pure deflate without size header)
     if let Ok(decompressed) = crate::compression::decompress_raw(data) {
-        eprintln!("[DEBUG] Successfully decompressed with raw deflate");
+        debug!("Successfully decompressed with raw deflate");
         return Ok(decompressed);
     }
 
@@ -33,8 +102,8 @@ fn try_decompress_stream(stream: &Stream) -> Result<Vec<u8>> {
     if data.len() >= 2 {
         let header = u16::from_be_bytes([data[0], data[1]]);
         if matches!(header, 0x789C | 0x78DA | 0x7801 | 0x785E | 0x78DE) {
-            if let Ok(decompressed) = decompress_zlib(data) {
-                eprintln!("[DEBUG] Successfully decompressed with zlib");
+            if let Ok(decompressed) = crate::compression::decompress_zlib(data) {
+                debug!("Successfully decompressed with zlib");
                 return Ok(decompressed);
             }
         }
@@ -45,29 +114,44 @@ fn try_decompress_stream(stream: &Stream) -> Result<Vec<u8>> {
     ))
 }
 
-/// Decompress data using zlib
-fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>> {
-    use flate2::read::ZlibDecoder;
-    use std::io::Read;
-
-    let mut decoder = ZlibDecoder::new(data);
-    let mut decompressed = Vec::new();
-
-    decoder
-        .read_to_end(&mut decompressed)
-        .map_err(|e| HwpError::DecompressionError(e.to_string()))?;
-
-    Ok(decompressed)
-}
-
 /// Parse an HWP document from raw bytes
 pub fn parse(data: &[u8]) -> Result<HwpDocument> {
+    parse_with_options(data, &ParseOptions::default())
+}
+
+/// Parse an HWP document from raw bytes, bounded by `options`
+pub fn parse_with_options(data: &[u8], options: &ParseOptions) -> Result<HwpDocument> {
     // Check if this is a CFB file (HWP v5.x)
     if is_cfb_file(data) {
-        parse_cfb_hwp(data)
+        parse_cfb_hwp(&mut Cursor::new(data), options)
     } else {
         // Legacy format (HWP v3.x or older)
-        parse_legacy_hwp(data)
+        parse_legacy_hwp(data, options)
+    }
+}
+
+/// Parse an HWP document from a reader, bounded by `options`
+///
+/// HWP 5.0 files are read stream by stream as the parser needs them rather
+/// than loaded whole; older formats are still read into memory first.
+pub fn parse_reader_with_options<R: Read + Seek>(
+    mut reader: R,
+    options: &ParseOptions,
+) -> Result<HwpDocument> {
+    let mut signature = [0u8; 8];
+    let is_cfb = match reader.read_exact(&mut signature) {
+        Ok(()) => is_cfb_file(&signature),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => false,
+        Err(e) => return Err(e.into()),
+    };
+    reader.seek(SeekFrom::Start(0))?;
+
+    if is_cfb {
+        parse_cfb_hwp(&mut reader, options)
+    } else {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        parse_legacy_hwp(&data, options)
     }
 }
 
@@ -81,13 +165,18 @@ fn is_cfb_file(data: &[u8]) -> bool {
 }
 
 /// Parse a CFB-based HWP file (v5.x)
-fn parse_cfb_hwp(data: &[u8]) -> Result<HwpDocument> {
-    // Parse CFB container
-    let mut container = parse_cfb_bytes(data)?;
-    let mut cursor = Cursor::new(data);
+fn parse_cfb_hwp<R: Read + Seek>(input: &mut R, options: &ParseOptions) -> Result<HwpDocument> {
+    // Parse CFB container; each stream is copied out as soon as it is read,
+    // so only the latest raw stream needs to stay cached
+    let mut container = CfbContainer::from_reader_with_options(
+        input,
+        CfbContainerOptions {
+            cache_limit_bytes: Some(0),
+        },
+    )?;
 
     // Read FileHeader stream
-    let file_header_stream = container.read_stream(&mut cursor, "FileHeader")?;
+    let file_header_stream = container.read_stream(input, "FileHeader")?;
     let header_data = if file_header_stream.is_compressed() {
         file_header_stream.decompress()?
     } else {
@@ -98,43 +187,41 @@ fn parse_cfb_hwp(data: &[u8]) -> Result<HwpDocument> {
     let mut reader = ByteReader::new(&header_data);
     let header = header::parse_header(&mut reader)?;
 
-    // Check if version is supported
-    if !header.version.is_supported() {
+    // Only HWP 3.x has its own layout; anything else must be 5.0 or later
+    if !header.version.is_hwp5() {
         return Err(HwpError::UnsupportedVersion {
             version: header.version.to_string(),
         });
     }
 
+    // Encrypted streams would only fail later as corrupt data; a header
+    // only parse never reads them
+    let encrypted = header.has_password() || header.is_drm_document();
+    if !options.skip_body {
+        if header.has_password() {
+            return Err(HwpError::PasswordProtected);
+        }
+        if header.is_drm_document() {
+            return Err(HwpError::DrmProtected);
+        }
+    }
+
     // Create document
     let mut document = HwpDocument::new(header);
 
-    if container.has_stream("DocInfo") {
-        eprintln!("[DEBUG] Reading DocInfo stream...");
-        let doc_info_stream = container.read_stream(&mut cursor, "DocInfo")?;
-        eprintln!(
-            "[DEBUG] DocInfo stream size: {} bytes",
-            doc_info_stream.size
-        );
+    if container.has_stream("DocInfo") && !encrypted {
+        debug!("Reading DocInfo stream...");
+        let doc_info_stream = container.read_stream(input, "DocInfo")?;
+        debug!("DocInfo stream size: {} bytes", doc_info_stream.size);
 
-        // Try to decompress DocInfo stream - HWP v5.x streams are usually compressed
-        let doc_info_data = match try_decompress_stream(doc_info_stream) {
-            Ok(decompressed) => {
-                eprintln!(
-                    "[DEBUG] DocInfo decompressed successfully: {} bytes",
-                    decompressed.len()
-                );
-                decompressed
-            }
-            Err(_) => {
-                eprintln!("[DEBUG] DocInfo not compressed, using raw data");
-                doc_info_stream.as_bytes().to_vec()
-            }
-        };
+        let doc_info_data =
+            read_record_stream(doc_info_stream, document.header.is_compressed(), options)?;
+        debug!("DocInfo data size: {} bytes", doc_info_data.len());
 
         // Parse DocInfo records
-        eprintln!("[DEBUG] Parsing DocInfo data...");
-        document.doc_info = doc_info::parse_doc_info(&doc_info_data)?;
-        eprintln!("[DEBUG] DocInfo parsed successfully");
+        debug!("Parsing DocInfo data...");
+        document.doc_info = doc_info::parse_doc_info_with_options(&doc_info_data, options)?;
+        debug!("DocInfo parsed successfully");
     }
 
     // Parse BodyText sections
@@ -145,50 +232,105 @@ fn parse_cfb_hwp(data: &[u8]) -> Result<HwpDocument> {
             break;
         }
 
-        eprintln!("[DEBUG] Reading section: {}", section_name);
-        let section_stream = container.read_stream(&mut cursor, &section_name)?;
-        eprintln!("[DEBUG] Stream size: {} bytes", section_stream.size);
-
-        // Try to decompress section stream - HWP v5.x sections are usually compressed
-        let section_data = match try_decompress_stream(section_stream) {
-            Ok(decompressed) => {
-                eprintln!(
-                    "[DEBUG] Section decompressed successfully: {} bytes",
-                    decompressed.len()
-                );
-                decompressed
-            }
-            Err(_) => {
-                eprintln!("[DEBUG] Section not compressed, using raw data");
-                section_stream.as_bytes().to_vec()
-            }
-        };
+        if section_idx >= options.max_sections {
+            return Err(HwpError::ValidationError {
+                message: format!(
+                    "Document exceeds the limit of {} sections",
+                    options.max_sections
+                ),
+            });
+        }
+
+        if options.skip_body {
+            // Keep the section count without touching the stream
+            document.sections.push(Section::new());
+            section_idx += 1;
+            continue;
+        }
+
+        debug!("Reading section: {}", section_name);
+        let section_stream = container.read_stream(input, &section_name)?;
+        debug!("Stream size: {} bytes", section_stream.size);
+
+        let section_data =
+            read_record_stream(section_stream, document.header.is_compressed(), options)?;
+        debug!("Section data size: {} bytes", section_data.len());
 
         // Parse section
-        eprintln!("[DEBUG] Parsing section data...");
-        let section = section::parse_section(&section_data, section_idx)?;
+        debug!("Parsing section data...");
+        let section = section::parse_section_with_options(&section_data, section_idx, options)?;
         document.sections.push(section);
 
         section_idx += 1;
     }
 
+    // Summary properties are optional; a missing or unreadable stream is not fatal
+    if container.has_stream(summary::SUMMARY_STREAM) {
+        document.summary = container
+            .read_stream(input, summary::SUMMARY_STREAM)
+            .ok()
+            .and_then(|stream| summary::parse_summary(stream.as_bytes()).ok());
+    }
+
+    // Load embedded binary data (images, OLE objects) from the BinData
+    // storage; like the body, a header-only parse leaves it unread, and a
+    // stream that can't be read is left out rather than failing the parse
+    let bin_data_streams = if options.skip_body {
+        Vec::new()
+    } else {
+        container.list_streams()
+    };
+    for name in bin_data_streams {
+        let id = match crate::bin_data::stream_id(&name) {
+            Some(id) => id,
+            None => continue,
+        };
+        let stream = match container.read_stream(input, &name) {
+            Ok(stream) => stream,
+            Err(e) => {
+                debug!("Skipping unreadable stream {}: {}", name, e);
+                continue;
+            }
+        };
+        let entry = document
+            .doc_info
+            .bin_data_entries
+            .iter()
+            .find(|entry| entry.id == id && entry.link_type != crate::bin_data::LINK_TYPE_LINK);
+        let data = crate::bin_data::decode_stream(
+            stream.as_bytes(),
+            entry,
+            document.header.is_compressed(),
+        );
+        document.bin_data.insert(id, data);
+    }
+
     Ok(document)
 }
 
 /// Parse a legacy HWP file (v3.x or older)
-fn parse_legacy_hwp(data: &[u8]) -> Result<HwpDocument> {
+fn parse_legacy_hwp(data: &[u8], options: &ParseOptions) -> Result<HwpDocument> {
+    if legacy::is_hwp3(data) {
+        return legacy::parse_hwp3(data);
+    }
+
     let mut reader = ByteReader::new(data);
 
     // Parse header
     let header = header::parse_header(&mut reader)?;
 
-    // Check if version is supported
-    if !header.version.is_supported() {
+    // Only HWP 3.x has its own layout; anything else must be 5.0 or later
+    if !header.version.is_hwp5() {
         return Err(HwpError::UnsupportedVersion {
             version: header.version.to_string(),
         });
     }
 
+    // Encrypted streams would only fail later as corrupt data
+    if header.has_password() && !options.skip_body {
+        return Err(HwpError::PasswordProtected);
+    }
+
     // Create document
     let document = HwpDocument::new(header);
 
@@ -1,6 +1,6 @@
 use crate::reader::ByteReader;
 use crate::validator::{DefaultRecordValidator, RecordContext, RecordValidator};
-use hwp_core::models::record::{Record, RecordHeader};
+use hwp_core::models::record::{Record, RecordHeader, RecordNode};
 use hwp_core::{HwpError, Result};
 use log::{debug, error, warn};
 
@@ -13,6 +13,10 @@ pub struct RecordParser<'a> {
     enable_recovery: bool,
     /// Count of recovered errors
     recovery_count: usize,
+    /// Maximum number of records to read before failing
+    max_records: Option<usize>,
+    /// Number of records read so far
+    records_read: usize,
 }
 
 impl<'a> RecordParser<'a> {
@@ -24,6 +28,8 @@ impl<'a> RecordParser<'a> {
             context: RecordContext::Unknown,
             enable_recovery: false,
             recovery_count: 0,
+            max_records: None,
+            records_read: 0,
         }
     }
 
@@ -35,6 +41,8 @@ impl<'a> RecordParser<'a> {
             context,
             enable_recovery: false,
             recovery_count: 0,
+            max_records: None,
+            records_read: 0,
         }
     }
 
@@ -46,6 +54,8 @@ impl<'a> RecordParser<'a> {
             context: RecordContext::Unknown,
             enable_recovery: false,
             recovery_count: 0,
+            max_records: None,
+            records_read: 0,
         }
     }
 
@@ -64,6 +74,11 @@ impl<'a> RecordParser<'a> {
         self.context = context;
     }
 
+    /// Limit the number of records this parser will read
+    pub fn set_max_records(&mut self, max_records: Option<usize>) {
+        self.max_records = max_records;
+    }
+
     /// Set a custom validator
     pub fn set_validator(&mut self, validator: Box<dyn RecordValidator>) {
         self.validator = validator;
@@ -96,12 +111,23 @@ impl<'a> RecordParser<'a> {
 
     /// Parse the next record from the stream
     pub fn parse_next_record(&mut self) -> Result<Option<Record>> {
-        let result = self.parse_next_record_internal();
+        let mut result = self.parse_next_record_internal();
 
         // If error recovery is enabled and we got an error, try to recover
         if self.enable_recovery && result.is_err() {
             warn!("Parse error occurred, attempting recovery: {:?}", result);
-            return self.try_recover();
+            result = self.try_recover();
+        }
+
+        if let Ok(Some(_)) = result {
+            self.records_read += 1;
+            if let Some(max) = self.max_records {
+                if self.records_read > max {
+                    return Err(HwpError::ValidationError {
+                        message: format!("Stream exceeds the limit of {} records", max),
+                    });
+                }
+            }
         }
 
         result
@@ -217,15 +243,46 @@ impl<'a> RecordParser<'a> {
         )))
     }
 
+    /// Iterate over the remaining records without collecting them
+    ///
+    /// The iterator ends after the last record or after yielding the first
+    /// error, so `find` and `take_while` stop reading as soon as they can.
+    pub fn iter(&mut self) -> Records<'_, 'a> {
+        Records {
+            parser: self,
+            failed: false,
+        }
+    }
+
     /// Parse all records from the stream
     pub fn parse_all_records(&mut self) -> Result<Vec<Record>> {
-        let mut records = Vec::new();
+        self.iter().collect()
+    }
+
+    /// Parse all records from the stream into a tree
+    ///
+    /// Each record becomes a child of the closest earlier record with a
+    /// lower level, so a level that jumps by more than one still nests
+    /// under its nearest ancestor. Records with no such ancestor are roots.
+    pub fn parse_tree(&mut self) -> Result<Vec<RecordNode>> {
+        let mut roots = Vec::new();
+        // The open path from a root down to the latest record
+        let mut path: Vec<RecordNode> = Vec::new();
 
         while let Some(record) = self.parse_next_record()? {
-            records.push(record);
+            while path
+                .last()
+                .is_some_and(|node| node.record.level >= record.level)
+            {
+                close_node(&mut path, &mut roots);
+            }
+            path.push(RecordNode::new(record));
         }
 
-        Ok(records)
+        while !path.is_empty() {
+            close_node(&mut path, &mut roots);
+        }
+        Ok(roots)
     }
 
     /// Parse records until a specific tag is found
@@ -244,6 +301,47 @@ impl<'a> RecordParser<'a> {
         Ok(records)
     }
 
+    /// Skip over the next record without reading its data
+    ///
+    /// Returns the tag ID of the skipped record, or `None` at the end of the
+    /// stream. Tags are not validated and the record limit is not applied.
+    pub fn skip_record(&mut self) -> Result<Option<u16>> {
+        if self.reader.is_eof() {
+            return Ok(None);
+        }
+
+        let value = self.reader.read_u32()?;
+        let header = RecordHeader::from_bytes(value.to_le_bytes());
+        let size = if header.has_extended_size() {
+            self.reader.read_u32()?
+        } else {
+            header.size()
+        };
+        self.reader.skip(size as usize)?;
+
+        Ok(Some(header.tag_id()))
+    }
+
+    /// Find the byte offset of the first record with the given tag
+    ///
+    /// Scans forward from the current position by skipping records. When the
+    /// tag is found the parser is left positioned at that record's header, so
+    /// the next `parse_next_record` call reads it; otherwise it ends at the
+    /// end of the stream.
+    pub fn position_of_tag(&mut self, tag: u16) -> Result<Option<usize>> {
+        loop {
+            let offset = self.reader.position();
+            match self.skip_record()? {
+                Some(tag_id) if tag_id == tag => {
+                    self.reader.seek(offset)?;
+                    return Ok(Some(offset));
+                }
+                Some(_) => continue,
+                None => return Ok(None),
+            }
+        }
+    }
+
     /// Get the current position in the stream
     pub fn position(&self) -> usize {
         self.reader.position()
@@ -260,6 +358,16 @@ impl<'a> RecordParser<'a> {
     }
 }
 
+/// Pop the deepest open node and attach it to its parent, or to `roots`
+fn close_node(path: &mut Vec<RecordNode>, roots: &mut Vec<RecordNode>) {
+    if let Some(node) = path.pop() {
+        match path.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
+}
+
 /// Record data parser for specific record types
 pub struct RecordDataParser<'a> {
     reader: ByteReader<'a>,
@@ -354,10 +462,99 @@ impl<'a> RecordDataParser<'a> {
     }
 }
 
+/// Iterator over the records of a [`RecordParser`], see [`RecordParser::iter`]
+pub struct Records<'p, 'a> {
+    parser: &'p mut RecordParser<'a>,
+    failed: bool,
+}
+
+impl Iterator for Records<'_, '_> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.parser.parse_next_record().transpose();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+/// Iterator that owns its [`RecordParser`], see [`iter_records`]
+pub struct RecordIter<'a> {
+    parser: RecordParser<'a>,
+    failed: bool,
+}
+
+impl Iterator for RecordIter<'_> {
+    type Item = Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.parser.parse_next_record().transpose();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+/// Lazily iterate over the records of a decompressed stream
+///
+/// `context` selects which tag IDs are accepted. Records are parsed one at
+/// a time as the iterator advances; it ends after the last record or after
+/// yielding the first error.
+pub fn iter_records(data: &[u8], context: RecordContext) -> RecordIter<'_> {
+    RecordIter {
+        parser: RecordParser::new_with_context(data, context),
+        failed: false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_tree_nesting() {
+        use hwp_core::constants::tag_id::section::*;
+
+        // (tag, level): a paragraph holding a table whose cell holds a
+        // paragraph, then a second top-level paragraph
+        let layout = [
+            (PARA_HEADER, 0),
+            (PARA_TEXT, 1),
+            (CTRL_HEADER, 1),
+            (TABLE, 2),
+            (LIST_HEADER, 2),
+            (PARA_HEADER, 2),
+            (PARA_TEXT, 3),
+            (PARA_HEADER, 0),
+            (PARA_TEXT, 1),
+        ];
+        let mut data = Vec::new();
+        for (tag, level) in layout {
+            let header = (tag as u32) | ((level as u32) << 10) | (24 << 20);
+            data.extend_from_slice(&header.to_le_bytes());
+            data.extend_from_slice(&[0; 24]);
+        }
+
+        let mut parser = RecordParser::new_with_context(&data, RecordContext::BodyText);
+        let tree = parser.parse_tree().unwrap();
+
+        let shape = |node: &RecordNode| -> Vec<u16> {
+            node.children.iter().map(|c| c.record.tag_id).collect()
+        };
+        assert_eq!(tree.len(), 2);
+        assert_eq!(shape(&tree[0]), [PARA_TEXT, CTRL_HEADER]);
+        let ctrl = &tree[0].children[1];
+        assert_eq!(shape(ctrl), [TABLE, LIST_HEADER, PARA_HEADER]);
+        assert_eq!(shape(&ctrl.children[2]), [PARA_TEXT]);
+        assert_eq!(shape(&tree[1]), [PARA_TEXT]);
+        assert_eq!(tree[0].walk().len(), 7);
+    }
+
     #[test]
     fn test_parse_simple_record() {
         // Create test data: header (tag=0x0010, level=0, size=30) + data
@@ -433,6 +630,37 @@ mod tests {
         assert_eq!(records[1].data.len(), 10);
     }
 
+    #[test]
+    fn test_iter_stops_early() {
+        let mut data = Vec::new();
+        for tag in [0x0013u32, 0x0010, 0x0013] {
+            let size = if tag == 0x0010 { 30 } else { 10 };
+            data.extend_from_slice(&(tag | (size << 20)).to_le_bytes());
+            data.extend(std::iter::repeat(0).take(size as usize));
+        }
+        // Truncated trailing record
+        data.extend_from_slice(&(0x0013u32 | (10 << 20)).to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+
+        let mut parser =
+            RecordParser::new_with_context(&data, crate::validator::RecordContext::DocInfo);
+        let found = parser
+            .iter()
+            .find(|r| matches!(r, Ok(record) if record.tag_id == 0x0010))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.data.len(), 30);
+
+        // Only the first two records were read
+        assert_eq!(parser.position(), 4 + 10 + 4 + 30);
+
+        // The rest yields one record, then the error, then ends
+        let rest: Vec<_> = parser.iter().collect();
+        assert_eq!(rest.len(), 2);
+        assert!(rest[0].is_ok());
+        assert!(rest[1].is_err());
+    }
+
     #[test]
     fn test_record_data_parser() {
         let record_data = vec![
@@ -1,59 +1,156 @@
 use crate::parser::record::RecordParser;
+use crate::parser::ParseOptions;
 use crate::reader::ByteReader;
 use crate::validator::RecordContext;
+use hwp_core::constants::ctrl_id::CtrlId;
 use hwp_core::constants::tag_id::section;
-use hwp_core::models::paragraph::{CharShapePos, LineSegment, ParagraphHeader};
-use hwp_core::models::section::Section;
-use hwp_core::models::Paragraph;
-use hwp_core::Result;
+use hwp_core::models::paragraph::{
+    CharShapePos, Control, ControlType, ExtendedControl, LineSegment, ParagraphHeader,
+};
+use hwp_core::models::section::{
+    ControlHeader, Equation, FootnoteShape, HeaderFooter, HeaderFooterKind, Hyperlink, Note,
+    NoteKind, PageDef, PageOrientation, Picture, PictureCrop, Section, Table, TableCell,
+};
+use hwp_core::models::{Paragraph, Record};
+use hwp_core::{HwpError, Result};
+use log::{debug, warn};
+
+/// PARA_TEXT control code that closes the innermost open field
+const FIELD_END: u16 = 0x0004;
 
 /// Parse a section from decompressed data
-pub fn parse_section(data: &[u8], _section_index: usize) -> Result<Section> {
+pub fn parse_section(data: &[u8], section_index: usize) -> Result<Section> {
+    parse_section_with_options(data, section_index, &ParseOptions::default())
+}
+
+/// Parse a section from decompressed data, bounded by `options`
+pub fn parse_section_with_options(
+    data: &[u8],
+    _section_index: usize,
+    options: &ParseOptions,
+) -> Result<Section> {
     let mut parser = RecordParser::new_with_context(data, RecordContext::BodyText);
+    parser.set_max_records(Some(options.max_records_per_stream));
     let mut section = Section::new();
 
+    // A record read past the end of a paragraph or table, to be handled next
+    let mut pending = None;
+
+    // Placement of the drawing object most recently opened, which the
+    // pictures inside it share
+    let mut shape_object: Option<Picture> = None;
+
     // Parse all records in the section
-    while let Some(record) = parser.parse_next_record()? {
+    while let Some(record) = next_record(&mut parser, &mut pending)? {
         match record.tag_id {
             section::PARA_HEADER => {
-                // Start of a new paragraph
-                let para_header = parse_para_header(&record.data)?;
-                let mut paragraph = Paragraph::new();
-
-                // Parse subsequent paragraph-related records
-                while let Some(next_record) = parser.parse_next_record()? {
-                    match next_record.tag_id {
-                        section::PARA_TEXT => {
-                            let text = parse_para_text(&next_record.data)?;
-                            paragraph.text = text;
-                        }
-                        section::PARA_CHAR_SHAPE => {
-                            let char_shapes = parse_para_char_shapes(
-                                &next_record.data,
-                                para_header.char_shape_count,
-                            )?;
-                            paragraph.char_shapes = char_shapes;
-                        }
-                        section::PARA_LINE_SEG => {
-                            let line_segments = parse_line_segments(&next_record.data)?;
-                            paragraph.line_segments = line_segments;
-                        }
-                        section::PARA_RANGE_TAG => {
-                            // Range tags - skip for now
-                        }
-                        section::PARA_HEADER => {
-                            // Next paragraph starts, put back the record
-                            // We need to handle this differently in real implementation
-                            break;
-                        }
-                        _ => {
-                            // Other record type, might be control or next section
-                            break;
-                        }
+                let paragraph = parse_paragraph(&mut parser, &mut pending, &record)?;
+                section.paragraphs.push(paragraph);
+            }
+
+            section::TABLE => {
+                // Tables hang off a control in the paragraph just parsed
+                let mut table = parse_table(&mut parser, &mut pending, &record, options, 1)?;
+                table.paragraph_index = section.paragraphs.len().saturating_sub(1);
+                section.tables.push(table);
+            }
+
+            section::EQEDIT => {
+                // Equations hang off a control in the paragraph just parsed
+                let mut equation = parse_equation(&record.data)?;
+                equation.paragraph_index = section.paragraphs.len().saturating_sub(1);
+                section.equations.push(equation);
+            }
+
+            section::SHAPE_COMPONENT_PICTURE => {
+                // Pictures hang off a drawing object in the paragraph just parsed
+                let (bin_data_id, crop) = parse_picture_record(&record.data)?;
+                section.pictures.push(Picture {
+                    paragraph_index: section.paragraphs.len().saturating_sub(1),
+                    bin_data_id,
+                    crop,
+                    ..shape_object.clone().unwrap_or_default()
+                });
+            }
+
+            section::CTRL_HEADER => {
+                // A record too short for a control ID names no control
+                let mut header = match parse_control_header(&record.data) {
+                    Ok(header) => header,
+                    Err(e) => {
+                        warn!("Skipping CTRL_HEADER without a control ID: {}", e);
+                        continue;
+                    }
+                };
+                header.paragraph_index = section.paragraphs.len().saturating_sub(1);
+                section.control_headers.push(header);
+
+                let kind = match header.kind() {
+                    Some(CtrlId::Footnote) => NoteKind::Footnote,
+                    Some(CtrlId::Endnote) => NoteKind::Endnote,
+                    Some(id @ (CtrlId::Header | CtrlId::Footer)) => {
+                        let kind = if id == CtrlId::Header {
+                            HeaderFooterKind::Header
+                        } else {
+                            HeaderFooterKind::Footer
+                        };
+                        let paragraphs =
+                            parse_control_paragraphs(&mut parser, &mut pending, &record)?;
+                        section
+                            .headers_footers
+                            .push(HeaderFooter { kind, paragraphs });
+                        continue;
+                    }
+                    Some(CtrlId::GenShapeObject) => {
+                        shape_object =
+                            Some(parse_shape_object(&mut parser, &mut pending, &record)?);
+                        continue;
+                    }
+                    Some(CtrlId::Hyperlink) => {
+                        // Links hang off a field in the paragraph just parsed
+                        let para_index = section.paragraphs.len().saturating_sub(1);
+                        let (char_offset, text) = hyperlink_span(&section, para_index);
+                        section.hyperlinks.push(Hyperlink {
+                            text,
+                            url: parse_hyperlink_url(&record.data)?,
+                            para_index,
+                            char_offset,
+                        });
+                        continue;
+                    }
+                    // Known controls whose content is parsed from their
+                    // own records, or not at all
+                    Some(_) => continue,
+                    None if header.is_field() => continue,
+                    None => {
+                        debug!("Skipping unknown control '{}'", header.name());
+                        continue;
                     }
+                };
+
+                // Notes hang off a control in the paragraph just parsed
+                let mut note = parse_note(&mut parser, &mut pending, &record, kind)?;
+                note.paragraph_index = section.paragraphs.len().saturating_sub(1);
+                note.position = note_position(&section, note.paragraph_index);
+                if note.number == 0 {
+                    let earlier = section.footnotes.iter().filter(|n| n.kind == kind).count();
+                    note.number = earlier as u16 + 1;
                 }
+                section.footnotes.push(note);
+            }
 
-                section.paragraphs.push(paragraph);
+            section::FOOTNOTE_SHAPE => {
+                // The first shape record configures footnotes, the second endnotes
+                let shape = parse_footnote_shape(&record.data)?;
+                if section.footnote_shape.is_none() {
+                    section.footnote_shape = Some(shape);
+                } else if section.endnote_shape.is_none() {
+                    section.endnote_shape = Some(shape);
+                }
+            }
+
+            section::PAGE_DEF => {
+                section.page_defs.push(parse_page_def(&record.data)?);
             }
 
             // Section definition records would be here
@@ -67,6 +164,503 @@ pub fn parse_section(data: &[u8], _section_index: usize) -> Result<Section> {
     Ok(section)
 }
 
+/// Take the pending record if there is one, otherwise read the next record
+fn next_record(parser: &mut RecordParser, pending: &mut Option<Record>) -> Result<Option<Record>> {
+    match pending.take() {
+        Some(record) => Ok(Some(record)),
+        None => parser.parse_next_record(),
+    }
+}
+
+/// Parse a paragraph and the records that belong to it
+///
+/// The first record that is not part of the paragraph is left in `pending`.
+fn parse_paragraph(
+    parser: &mut RecordParser,
+    pending: &mut Option<Record>,
+    header_record: &Record,
+) -> Result<Paragraph> {
+    let mut paragraph = Paragraph::new();
+    paragraph.header = parse_para_header(&header_record.data)?;
+    let mut unit_chars = Vec::new();
+
+    while let Some(record) = next_record(parser, pending)? {
+        match record.tag_id {
+            section::PARA_TEXT => {
+                let (text, controls, units) = parse_para_text(&record.data)?;
+                paragraph.text = text;
+                paragraph.controls = controls;
+                unit_chars = units;
+            }
+            section::PARA_CHAR_SHAPE => {
+                paragraph.char_shapes =
+                    parse_para_char_shapes(&record.data, paragraph.header.char_shape_count)?;
+            }
+            section::PARA_LINE_SEG => {
+                paragraph.line_segments = parse_line_segments(&record.data)?;
+            }
+            section::PARA_RANGE_TAG => {
+                // Range tags - skip for now
+            }
+            _ => {
+                // Next paragraph, a control or another section record;
+                // hand it back to the caller
+                *pending = Some(record);
+                break;
+            }
+        }
+    }
+
+    let mut char_shapes = std::mem::take(&mut paragraph.char_shapes);
+    for shape in &mut char_shapes {
+        shape.position = unit_chars
+            .get(shape.position as usize)
+            .or(unit_chars.last())
+            .copied()
+            .unwrap_or(0);
+    }
+    paragraph.char_shapes = sanitize_char_shapes(char_shapes, paragraph.char_len());
+    Ok(paragraph)
+}
+
+/// Parse a TABLE record and the cells that follow it
+///
+/// Each cell starts with a LIST_HEADER at the table's level and owns the
+/// paragraphs that follow at that level. Parsing stops at the first record
+/// above the table's level.
+///
+/// `depth` counts the tables this one sits in, itself included; parsing
+/// fails once it passes `options.max_nesting`.
+fn parse_table(
+    parser: &mut RecordParser,
+    pending: &mut Option<Record>,
+    table_record: &Record,
+    options: &ParseOptions,
+    depth: usize,
+) -> Result<Table> {
+    if depth > options.max_nesting {
+        return Err(HwpError::ValidationError {
+            message: format!(
+                "Tables are nested more than {} levels deep",
+                options.max_nesting
+            ),
+        });
+    }
+
+    let level = table_record.level;
+    let mut table = parse_table_record(&table_record.data)?;
+    let mut cell: Option<TableCell> = None;
+
+    while let Some(record) = next_record(parser, pending)? {
+        if record.level < level {
+            *pending = Some(record);
+            break;
+        }
+
+        match record.tag_id {
+            section::LIST_HEADER if record.level == level => {
+                table.cells.extend(cell.take());
+                cell = Some(parse_cell_header(&record.data)?);
+            }
+            section::PARA_HEADER if record.level == level => {
+                let paragraph = parse_paragraph(parser, pending, &record)?;
+                if let Some(cell) = cell.as_mut() {
+                    cell.paragraphs.push(paragraph);
+                }
+            }
+            section::TABLE if record.level > level => {
+                // Nested table inside the current cell
+                let mut nested = parse_table(parser, pending, &record, options, depth + 1)?;
+                if let Some(cell) = cell.as_mut() {
+                    nested.paragraph_index = cell.paragraphs.len().saturating_sub(1);
+                    cell.tables.push(nested);
+                }
+            }
+            _ => {
+                // Other controls nested inside cells are skipped
+            }
+        }
+    }
+
+    table.cells.extend(cell);
+    Ok(table)
+}
+
+/// Parse the control ID and properties at the start of a CTRL_HEADER record
+///
+/// Properties read as 0 when the record ends after the control ID.
+fn parse_control_header(data: &[u8]) -> Result<ControlHeader> {
+    let mut reader = ByteReader::new(data);
+    let ctrl_id = reader.read_u32()?;
+    let properties = if reader.remaining() >= 4 {
+        reader.read_u32()?
+    } else {
+        0
+    };
+
+    Ok(ControlHeader {
+        ctrl_id,
+        properties,
+        ..ControlHeader::default()
+    })
+}
+
+/// Parse a footnote or endnote control and the paragraphs of its body
+fn parse_note(
+    parser: &mut RecordParser,
+    pending: &mut Option<Record>,
+    ctrl_record: &Record,
+    kind: NoteKind,
+) -> Result<Note> {
+    // Control ID, then the note number
+    let mut reader = ByteReader::new(&ctrl_record.data);
+    reader.skip(4)?;
+    let number = if reader.remaining() >= 4 {
+        reader.read_u32()? as u16
+    } else {
+        0
+    };
+
+    Ok(Note {
+        kind,
+        number,
+        paragraphs: parse_control_paragraphs(parser, pending, ctrl_record)?,
+        ..Note::default()
+    })
+}
+
+/// Parse the paragraphs of a control's body, such as a note or header
+///
+/// The body is a LIST_HEADER followed by paragraphs one level below the
+/// control. Parsing stops at the first record at or above the control's
+/// level; tables and other controls inside the body are skipped.
+fn parse_control_paragraphs(
+    parser: &mut RecordParser,
+    pending: &mut Option<Record>,
+    ctrl_record: &Record,
+) -> Result<Vec<Paragraph>> {
+    let level = ctrl_record.level;
+    let mut paragraphs = Vec::new();
+
+    while let Some(record) = next_record(parser, pending)? {
+        if record.level <= level {
+            *pending = Some(record);
+            break;
+        }
+
+        if record.tag_id == section::PARA_HEADER && record.level == level + 1 {
+            paragraphs.push(parse_paragraph(parser, pending, &record)?);
+        }
+    }
+
+    Ok(paragraphs)
+}
+
+/// Character position of the next unclaimed note reference in a paragraph
+///
+/// Falls back to the end of the paragraph when its text holds fewer note
+/// controls than notes parsed for it.
+fn note_position(section: &Section, paragraph_index: usize) -> usize {
+    let paragraph = match section.paragraphs.get(paragraph_index) {
+        Some(paragraph) => paragraph,
+        None => return 0,
+    };
+    let claimed = section
+        .footnotes
+        .iter()
+        .filter(|n| n.paragraph_index == paragraph_index)
+        .count();
+
+    paragraph
+        .controls
+        .iter()
+        .filter(|c| {
+            matches!(
+                c.control_type,
+                ControlType::Extended(ExtendedControl::Footnote | ExtendedControl::Endnote)
+            )
+        })
+        .nth(claimed)
+        .map(|c| c.position as usize)
+        .unwrap_or_else(|| paragraph.text.chars().count())
+}
+
+/// Character position and display text of the next unclaimed hyperlink
+/// field in a paragraph
+///
+/// Field ends carry no ID, so each one closes the innermost open field;
+/// a field left open runs to the end of the paragraph. Falls back to an
+/// empty span at the end of the paragraph when its text holds fewer
+/// hyperlink fields than links parsed for it.
+fn hyperlink_span(section: &Section, paragraph_index: usize) -> (usize, String) {
+    let paragraph = match section.paragraphs.get(paragraph_index) {
+        Some(paragraph) => paragraph,
+        None => return (0, String::new()),
+    };
+    let chars: Vec<char> = paragraph.text.chars().collect();
+    let claimed = section
+        .hyperlinks
+        .iter()
+        .filter(|l| l.para_index == paragraph_index)
+        .count();
+
+    // (start, end) of each hyperlink field, in the order the fields start
+    let mut spans: Vec<(usize, Option<usize>)> = Vec::new();
+    let mut open: Vec<Option<usize>> = Vec::new();
+    for control in &paragraph.controls {
+        let position = control.position as usize;
+        match &control.control_type {
+            ControlType::Extended(ExtendedControl::Hyperlink) => {
+                open.push(Some(spans.len()));
+                spans.push((position, None));
+            }
+            ControlType::Extended(ExtendedControl::Field) => open.push(None),
+            ControlType::Extended(ExtendedControl::Other(id)) if id >> 24 == b'%' as u32 => {
+                open.push(None)
+            }
+            ControlType::Inline(code) if *code == FIELD_END as u32 => {
+                if let Some(Some(index)) = open.pop() {
+                    spans[index].1 = Some(position);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match spans.get(claimed) {
+        Some(&(start, end)) => {
+            let start = start.min(chars.len());
+            let end = end.unwrap_or(chars.len()).clamp(start, chars.len());
+            (start, chars[start..end].iter().collect())
+        }
+        None => (chars.len(), String::new()),
+    }
+}
+
+/// Read a hyperlink's target from its field CTRL_HEADER record
+///
+/// The field command holds the URL, with `:` and `;` escaped by a
+/// backslash, followed by `;`-separated link options.
+fn parse_hyperlink_url(data: &[u8]) -> Result<String> {
+    let mut reader = ByteReader::new(data);
+    reader.skip(4)?; // Control ID
+    let _properties = reader.read_u32()?;
+    let _extra_properties = reader.read_u8()?;
+    let length = reader.read_u16()? as usize;
+    let command = reader.read_utf16_string_n(length)?;
+
+    let mut url = String::with_capacity(command.len());
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => url.extend(chars.next()),
+            ';' => break,
+            c => url.push(c),
+        }
+    }
+    Ok(url)
+}
+
+/// Parse the fixed part of a TABLE record
+fn parse_table_record(data: &[u8]) -> Result<Table> {
+    let mut reader = ByteReader::new(data);
+
+    let _properties = reader.read_u32()?;
+    let rows = reader.read_u16()?;
+    let cols = reader.read_u16()?;
+
+    Ok(Table {
+        rows,
+        cols,
+        ..Table::default()
+    })
+}
+
+/// Parse the LIST_HEADER that opens a table cell
+fn parse_cell_header(data: &[u8]) -> Result<TableCell> {
+    let mut reader = ByteReader::new(data);
+
+    // List header: paragraph count and properties
+    let _paragraph_count = reader.read_u32()?;
+    let _properties = reader.read_u32()?;
+
+    // Cell properties
+    let col = reader.read_u16()?;
+    let row = reader.read_u16()?;
+    let col_span = reader.read_u16()?;
+    let row_span = reader.read_u16()?;
+
+    Ok(TableCell {
+        col_span: col_span.max(1),
+        row_span: row_span.max(1),
+        ..TableCell::new(row, col)
+    })
+}
+
+/// Parse an EQEDIT record
+fn parse_equation(data: &[u8]) -> Result<Equation> {
+    let mut reader = ByteReader::new(data);
+
+    // Properties, then the length-prefixed equation script; the character
+    // size, color, baseline, version and font name that follow are not kept
+    let _properties = reader.read_u32()?;
+    let script_len = reader.read_u16()? as usize;
+    let script = reader.read_utf16_string_n(script_len)?;
+
+    Ok(Equation {
+        raw_data: data.to_vec(),
+        text_representation: script,
+        ..Equation::default()
+    })
+}
+
+/// Parse the placement and caption of a drawing object's CTRL_HEADER
+///
+/// The common object properties after the control ID and properties give
+/// the offsets and size; a record too short to hold them leaves them 0.
+/// The caption, if any, is a LIST_HEADER one level below the control
+/// followed by paragraphs at that level. The first record after it is
+/// left in `pending`.
+fn parse_shape_object(
+    parser: &mut RecordParser,
+    pending: &mut Option<Record>,
+    ctrl_record: &Record,
+) -> Result<Picture> {
+    let mut reader = ByteReader::new(&ctrl_record.data);
+    let mut object = Picture::default();
+    if reader.remaining() >= 24 {
+        reader.skip(8)?; // Control ID and properties
+        object.y = reader.read_hwpunit()?;
+        object.x = reader.read_hwpunit()?;
+        object.width = reader.read_hwpunit()?;
+        object.height = reader.read_hwpunit()?;
+    }
+
+    let level = ctrl_record.level + 1;
+    match next_record(parser, pending)? {
+        Some(record) if record.tag_id == section::LIST_HEADER && record.level == level => {}
+        record => {
+            *pending = record;
+            return Ok(object);
+        }
+    }
+
+    let mut caption = Vec::new();
+    while let Some(record) = next_record(parser, pending)? {
+        if record.tag_id != section::PARA_HEADER || record.level != level {
+            *pending = Some(record);
+            break;
+        }
+        let paragraph = parse_paragraph(parser, pending, &record)?;
+        if !paragraph.text.is_empty() {
+            caption.push(paragraph.text);
+        }
+    }
+    object.caption = Some(caption.join(" "));
+
+    Ok(object)
+}
+
+/// Read the BIN_DATA ID and crop rectangle of a SHAPE_COMPONENT_PICTURE
+/// record
+fn parse_picture_record(data: &[u8]) -> Result<(u16, PictureCrop)> {
+    let mut reader = ByteReader::new(data);
+
+    // Border color, thickness and properties and the image rectangle's
+    // four corners come first
+    reader.skip(4 + 4 + 4 + 32)?;
+    let crop = PictureCrop {
+        left: reader.read_i32()?,
+        top: reader.read_i32()?,
+        right: reader.read_i32()?,
+        bottom: reader.read_i32()?,
+    };
+
+    // Inner margins, then brightness, contrast and effect
+    reader.skip(8 + 3)?;
+    Ok((reader.read_u16()?, crop))
+}
+
+/// Parse a FOOTNOTE_SHAPE record
+fn parse_footnote_shape(data: &[u8]) -> Result<FootnoteShape> {
+    let mut reader = ByteReader::new(data);
+
+    let properties = reader.read_u32()?;
+    let user_symbol = reader.read_utf16_string_n(1)?;
+    let prefix_symbol = reader.read_utf16_string_n(1)?;
+    let suffix_symbol = reader.read_utf16_string_n(1)?;
+    let starting_number = reader.read_u16()?;
+    let divider_length = reader.read_u16()? as u32;
+    let divider_margin_top = reader.read_u16()?;
+    let divider_margin_bottom = reader.read_u16()?;
+    let notes_margin_top = reader.read_u16()?;
+    let divider_type = reader.read_u8()?;
+    let divider_thickness = reader.read_u8()?;
+
+    // Divider color was added in a later revision
+    let divider_color = if reader.remaining() >= 4 {
+        reader.read_u32()?
+    } else {
+        0
+    };
+
+    Ok(FootnoteShape {
+        properties,
+        user_symbol,
+        prefix_symbol,
+        suffix_symbol,
+        starting_number,
+        divider_length,
+        divider_margin_top,
+        divider_margin_bottom,
+        notes_margin_top,
+        notes_margin_bottom: 0,
+        divider_type,
+        divider_thickness,
+        divider_color,
+    })
+}
+
+/// Parse a PAGE_DEF record
+///
+/// Sizes and margins are stored as nine HWPUNIT values followed by the
+/// properties, whose bit 0 marks landscape paper.
+fn parse_page_def(data: &[u8]) -> Result<PageDef> {
+    let mut reader = ByteReader::new(data);
+
+    let width = reader.read_hwpunit()?;
+    let height = reader.read_hwpunit()?;
+    let margin_left = reader.read_hwpunit()?;
+    let margin_right = reader.read_hwpunit()?;
+    let margin_top = reader.read_hwpunit()?;
+    let margin_bottom = reader.read_hwpunit()?;
+    let header_margin = reader.read_hwpunit()?;
+    let footer_margin = reader.read_hwpunit()?;
+    let gutter_margin = reader.read_hwpunit()?;
+    let properties = reader.read_u32()?;
+
+    let orientation = if properties & 0x01 != 0 {
+        PageOrientation::Landscape
+    } else {
+        PageOrientation::Portrait
+    };
+
+    Ok(PageDef {
+        width,
+        height,
+        margin_left,
+        margin_right,
+        margin_top,
+        margin_bottom,
+        header_margin,
+        footer_margin,
+        gutter_margin,
+        properties,
+        orientation,
+        footnote_shape_id: 0,
+    })
+}
+
 /// Parse paragraph header
 fn parse_para_header(data: &[u8]) -> Result<ParagraphHeader> {
     let mut reader = ByteReader::new(data);
@@ -93,86 +687,98 @@ fn parse_para_header(data: &[u8]) -> Result<ParagraphHeader> {
 }
 
 /// Parse paragraph text with proper control character handling
-fn parse_para_text(data: &[u8]) -> Result<String> {
+///
+/// Char controls take one code unit; inline and extended controls take
+/// eight, the code followed by seven units of control data. Tabs, line
+/// breaks, hyphens and the special spaces become their Unicode equivalents
+/// and inline objects other than tables become U+FFFC (tables are kept in
+/// [`Section::tables`] instead). Other controls add no text.
+///
+/// Also returns the note controls and field start and end markers found
+/// in the text, positioned by the number of characters before them, and
+/// the number of characters before each code unit, one entry per unit
+/// plus one for the end of the text. Records such as PARA_CHAR_SHAPE
+/// position by code unit, and the map converts those positions.
+fn parse_para_text(data: &[u8]) -> Result<(String, Vec<Control>, Vec<u32>)> {
     // Text is stored as UTF-16LE
     let mut text = String::new();
+    let mut controls = Vec::new();
+    let mut unit_chars = Vec::with_capacity(data.len() / 2 + 1);
+    let mut char_count = 0u32;
     let mut i = 0;
 
     while i + 1 < data.len() {
+        let unit_start = i;
+        let text_len = text.len();
         let ch = u16::from_le_bytes([data[i], data[i + 1]]);
         i += 2;
 
-        // Handle special characters and control codes
+        // Control ID stored after an inline or extended control code
+        let control_id = data
+            .get(i..i + 4)
+            .map(|id| u32::from_le_bytes([id[0], id[1], id[2], id[3]]));
+
         match ch {
-            0x0000 => break,           // Null terminator
-            0x0009 => text.push('\t'), // Tab
-            0x000A => text.push('\n'), // Line feed
-            0x000D => continue,        // Carriage return (skip in Windows-style line endings)
-
-            // HWP specific control characters
-            0x0001 => {
-                // Reserved for future use
-                continue;
-            }
-            0x0002 => {
-                // Section column definition - marks column break
-                // For text extraction, we can treat this as a space or newline
-                text.push(' ');
-            }
-            0x0003 => {
-                // Section definition - marks section break
-                text.push('\n');
-            }
-            0x0004..=0x0007 => {
-                // Reserved control characters
-                continue;
-            }
-            0x0008 => {
-                // Field start - inline control object follows
-                // For now, skip the control data
-                if i + 5 < data.len() {
-                    // Control objects have additional data we need to skip
-                    // Format: type(4 bytes) + additional data
-                    i += 8; // Skip control ID and basic info
-                            // TODO: Parse control objects properly
-                }
-                continue;
+            // Char controls
+            0x0000 => break,                 // Unusable; treated as end of text
+            0x000A => text.push('\n'),       // Line break within the paragraph
+            0x000D => break,                 // Paragraph end; nothing after it belongs here
+            0x0018 => text.push('-'),        // Hyphen
+            0x0019..=0x001D => {}            // Reserved
+            0x001E => text.push('\u{00A0}'), // Non-breaking space
+            0x001F => text.push(' '),        // Fixed-width space
+
+            // Inline and extended controls; each is followed by seven
+            // units of control data
+            0x0009 => {
+                text.push('\t');
+                i = (i + 14).min(data.len());
             }
             0x000B => {
-                // Drawing object/table - for text extraction, skip
-                if i + 5 < data.len() {
-                    i += 8; // Skip control data
+                // Drawing object or table; the content is parsed from the
+                // control records
+                if control_id.and_then(CtrlId::from_u32) != Some(CtrlId::Table) {
+                    text.push('\u{FFFC}');
                 }
-                continue;
-            }
-            0x000C => {
-                // Form feed / page break
-                text.push('\n');
+                i = (i + 14).min(data.len());
             }
-            0x000E..=0x0017 => {
-                // Reserved for special controls
-                continue;
-            }
-            0x0018 => {
-                // Column break
-                text.push('\n');
-            }
-            0x0019 => {
-                // Section break
-                text.push('\n');
+            0x0003 | 0x0011 => {
+                // Field start or footnote/endnote; the field or note is
+                // parsed from the control records
+                if let Some(id) = control_id {
+                    controls.push(Control {
+                        position: char_count,
+                        control_type: ControlType::Extended(ExtendedControl::from_ctrl_id(id)),
+                        data: Vec::new(),
+                    });
+                }
+                i = (i + 14).min(data.len());
             }
-            0x001A..=0x001D => {
-                // Reserved
-                continue;
+            FIELD_END => {
+                controls.push(Control {
+                    position: char_count,
+                    control_type: ControlType::Inline(FIELD_END as u32),
+                    data: Vec::new(),
+                });
+                i = (i + 14).min(data.len());
             }
-            0x001E => {
-                // Hyphen
-                text.push('-');
+            0x0001..=0x0017 => {
+                // Section/column definitions, fields, headers and footers,
+                // auto numbers, bookmarks and the like add no text
+                i = (i + 14).min(data.len());
             }
-            0x001F => {
-                // Non-breaking space
-                text.push('\u{00A0}');
+
+            0xD800..=0xDBFF => {
+                // High surrogate; characters outside the BMP take two units
+                let low = data
+                    .get(i..i + 2)
+                    .map(|unit| u16::from_le_bytes([unit[0], unit[1]]));
+                if let Some(Ok(c)) = low.and_then(|low| char::decode_utf16([ch, low]).next()) {
+                    text.push(c);
+                    i += 2;
+                }
             }
+
             _ => {
                 // Regular character
                 if let Some(c) = char::from_u32(ch as u32) {
@@ -180,9 +786,14 @@ fn parse_para_text(data: &[u8]) -> Result<String> {
                 }
             }
         }
+
+        let units = (i - unit_start + 1) / 2;
+        unit_chars.extend(std::iter::repeat(char_count).take(units));
+        char_count += text[text_len..].chars().count() as u32;
     }
+    unit_chars.push(char_count);
 
-    Ok(text)
+    Ok((text, controls, unit_chars))
 }
 
 /// Parse character shape positions
@@ -199,6 +810,38 @@ fn parse_para_char_shapes(data: &[u8], count: u16) -> Result<Vec<CharShapePos>>
     Ok(shapes)
 }
 
+/// Clamp character shape positions to the text and keep them increasing
+///
+/// A position past the end of the text is moved to the end. A shape at the
+/// same position as the one before it replaces that one, which would cover
+/// no text, as happens when both start within one control; a position
+/// before the previous one is dropped.
+fn sanitize_char_shapes(shapes: Vec<CharShapePos>, char_len: usize) -> Vec<CharShapePos> {
+    let mut sanitized: Vec<CharShapePos> = Vec::with_capacity(shapes.len());
+    for mut shape in shapes {
+        shape.position = shape.position.min(char_len as u32);
+        match sanitized.last_mut() {
+            Some(last) if last.position == shape.position => {
+                *last = shape;
+                continue;
+            }
+            _ => {}
+        }
+        if sanitized
+            .last()
+            .is_some_and(|last| last.position > shape.position)
+        {
+            debug!(
+                "Dropping char shape {} at out-of-order position {}",
+                shape.shape_id, shape.position
+            );
+            continue;
+        }
+        sanitized.push(shape);
+    }
+    sanitized
+}
+
 /// Parse line segments
 fn parse_line_segments(data: &[u8]) -> Result<Vec<LineSegment>> {
     let mut reader = ByteReader::new(data);
@@ -242,3 +885,681 @@ pub fn parse_body_text(data: &[u8]) -> Result<Vec<Section>> {
 
     Ok(sections)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_core::models::document::HwpUnit;
+    use hwp_core::models::section::TABLE_SEPARATOR;
+
+    fn record(tag_id: u16, level: u8, data: &[u8]) -> Vec<u8> {
+        let value = (tag_id as u32) | ((level as u32) << 10) | ((data.len() as u32) << 20);
+        let mut bytes = value.to_le_bytes().to_vec();
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    fn utf16(units: &[u16]) -> Vec<u8> {
+        units.iter().flat_map(|u| u.to_le_bytes()).collect()
+    }
+
+    fn text_units(text: &str) -> Vec<u16> {
+        text.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_soft_break_stays_within_paragraph() {
+        let mut first = text_units("Line one");
+        first.push(0x000A);
+        first.extend(text_units("Line two"));
+        first.push(0x000D);
+
+        let mut second = text_units("Next");
+        second.push(0x000D);
+
+        let mut data = Vec::new();
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&first)));
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&second)));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.paragraphs[0].text, "Line one\nLine two");
+        assert_eq!(section.paragraphs[1].text, "Next");
+    }
+
+    #[test]
+    fn test_page_break_from_para_header() {
+        // Division type is the byte after the u16 para shape and u8 style IDs
+        let mut page_break = [0u8; 22];
+        page_break[11] = 0x04;
+        let mut column_break = [0u8; 22];
+        column_break[11] = 0x08;
+
+        let mut data = Vec::new();
+        for header in [[0u8; 22], page_break, column_break] {
+            data.extend(record(section::PARA_HEADER, 0, &header));
+            data.extend(record(section::PARA_TEXT, 1, &utf16(&[0x000D])));
+        }
+
+        let section = parse_section(&data, 0).unwrap();
+        let breaks: Vec<bool> = section
+            .paragraphs
+            .iter()
+            .map(|p| p.starts_new_page())
+            .collect();
+        assert_eq!(breaks, [false, true, false]);
+        assert_eq!(section.paragraphs[2].header.division_type, 0x08);
+    }
+
+    #[test]
+    fn test_paragraph_end_terminates_text() {
+        let mut units = text_units("Done");
+        units.push(0x000D);
+        units.extend(text_units("junk"));
+
+        assert_eq!(parse_para_text(&utf16(&units)).unwrap().0, "Done");
+    }
+
+    #[test]
+    fn test_control_codes_decoded() {
+        let mut units = text_units("A");
+        units.extend([0x0009, 0, 0, 0, 0, 0, 0, 0x0009]); // Tab
+        units.extend(text_units("B"));
+        units.push(0x000A);
+        units.extend([0x001E, 0x001F, 0x0018]);
+        units.extend([0x000B, 0x6970, 0x2463, 0, 0, 0, 0, 0x000B]); // Picture
+        units.extend([0x000B, 0x6C20, 0x7462, 0, 0, 0, 0, 0x000B]); // Table
+        units.extend([0x0003, 0x6C64, 0x2566, 0, 0, 0, 0, 0x0003]); // Field start
+        units.extend(text_units("C"));
+        units.extend([0x0004, 0x6C64, 0x2566, 0, 0, 0, 0, 0x0004]); // Field end
+        units.push(0x000D);
+
+        let (text, controls, _) = parse_para_text(&utf16(&units)).unwrap();
+        assert_eq!(text, "A\tB\n\u{00A0} -\u{FFFC}C");
+
+        // Only the field markers are kept as controls
+        let positions: Vec<u32> = controls.iter().map(|c| c.position).collect();
+        assert_eq!(positions, [8, 9]);
+        assert!(matches!(
+            controls[0].control_type,
+            ControlType::Extended(ExtendedControl::Other(0x2566_6C64))
+        ));
+        assert!(matches!(
+            controls[1].control_type,
+            ControlType::Inline(0x0004)
+        ));
+    }
+
+    /// A field CTRL_HEADER record with the given control ID and command
+    fn field_header(ctrl_id: &[u8; 4], command: &str) -> Vec<u8> {
+        let mut data = ctrl_id.to_vec();
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&(command.encode_utf16().count() as u16).to_le_bytes());
+        data.extend(utf16(&text_units(command)));
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_hyperlink_fields() {
+        let field_start = |id: u32| [0x0003, id as u16, (id >> 16) as u16, 0, 0, 0, 0, 0x0003];
+        let field_end = [0x0004, 0, 0, 0, 0, 0, 0, 0x0004];
+
+        // "Visit {Hancom} or {{docs}}", the second link nested in a
+        // click-here field
+        let mut units = text_units("Visit ");
+        units.extend(field_start(0x2568_6C6B));
+        units.extend(text_units("Hancom"));
+        units.extend(field_end);
+        units.extend(text_units(" or "));
+        units.extend(field_start(0x2563_6C6B));
+        units.extend(field_start(0x2568_6C6B));
+        units.extend(text_units("docs"));
+        units.extend(field_end);
+        units.extend(field_end);
+        units.push(0x000D);
+
+        let mut data = Vec::new();
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&units)));
+        data.extend(record(
+            section::CTRL_HEADER,
+            1,
+            &field_header(b"klh%", "https\\://www.hancom.com;1;0;0;"),
+        ));
+        data.extend(record(
+            section::CTRL_HEADER,
+            1,
+            &field_header(b"klc%", "Clickhere:set:0:"),
+        ));
+        data.extend(record(
+            section::CTRL_HEADER,
+            1,
+            &field_header(b"klh%", "https\\://example.com/a\\;b;1;0;0;"),
+        ));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs[0].text, "Visit Hancom or docs");
+        assert_eq!(
+            section.hyperlinks,
+            vec![
+                Hyperlink {
+                    text: "Hancom".to_string(),
+                    url: "https://www.hancom.com".to_string(),
+                    para_index: 0,
+                    char_offset: 6,
+                },
+                Hyperlink {
+                    text: "docs".to_string(),
+                    url: "https://example.com/a;b".to_string(),
+                    para_index: 0,
+                    char_offset: 16,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_control_headers_recorded() {
+        let header = |id: &[u8; 4], properties: Option<u32>| {
+            let mut data = u32::from_be_bytes(*id).to_le_bytes().to_vec();
+            data.extend(properties.map(u32::to_le_bytes).unwrap_or_default());
+            data
+        };
+
+        let mut data = Vec::new();
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(
+            section::CTRL_HEADER,
+            1,
+            &header(b"secd", Some(0x10)),
+        ));
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::CTRL_HEADER, 1, &header(b"xyz ", None)));
+        // Too short for a control ID; skipped without failing the section
+        data.extend(record(section::CTRL_HEADER, 1, &[0x20, 0x7A]));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(
+            section.control_headers,
+            vec![
+                ControlHeader {
+                    ctrl_id: 0x7365_6364,
+                    properties: 0x10,
+                    paragraph_index: 0,
+                },
+                ControlHeader {
+                    ctrl_id: 0x7879_7A20,
+                    properties: 0,
+                    paragraph_index: 1,
+                },
+            ]
+        );
+        assert_eq!(section.control_headers[0].kind(), Some(CtrlId::SectionDef));
+        assert_eq!(section.control_headers[1].kind(), None);
+        assert_eq!(section.control_headers[1].name(), "xyz ");
+    }
+
+    #[test]
+    fn test_ctrl_id_four_character_codes() {
+        for &id in CtrlId::ALL {
+            let header = ControlHeader {
+                ctrl_id: id as u32,
+                ..ControlHeader::default()
+            };
+            assert_eq!(header.kind(), Some(id));
+            assert_eq!(header.name(), id.name());
+        }
+
+        let kind = |name: &[u8; 4]| CtrlId::from_u32(u32::from_be_bytes(*name));
+        assert_eq!(kind(b"eqed"), Some(CtrlId::Equation));
+        assert_eq!(kind(b"$pic"), Some(CtrlId::Picture));
+        assert_eq!(kind(b"pgct"), Some(CtrlId::PageOddEvenAdjust));
+        assert_eq!(kind(b"tdut"), Some(CtrlId::DutmalTitle));
+        assert_eq!(kind(b"bokm"), Some(CtrlId::Bookmark));
+        assert_eq!(kind(b"aeop"), None);
+    }
+
+    #[test]
+    fn test_char_shape_runs_sanitized() {
+        let mut header = [0u8; 22];
+        header[12..14].copy_from_slice(&5u16.to_le_bytes()); // char shape count
+
+        let mut shapes = Vec::new();
+        for (position, shape_id) in [(0u32, 1u16), (3, 2), (2, 3), (3, 4), (40, 5)] {
+            shapes.extend_from_slice(&position.to_le_bytes());
+            shapes.extend_from_slice(&shape_id.to_le_bytes());
+        }
+
+        let mut data = record(section::PARA_HEADER, 0, &header);
+        data.extend(record(
+            section::PARA_TEXT,
+            1,
+            &utf16(&text_units("Hello\r")),
+        ));
+        data.extend(record(section::PARA_CHAR_SHAPE, 1, &shapes));
+
+        let section = parse_section(&data, 0).unwrap();
+        let positions: Vec<(u32, u16)> = section.paragraphs[0]
+            .char_shapes
+            .iter()
+            .map(|shape| (shape.position, shape.shape_id))
+            .collect();
+        assert_eq!(positions, [(0, 1), (3, 4), (5, 5)]);
+    }
+
+    #[test]
+    fn test_char_shape_positions_counted_in_chars() {
+        let mut header = [0u8; 22];
+        header[12..14].copy_from_slice(&3u16.to_le_bytes()); // char shape count
+
+        // "A", a tab (8 units), "한" and a character outside the BMP
+        // (2 units), then "B"
+        let mut units = text_units("A");
+        units.extend([0x0009, 0, 0, 0, 0, 0, 0, 0x0009]);
+        units.extend(text_units("한\u{20000}B\r"));
+
+        let mut shapes = Vec::new();
+        for (position, shape_id) in [(0u32, 1u16), (9, 2), (12, 3)] {
+            shapes.extend_from_slice(&position.to_le_bytes());
+            shapes.extend_from_slice(&shape_id.to_le_bytes());
+        }
+
+        let mut data = record(section::PARA_HEADER, 0, &header);
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&units)));
+        data.extend(record(section::PARA_CHAR_SHAPE, 1, &shapes));
+
+        let section = parse_section(&data, 0).unwrap();
+        let paragraph = &section.paragraphs[0];
+        assert_eq!(paragraph.text, "A\t한\u{20000}B");
+        let positions: Vec<(u32, u16)> = paragraph
+            .char_shapes
+            .iter()
+            .map(|shape| (shape.position, shape.shape_id))
+            .collect();
+        assert_eq!(positions, [(0, 1), (2, 2), (4, 3)]);
+    }
+
+    #[test]
+    fn test_picture_bin_data_id() {
+        let mut picture = vec![0u8; 44];
+        for edge in [10i32, 20, 300, 400] {
+            picture.extend_from_slice(&edge.to_le_bytes()); // Crop rectangle
+        }
+        picture.extend_from_slice(&[0; 11]);
+        picture.extend_from_slice(&3u16.to_le_bytes());
+        picture.extend_from_slice(&[0; 9]);
+
+        let mut object = b" osg".to_vec();
+        for value in [0u32, 500, 1000, 14000, 7000] {
+            object.extend_from_slice(&value.to_le_bytes()); // Properties, y, x, width, height
+        }
+        object.extend_from_slice(&[0; 22]);
+
+        let mut data = Vec::new();
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(
+            section::PARA_TEXT,
+            1,
+            &utf16(&[0x000B, 0x6F73, 0x2067, 0, 0, 0, 0, 0x000B, 0x000D]),
+        ));
+        data.extend(record(section::CTRL_HEADER, 1, &object));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(record(section::PARA_HEADER, 2, &[0; 22]));
+        data.extend(record(
+            section::PARA_TEXT,
+            3,
+            &utf16(&text_units("Figure 1\r")),
+        ));
+        data.extend(record(section::SHAPE_COMPONENT, 2, &[0; 8]));
+        data.extend(record(section::SHAPE_COMPONENT_PICTURE, 3, &picture));
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+
+        let section = parse_section(&data, 0).unwrap();
+        // The caption paragraph belongs to the picture, not the body
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(
+            section.pictures,
+            vec![Picture {
+                paragraph_index: 0,
+                bin_data_id: 3,
+                x: HwpUnit(1000),
+                y: HwpUnit(500),
+                width: HwpUnit(14000),
+                height: HwpUnit(7000),
+                crop: PictureCrop {
+                    left: 10,
+                    top: 20,
+                    right: 300,
+                    bottom: 400,
+                },
+                caption: Some("Figure 1".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_picture_without_placement() {
+        let mut picture = vec![0u8; 71];
+        picture.extend_from_slice(&3u16.to_le_bytes());
+
+        let mut data = Vec::new();
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::CTRL_HEADER, 1, b" osg"));
+        data.extend(record(section::SHAPE_COMPONENT, 2, &[0; 8]));
+        data.extend(record(section::SHAPE_COMPONENT_PICTURE, 3, &picture));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(
+            section.pictures,
+            vec![Picture {
+                bin_data_id: 3,
+                ..Picture::default()
+            }]
+        );
+    }
+
+    fn table_record(rows: u16, cols: u16) -> Vec<u8> {
+        let mut data = 0u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&rows.to_le_bytes());
+        data.extend_from_slice(&cols.to_le_bytes());
+        data.extend_from_slice(&[0; 10]);
+        data
+    }
+
+    fn cell_header(row: u16, col: u16, row_span: u16, col_span: u16, paras: u32) -> Vec<u8> {
+        let mut data = paras.to_le_bytes().to_vec();
+        data.extend_from_slice(&0u32.to_le_bytes());
+        for value in [col, row, col_span, row_span] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&[0; 18]);
+        data
+    }
+
+    fn paragraph(level: u8, text: &str) -> Vec<u8> {
+        let mut units = text_units(text);
+        units.push(0x000D);
+        let mut data = record(section::PARA_HEADER, level, &[0; 22]);
+        data.extend(record(section::PARA_TEXT, level + 1, &utf16(&units)));
+        data
+    }
+
+    #[test]
+    fn test_table_cells_and_spans() {
+        // Anchor paragraph: the table control followed by paragraph end
+        let mut anchor = vec![0x000B, 0x6C20, 0x7462, 0, 0, 0, 0, 0x000B];
+        anchor.extend(text_units("Before"));
+        anchor.push(0x000D);
+
+        let mut data = record(section::PARA_HEADER, 0, &[0; 22]);
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&anchor)));
+        data.extend(record(section::CTRL_HEADER, 1, b" lbt"));
+        data.extend(record(section::TABLE, 2, &table_record(2, 2)));
+
+        // Header row merged across both columns
+        data.extend(record(section::LIST_HEADER, 2, &cell_header(0, 0, 1, 2, 1)));
+        data.extend(paragraph(2, "Header"));
+        data.extend(record(section::LIST_HEADER, 2, &cell_header(1, 0, 1, 1, 2)));
+        data.extend(paragraph(2, "A1"));
+        data.extend(paragraph(2, "A2"));
+        // Empty cell with no paragraphs
+        data.extend(record(section::LIST_HEADER, 2, &cell_header(1, 1, 1, 1, 0)));
+
+        data.extend(paragraph(0, "After"));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.paragraphs[0].text, "Before");
+        assert_eq!(section.paragraphs[1].text, "After");
+
+        assert_eq!(section.tables.len(), 1);
+        let table = &section.tables[0];
+        assert_eq!(table.paragraph_index, 0);
+        assert_eq!((table.rows, table.cols), (2, 2));
+        assert_eq!(table.cells.len(), 3);
+
+        let header = table.cell(0, 0).unwrap();
+        assert_eq!((header.row_span, header.col_span), (1, 2));
+        assert_eq!(header.text(), "Header");
+        assert_eq!(table.cell(1, 0).unwrap().text(), "A1\nA2");
+        assert!(table.cell(1, 1).unwrap().paragraphs.is_empty());
+        assert!(table.cell(0, 1).is_none());
+    }
+
+    #[test]
+    fn test_table_markers() {
+        let mut data = paragraph(0, "Before");
+        data.extend(record(section::CTRL_HEADER, 1, b" lbt"));
+        data.extend(record(section::TABLE, 2, &table_record(2, 2)));
+        for (row, col, text) in [(0, 0, "a"), (0, 1, "b"), (1, 0, "c"), (1, 1, "d")] {
+            data.extend(record(
+                section::LIST_HEADER,
+                2,
+                &cell_header(row, col, 1, 1, 1),
+            ));
+            data.extend(paragraph(2, text));
+        }
+        data.extend(paragraph(0, "After"));
+
+        let section = parse_section(&data, 0).unwrap();
+        let text = section.marked_text();
+        assert_eq!(text, "Before\n\u{1E}a\u{1F}b\u{1D}c\u{1F}d\u{1E}After\n");
+
+        let parts: Vec<&str> = text.split(TABLE_SEPARATOR).collect();
+        assert_eq!(parts, ["Before\n", "a\u{1F}b\u{1D}c\u{1F}d", "After\n"]);
+    }
+
+    #[test]
+    fn test_nested_table_in_cell() {
+        let mut data = paragraph(0, "");
+        data.extend(record(section::CTRL_HEADER, 1, b" lbt"));
+        data.extend(record(section::TABLE, 2, &table_record(1, 1)));
+        data.extend(record(section::LIST_HEADER, 2, &cell_header(0, 0, 1, 1, 1)));
+        data.extend(paragraph(2, "Outer"));
+
+        // Inner 1x2 table hangs off the cell's paragraph
+        data.extend(record(section::CTRL_HEADER, 3, b" lbt"));
+        data.extend(record(section::TABLE, 4, &table_record(1, 2)));
+        data.extend(record(section::LIST_HEADER, 4, &cell_header(0, 0, 1, 1, 1)));
+        data.extend(paragraph(4, "x"));
+        data.extend(record(section::LIST_HEADER, 4, &cell_header(0, 1, 1, 1, 1)));
+        data.extend(paragraph(4, "y"));
+
+        data.extend(paragraph(0, "After"));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.tables.len(), 1);
+
+        let outer = &section.tables[0];
+        assert_eq!(outer.cells.len(), 1);
+        let outer_cell = outer.cell(0, 0).unwrap();
+        assert_eq!(outer_cell.text(), "Outer");
+
+        assert_eq!(outer_cell.tables.len(), 1);
+        let inner = &outer_cell.tables[0];
+        assert_eq!((inner.rows, inner.cols), (1, 2));
+        assert_eq!(inner.to_tsv(), "x\ty\n");
+    }
+
+    #[test]
+    fn test_footnote_shape_config() {
+        use hwp_core::models::section::{FootnoteRestart, NumberFormat};
+
+        // Circled digits, restarting on every page, drawn as superscript
+        let properties: u32 = 1 | (2 << 10) | (1 << 12);
+        let mut data = properties.to_le_bytes().to_vec();
+        data.extend(utf16(&[0, 0, u16::from(b')')]));
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&[0; 10]);
+        data.extend_from_slice(&0x00FF_0000u32.to_le_bytes());
+
+        let mut records = paragraph(0, "");
+        records.extend(record(section::FOOTNOTE_SHAPE, 2, &data));
+
+        let section = parse_section(&records, 0).unwrap();
+        let shape = section.footnote_shape.as_ref().unwrap();
+        assert_eq!(shape.suffix_symbol, ")");
+        assert_eq!(shape.divider_color, 0x00FF_0000);
+        assert!(section.endnote_shape.is_none());
+
+        let config = shape.config();
+        assert_eq!(config.number_format, NumberFormat::CircledDigit);
+        assert_eq!(config.restart, FootnoteRestart::PerPage);
+        assert!(config.superscript);
+        assert_eq!(config.starting_number, 1);
+    }
+
+    #[test]
+    fn test_page_def_landscape() {
+        // A4 landscape with 20mm margins all round
+        let mut data = Vec::new();
+        for value in [59528u32, 84188, 5669, 5669, 5669, 5669, 4252, 4252, 0, 1] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let mut records = paragraph(0, "");
+        records.extend(record(section::CTRL_HEADER, 1, b"dces"));
+        records.extend(record(section::PAGE_DEF, 2, &data));
+
+        let section = parse_section(&records, 0).unwrap();
+        assert_eq!(section.page_defs.len(), 1);
+        let page = section.page_def();
+        assert_eq!(page.orientation, PageOrientation::Landscape);
+        assert_eq!((page.width, page.height), (HwpUnit(59528), HwpUnit(84188)));
+        assert_eq!(page.oriented_size(), (HwpUnit(84188), HwpUnit(59528)));
+        assert_eq!(page.margin_left, HwpUnit(5669));
+        assert_eq!(page.footer_margin, HwpUnit(4252));
+
+        // Without a PAGE_DEF the section falls back to A4 portrait
+        let section = parse_section(&paragraph(0, "Text"), 0).unwrap();
+        assert!(section.page_defs.is_empty());
+        assert_eq!(section.page_def(), PageDef::default());
+        assert_eq!(section.page_def().orientation, PageOrientation::Portrait);
+    }
+
+    #[test]
+    fn test_equation_script() {
+        let script = text_units("{a+b} over 2 times pi");
+        let mut data = 0u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&(script.len() as u16).to_le_bytes());
+        data.extend(utf16(&script));
+        data.extend_from_slice(&1000u32.to_le_bytes()); // character size
+
+        let mut records = paragraph(0, "Formula");
+        records.extend(record(section::CTRL_HEADER, 1, b"deqe"));
+        records.extend(record(section::EQEDIT, 2, &data));
+        records.extend(paragraph(0, "After"));
+
+        let section = parse_section(&records, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.equations.len(), 1);
+
+        let equation = &section.equations[0];
+        assert_eq!(equation.paragraph_index, 0);
+        assert_eq!(equation.raw_data, data);
+        assert_eq!(equation.text_representation, "{a+b} over 2 times pi");
+        assert_eq!(equation.plain_text(), "a+b / 2 × π");
+    }
+
+    #[test]
+    fn test_footnotes_and_endnotes() {
+        // "Cited" + footnote control + "." + endnote control
+        let mut anchor = text_units("Cited");
+        anchor.extend([0x0011, 0x2020, 0x666E, 0, 0, 0, 0, 0x0011]);
+        anchor.extend(text_units("."));
+        anchor.extend([0x0011, 0x2020, 0x656E, 0, 0, 0, 0, 0x0011]);
+        anchor.push(0x000D);
+
+        let mut footnote = b"  nf".to_vec();
+        footnote.extend_from_slice(&3u32.to_le_bytes());
+        footnote.extend_from_slice(&[0; 12]);
+
+        let mut data = record(section::PARA_HEADER, 0, &[0; 22]);
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&anchor)));
+        data.extend(record(section::CTRL_HEADER, 1, &footnote));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(paragraph(2, "Kim, 2020."));
+        data.extend(paragraph(2, "p. 12"));
+        // Endnote without a stored number is numbered in order
+        data.extend(record(section::CTRL_HEADER, 1, b"  ne"));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(paragraph(2, "See appendix"));
+        data.extend(paragraph(0, "After"));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.paragraphs[0].text, "Cited.");
+        assert_eq!(section.footnotes.len(), 2);
+
+        let note = &section.footnotes[0];
+        assert_eq!(note.kind, NoteKind::Footnote);
+        assert_eq!(note.number, 3);
+        assert_eq!((note.paragraph_index, note.position), (0, 5));
+        assert_eq!(note.text(), "Kim, 2020. p. 12");
+
+        let endnote = &section.footnotes[1];
+        assert_eq!(endnote.kind, NoteKind::Endnote);
+        assert_eq!(endnote.number, 1);
+        assert_eq!(endnote.position, 6);
+
+        assert_eq!(
+            section.text_with_tables(),
+            "Cited[3].[1]\nAfter\n\n[3] Kim, 2020. p. 12\n[1] See appendix\n"
+        );
+    }
+
+    #[test]
+    fn test_section_part_order() {
+        use hwp_core::models::section::SectionPart;
+
+        let mut anchor = text_units("Body");
+        anchor.extend([0x0011, 0x2020, 0x666E, 0, 0, 0, 0, 0x0011]);
+        anchor.push(0x000D);
+
+        // Header control first, as Hangul writes it, then the cited paragraph
+        let mut data = paragraph(0, "");
+        data.extend(record(section::CTRL_HEADER, 1, b"daeh"));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(paragraph(2, "Journal of Tests"));
+        data.extend(record(section::PARA_HEADER, 0, &[0; 22]));
+        data.extend(record(section::PARA_TEXT, 1, &utf16(&anchor)));
+        data.extend(record(section::CTRL_HEADER, 1, b"  nf"));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(paragraph(2, "A note."));
+        data.extend(record(section::CTRL_HEADER, 1, b"toof"));
+        data.extend(record(section::LIST_HEADER, 2, &[0; 8]));
+        data.extend(paragraph(2, "Page 1"));
+
+        let section = parse_section(&data, 0).unwrap();
+        assert_eq!(section.paragraphs.len(), 2);
+        assert_eq!(section.headers_footers.len(), 2);
+        assert_eq!(section.headers_footers[1].kind, HeaderFooterKind::Footer);
+
+        assert_eq!(
+            section.get_text_ordered(&SectionPart::DEFAULT_ORDER),
+            "Body[1]\n\n[1] A note.\n"
+        );
+        assert_eq!(
+            section.get_text_ordered(&[
+                SectionPart::HeadersFooters,
+                SectionPart::Body,
+                SectionPart::Notes,
+            ]),
+            "Header: Journal of Tests\nFooter: Page 1\n\nBody[1]\n\n[1] A note.\n"
+        );
+        assert_eq!(
+            section.get_text_ordered(&[SectionPart::Notes, SectionPart::Body]),
+            "[1] A note.\n\nBody[1]\n"
+        );
+        assert_eq!(
+            "headers".parse::<SectionPart>().unwrap(),
+            SectionPart::HeadersFooters
+        );
+        assert!("margins".parse::<SectionPart>().is_err());
+    }
+}
@@ -0,0 +1,270 @@
+use crate::reader::ByteReader;
+use encoding_rs::{Encoding, EUC_KR, UTF_16LE};
+use hwp_core::models::document::DocumentSummary;
+use hwp_core::{HwpError, Result};
+
+/// Name of the summary property-set stream in an HWP container
+pub const SUMMARY_STREAM: &str = "\u{5}HwpSummaryInformation";
+
+/// Offset of the property set count, after the byte order, version, system
+/// identifier and CLSID
+const SET_COUNT_OFFSET: usize = 24;
+
+// Property identifiers of the summary information property set
+const PID_CODEPAGE: u32 = 1;
+const PID_TITLE: u32 = 2;
+const PID_SUBJECT: u32 = 3;
+const PID_AUTHOR: u32 = 4;
+const PID_KEYWORDS: u32 = 5;
+const PID_CREATE_DTM: u32 = 12;
+const PID_LASTSAVE_DTM: u32 = 13;
+
+// Property value types
+const VT_I2: u16 = 0x0002;
+const VT_LPSTR: u16 = 0x001E;
+const VT_LPWSTR: u16 = 0x001F;
+const VT_FILETIME: u16 = 0x0040;
+
+/// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01
+const FILETIME_UNIX_OFFSET: u64 = 11_644_473_600;
+
+/// Parse a `\x05HwpSummaryInformation` property-set stream
+///
+/// Only the first property set is read. Byte strings are decoded with the
+/// set's code page (EUC-KR unless it says UTF-16 or UTF-8); properties of
+/// other types or with unreadable values are ignored.
+pub fn parse_summary(data: &[u8]) -> Result<DocumentSummary> {
+    let mut reader = ByteReader::new(data);
+
+    let byte_order = reader.read_u16()?;
+    if byte_order != 0xFFFE {
+        return Err(HwpError::InvalidFormat {
+            reason: format!("Invalid property set byte order 0x{:04X}", byte_order),
+        });
+    }
+    reader.seek(SET_COUNT_OFFSET)?;
+    let set_count = reader.read_u32()?;
+    if set_count == 0 {
+        return Ok(DocumentSummary::default());
+    }
+    reader.skip(16)?; // FMTID
+    let set_offset = reader.read_u32()? as usize;
+
+    reader.seek(set_offset)?;
+    let _set_size = reader.read_u32()?;
+    let property_count = reader.read_u32()? as usize;
+
+    let mut properties = Vec::with_capacity(property_count.min(64));
+    for _ in 0..property_count {
+        let id = reader.read_u32()?;
+        let offset = reader.read_u32()? as usize;
+        properties.push((id, set_offset + offset));
+    }
+
+    // The code page decides how byte strings are decoded
+    let mut encoding = EUC_KR;
+    for &(id, offset) in &properties {
+        if id == PID_CODEPAGE {
+            if let Ok(Value::Int(code_page)) = read_value(data, offset, encoding) {
+                encoding = code_page_encoding(code_page as u16);
+            }
+        }
+    }
+
+    let mut summary = DocumentSummary::default();
+    for (id, offset) in properties {
+        let value = match read_value(data, offset, encoding) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+
+        match (id, value) {
+            (PID_TITLE, Value::Text(text)) => summary.title = Some(text),
+            (PID_SUBJECT, Value::Text(text)) => summary.subject = Some(text),
+            (PID_AUTHOR, Value::Text(text)) => summary.author = Some(text),
+            (PID_KEYWORDS, Value::Text(text)) => summary.keywords = Some(text),
+            (PID_CREATE_DTM, Value::Time(time)) => summary.created = format_filetime(time),
+            (PID_LASTSAVE_DTM, Value::Time(time)) => summary.modified = format_filetime(time),
+            _ => {}
+        }
+    }
+
+    Ok(summary)
+}
+
+/// A decoded property value
+enum Value {
+    Int(i16),
+    Text(String),
+    Time(u64),
+    Other,
+}
+
+/// Read the typed value stored at `offset`
+fn read_value(data: &[u8], offset: usize, encoding: &'static Encoding) -> Result<Value> {
+    let mut reader = ByteReader::new(data);
+    reader.seek(offset)?;
+
+    let value_type = reader.read_u16()?;
+    reader.skip(2)?; // padding
+
+    Ok(match value_type {
+        VT_I2 => Value::Int(reader.read_u16()? as i16),
+        VT_LPSTR => {
+            let size = reader.read_u32()? as usize;
+            let bytes = reader.read_bytes(size)?;
+            Value::Text(decode_string(&bytes, encoding))
+        }
+        VT_LPWSTR => {
+            // Length in characters, including the terminating null
+            let len = reader.read_u32()? as usize;
+            let bytes = reader.read_bytes(len * 2)?;
+            Value::Text(decode_string(&bytes, UTF_16LE))
+        }
+        VT_FILETIME => {
+            let low = reader.read_u32()? as u64;
+            let high = reader.read_u32()? as u64;
+            Value::Time(high << 32 | low)
+        }
+        _ => Value::Other,
+    })
+}
+
+/// Decode a string value, dropping the null terminator and any padding
+fn decode_string(bytes: &[u8], encoding: &'static Encoding) -> String {
+    let (decoded, _) = encoding.decode_without_bom_handling(bytes);
+    decoded.trim_end_matches('\0').to_string()
+}
+
+/// Encoding for a Windows code page
+fn code_page_encoding(code_page: u16) -> &'static Encoding {
+    match code_page {
+        1200 => UTF_16LE,
+        65001 => encoding_rs::UTF_8,
+        _ => EUC_KR,
+    }
+}
+
+/// Format a FILETIME (100ns ticks since 1601) as an ISO 8601 UTC timestamp
+fn format_filetime(filetime: u64) -> Option<String> {
+    let seconds = (filetime / 10_000_000).checked_sub(FILETIME_UNIX_OFFSET)?;
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+
+    // Civil date from days since 1970-01-01
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a single-set property stream from (id, type, value bytes)
+    fn property_stream(properties: &[(u32, u16, Vec<u8>)]) -> Vec<u8> {
+        let mut values = Vec::new();
+        let mut index = Vec::new();
+        let index_size = 8 + properties.len() * 8;
+        for (id, value_type, bytes) in properties {
+            index.push((*id, index_size + values.len()));
+            values.extend_from_slice(&(*value_type as u32).to_le_bytes());
+            values.extend_from_slice(bytes);
+            while values.len() % 4 != 0 {
+                values.push(0);
+            }
+        }
+
+        let mut data = vec![0xFE, 0xFF, 0, 0];
+        data.extend_from_slice(&[0; 20]);
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[0; 16]);
+        data.extend_from_slice(&48u32.to_le_bytes());
+
+        data.extend_from_slice(&((index_size + values.len()) as u32).to_le_bytes());
+        data.extend_from_slice(&(properties.len() as u32).to_le_bytes());
+        for (id, offset) in index {
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&(offset as u32).to_le_bytes());
+        }
+        data.extend(values);
+        data
+    }
+
+    fn lpstr(bytes: &[u8]) -> Vec<u8> {
+        let mut data = ((bytes.len() + 1) as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(bytes);
+        data.push(0);
+        data
+    }
+
+    fn lpwstr(text: &str) -> Vec<u8> {
+        let units: Vec<u16> = text.encode_utf16().chain(Some(0)).collect();
+        let mut data = (units.len() as u32).to_le_bytes().to_vec();
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn test_parse_summary_properties() {
+        let (title, _, _) = EUC_KR.encode("보고서");
+        // 2024-03-01T09:30:00Z
+        let created: u64 = (1_709_285_400 + FILETIME_UNIX_OFFSET) * 10_000_000;
+
+        let data = property_stream(&[
+            (PID_CODEPAGE, VT_I2, 949u16.to_le_bytes().to_vec()),
+            (PID_TITLE, VT_LPSTR, lpstr(&title)),
+            (PID_AUTHOR, VT_LPWSTR, lpwstr("홍길동")),
+            (PID_KEYWORDS, VT_LPWSTR, lpwstr("예산, 계획")),
+            (PID_CREATE_DTM, VT_FILETIME, created.to_le_bytes().to_vec()),
+        ]);
+
+        let summary = parse_summary(&data).unwrap();
+        assert_eq!(summary.title.as_deref(), Some("보고서"));
+        assert_eq!(summary.author.as_deref(), Some("홍길동"));
+        assert_eq!(summary.keywords.as_deref(), Some("예산, 계획"));
+        assert_eq!(summary.subject, None);
+        assert_eq!(summary.created.as_deref(), Some("2024-03-01T09:30:00Z"));
+        assert_eq!(summary.modified, None);
+    }
+
+    #[test]
+    fn test_utf16_code_page_strings() {
+        let title: Vec<u8> = "제목\0"
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+        let mut value = (title.len() as u32).to_le_bytes().to_vec();
+        value.extend(title);
+
+        let data = property_stream(&[
+            (PID_CODEPAGE, VT_I2, 1200u16.to_le_bytes().to_vec()),
+            (PID_TITLE, VT_LPSTR, value),
+        ]);
+
+        let summary = parse_summary(&data).unwrap();
+        assert_eq!(summary.title.as_deref(), Some("제목"));
+    }
+
+    #[test]
+    fn test_invalid_byte_order() {
+        assert!(parse_summary(&[0; 48]).is_err());
+    }
+}
@@ -1,9 +1,14 @@
 use byteorder::{LittleEndian, ReadBytesExt};
 use encoding_rs::EUC_KR;
+use hwp_core::models::document::HwpUnit;
 use hwp_core::{HwpError, Result};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
 /// A reader for parsing binary HWP data
+///
+/// The position never moves past the end of the buffer, so
+/// `remaining() == len() - position()` holds after every operation,
+/// including failed reads.
 pub struct ByteReader<'a> {
     cursor: Cursor<&'a [u8]>,
     size: usize,
@@ -25,7 +30,13 @@ impl<'a> ByteReader<'a> {
 
     /// Get the remaining bytes available to read
     pub fn remaining(&self) -> usize {
-        self.size.saturating_sub(self.position())
+        debug_assert!(
+            self.position() <= self.size,
+            "position {} past end of {}-byte buffer",
+            self.position(),
+            self.size
+        );
+        self.size - self.position()
     }
 
     /// Check if we've reached the end of the buffer
@@ -57,7 +68,7 @@ impl<'a> ByteReader<'a> {
         Ok(())
     }
 
-    /// Get the total size of the buffer
+    /// Get the total size of the buffer, regardless of the position
     pub fn len(&self) -> usize {
         self.size
     }
@@ -68,17 +79,33 @@ impl<'a> ByteReader<'a> {
     }
 
     /// Peek at bytes without advancing the cursor
-    pub fn peek_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+    pub fn peek_bytes(&self, n: usize) -> Result<&'a [u8]> {
         if self.remaining() < n {
             return Err(HwpError::BufferUnderflow {
                 requested: n,
                 available: self.remaining(),
             });
         }
-        let current_pos = self.position();
-        let result = self.read_bytes(n)?;
-        self.seek(current_pos)?;
-        Ok(result)
+        let data: &'a [u8] = self.cursor.get_ref();
+        let start = self.position();
+        Ok(&data[start..start + n])
+    }
+
+    /// Peek at the next byte without advancing the cursor
+    pub fn peek_u8(&self) -> Result<u8> {
+        Ok(self.peek_bytes(1)?[0])
+    }
+
+    /// Peek at the next 16-bit unsigned integer (little-endian)
+    pub fn peek_u16(&self) -> Result<u16> {
+        let bytes = self.peek_bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Peek at the next 32-bit unsigned integer (little-endian)
+    pub fn peek_u32(&self) -> Result<u32> {
+        let bytes = self.peek_bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
     }
 
     /// Read a single byte
@@ -147,6 +174,11 @@ impl<'a> ByteReader<'a> {
         Ok(self.cursor.read_i32::<LittleEndian>()?)
     }
 
+    /// Read a length in HWPUNIT (signed 32-bit, little-endian)
+    pub fn read_hwpunit(&mut self) -> Result<HwpUnit> {
+        self.read_i32().map(HwpUnit)
+    }
+
     /// Read a 64-bit unsigned integer (little-endian)
     pub fn read_u64(&mut self) -> Result<u64> {
         if self.remaining() < 8 {
@@ -226,6 +258,24 @@ impl<'a> ByteReader<'a> {
         String::from_utf16(&utf16_chars).map_err(|e| HwpError::EncodingError(e.to_string()))
     }
 
+    /// Read a UTF-16LE string up to (not including) a terminator code
+    ///
+    /// The terminator is left unread so callers can dispatch on it.
+    pub fn read_utf16_until(&mut self, term: u16) -> Result<String> {
+        let mut utf16_chars = Vec::new();
+
+        loop {
+            let ch = self.read_u16()?;
+            if ch == term {
+                self.seek(self.position() - 2)?;
+                break;
+            }
+            utf16_chars.push(ch);
+        }
+
+        String::from_utf16(&utf16_chars).map_err(|e| HwpError::EncodingError(e.to_string()))
+    }
+
     /// Read a null-terminated EUC-KR string
     pub fn read_euc_kr_string(&mut self) -> Result<String> {
         let mut bytes = Vec::new();
@@ -290,12 +340,47 @@ impl<'a> ByteReader<'a> {
 
         Ok(ByteReader::new(sub_data))
     }
+
+    /// Create a reader over `size` bytes starting at `offset` in the
+    /// underlying buffer, without moving this reader's position
+    ///
+    /// The window borrows the original buffer rather than this reader, so
+    /// it can outlive it.
+    pub fn window(&self, offset: usize, size: usize) -> Result<ByteReader<'a>> {
+        let data: &'a [u8] = self.cursor.get_ref();
+        match offset.checked_add(size) {
+            Some(end) if end <= data.len() => Ok(ByteReader::new(&data[offset..end])),
+            _ => Err(HwpError::BufferUnderflow {
+                requested: size,
+                available: data.len().saturating_sub(offset),
+            }),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_window_leaves_position() {
+        let data = [1u8, 2, 3, 4, 5, 6];
+        let window = {
+            let mut reader = ByteReader::new(&data);
+            reader.skip(1).unwrap();
+            let mut window = reader.window(2, 3).unwrap();
+            assert_eq!(reader.position(), 1);
+            assert_eq!(window.read_u8().unwrap(), 3);
+            assert!(reader.window(4, 3).is_err());
+            assert!(reader.window(usize::MAX, 2).is_err());
+            window
+        };
+
+        // The window outlives the reader it came from
+        assert_eq!(window.position(), 1);
+        assert_eq!(window.remaining(), 2);
+    }
+
     #[test]
     fn test_read_basic_types() {
         let data = vec![
@@ -314,6 +399,65 @@ mod tests {
         assert!(reader.is_eof());
     }
 
+    #[test]
+    fn test_read_hwpunit() {
+        // 59528 (A4 width) and -1200
+        let data = [0x88, 0xE8, 0x00, 0x00, 0x50, 0xFB, 0xFF, 0xFF, 0x01];
+        let mut reader = ByteReader::new(&data);
+
+        let width = reader.read_hwpunit().unwrap();
+        assert_eq!(width, HwpUnit(59528));
+        assert_eq!(width.to_mm().round(), 210.0);
+        assert_eq!(reader.read_hwpunit().unwrap().to_pt(), -12.0);
+        assert!(reader.read_hwpunit().is_err());
+    }
+
+    #[test]
+    fn test_peek_does_not_advance() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut reader = ByteReader::new(&data);
+        reader.skip(1).unwrap();
+
+        assert_eq!(reader.peek_bytes(3).unwrap(), &[0x02, 0x03, 0x04]);
+        assert_eq!(reader.peek_u8().unwrap(), 0x02);
+        assert_eq!(reader.peek_u16().unwrap(), 0x0302);
+        assert_eq!(reader.peek_u32().unwrap(), 0x05040302);
+        assert_eq!(reader.position(), 1);
+
+        // Peeking past the end fails without moving either
+        assert!(reader.peek_bytes(5).is_err());
+        assert_eq!(reader.position(), 1);
+        assert_eq!(reader.read_u32().unwrap(), 0x05040302);
+        assert!(reader.peek_u8().is_err());
+    }
+
+    #[test]
+    fn test_remaining_is_len_minus_position() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05];
+        let mut reader = ByteReader::new(&data);
+        let check = |reader: &ByteReader| {
+            assert_eq!(reader.len(), 5);
+            assert_eq!(reader.remaining(), reader.len() - reader.position());
+        };
+
+        check(&reader);
+        reader.read_u16().unwrap();
+        check(&reader);
+        reader.seek(4).unwrap();
+        check(&reader);
+        assert!(reader.skip(2).is_err());
+        check(&reader);
+        assert!(reader.read_u32().is_err());
+        check(&reader);
+        assert!(reader.seek(6).is_err());
+        check(&reader);
+
+        let empty = ByteReader::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.remaining(), 0);
+        assert!(!reader.is_empty());
+    }
+
     #[test]
     fn test_utf16_string() {
         // "한글" in UTF-16LE with null terminator
@@ -328,6 +472,26 @@ mod tests {
         assert_eq!(s, "한글");
     }
 
+    #[test]
+    fn test_utf16_until_terminator() {
+        // "한A" followed by a field-end sentinel (0x0004) and trailing data
+        let data = vec![
+            0x5C, 0xD5, // '한'
+            0x41, 0x00, // 'A'
+            0x04, 0x00, // sentinel
+            0x42, 0x00, // 'B'
+        ];
+
+        let mut reader = ByteReader::new(&data);
+        assert_eq!(reader.read_utf16_until(0x0004).unwrap(), "한A");
+        assert_eq!(reader.read_u16().unwrap(), 0x0004);
+        assert_eq!(reader.read_u16().unwrap(), 0x0042);
+
+        // Missing terminator is an error rather than a silent truncation
+        let mut reader = ByteReader::new(&data[..4]);
+        assert!(reader.read_utf16_until(0x0004).is_err());
+    }
+
     #[test]
     fn test_buffer_underflow() {
         let data = vec![0x01, 0x02];
@@ -0,0 +1,231 @@
+use crate::parser::record::RecordParser;
+use crate::validator::RecordContext;
+use hwp_core::constants::tag_id::section;
+use hwp_core::models::section::{Equation, Table};
+use hwp_core::models::Paragraph;
+use hwp_core::{HwpDocument, Result};
+use regex::Regex;
+
+/// Character that overwrites each character of a match
+pub const REDACTION_CHAR: char = '█';
+
+/// Overwrite every character of every match of `patterns` in `text` with
+/// [`REDACTION_CHAR`]
+///
+/// The text keeps its character count, so character positions into it,
+/// such as char shape positions and hyperlink offsets, stay valid. Patterns
+/// are applied in order, each to the output of the one before. Returns the
+/// redacted text and the number of matches replaced.
+pub fn redact_text(text: &str, patterns: &[Regex]) -> (String, usize) {
+    let mut text = text.to_string();
+    let mut count = 0;
+    for pattern in patterns {
+        let mut found = 0;
+        text = pattern
+            .replace_all(&text, |captures: &regex::Captures| {
+                let matched = &captures[0];
+                if !matched.is_empty() {
+                    found += 1;
+                }
+                REDACTION_CHAR.to_string().repeat(matched.chars().count())
+            })
+            .into_owned();
+        count += found;
+    }
+    (text, count)
+}
+
+/// Redact the text of every paragraph in a parsed document
+///
+/// Body paragraphs, table cells (nested tables included), notes, headers,
+/// footers, hyperlink text and targets, equation scripts, picture captions
+/// and the summary's title, author, subject and keywords are all covered.
+/// Returns the number of matches replaced.
+pub fn redact_document(document: &mut HwpDocument, patterns: &[Regex]) -> usize {
+    let mut count = 0;
+    if let Some(summary) = &mut document.summary {
+        for field in [
+            &mut summary.title,
+            &mut summary.author,
+            &mut summary.subject,
+            &mut summary.keywords,
+        ] {
+            count += redact_option(field, patterns);
+        }
+    }
+    for section in &mut document.sections {
+        count += redact_paragraphs(&mut section.paragraphs, patterns);
+        for table in &mut section.tables {
+            count += redact_table(table, patterns);
+        }
+        for note in &mut section.footnotes {
+            count += redact_paragraphs(&mut note.paragraphs, patterns);
+        }
+        for header_footer in &mut section.headers_footers {
+            count += redact_paragraphs(&mut header_footer.paragraphs, patterns);
+        }
+        for link in &mut section.hyperlinks {
+            count += redact_string(&mut link.text, patterns);
+            count += redact_string(&mut link.url, patterns);
+        }
+        for equation in &mut section.equations {
+            count += redact_equation(equation, patterns);
+        }
+        for picture in &mut section.pictures {
+            count += redact_option(&mut picture.caption, patterns);
+        }
+    }
+    count
+}
+
+fn redact_string(text: &mut String, patterns: &[Regex]) -> usize {
+    let (redacted, found) = redact_text(text, patterns);
+    if found > 0 {
+        *text = redacted;
+    }
+    found
+}
+
+fn redact_option(text: &mut Option<String>, patterns: &[Regex]) -> usize {
+    text.as_mut()
+        .map_or(0, |text| redact_string(text, patterns))
+}
+
+/// Redact an equation's script, both as decoded and in its raw record
+fn redact_equation(equation: &mut Equation, patterns: &[Regex]) -> usize {
+    let found = redact_string(&mut equation.text_representation, patterns);
+    if found > 0 {
+        // The script follows the u32 properties and its u16 length
+        let data = &mut equation.raw_data;
+        if data.len() >= 6 {
+            let len = u16::from_le_bytes([data[4], data[5]]) as usize;
+            let end = data.len().min(6 + len * 2);
+            let script = &mut data[6..end];
+            let mut units: Vec<u16> = script
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect();
+            redact_units(&mut units, patterns);
+            for (pair, unit) in script.chunks_exact_mut(2).zip(units) {
+                pair.copy_from_slice(&unit.to_le_bytes());
+            }
+        }
+    }
+    found
+}
+
+fn redact_paragraphs(paragraphs: &mut [Paragraph], patterns: &[Regex]) -> usize {
+    let mut count = 0;
+    for paragraph in paragraphs {
+        count += redact_string(&mut paragraph.text, patterns);
+    }
+    count
+}
+
+fn redact_table(table: &mut Table, patterns: &[Regex]) -> usize {
+    let mut count = 0;
+    for cell in &mut table.cells {
+        count += redact_paragraphs(&mut cell.paragraphs, patterns);
+        for nested in &mut cell.tables {
+            count += redact_table(nested, patterns);
+        }
+    }
+    count
+}
+
+/// Redact the text of a PARA_TEXT record in place
+///
+/// Every UTF-16 unit of a match is overwritten with [`REDACTION_CHAR`], so
+/// the record keeps its length and the character positions that the
+/// paragraph's header, char shapes and line segments refer to stay valid.
+/// Matches never span a control character. Returns the new record data and
+/// the number of matches replaced.
+pub fn redact_para_text(data: &[u8], patterns: &[Regex]) -> (Vec<u8>, usize) {
+    let mut units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    let mut count = 0;
+    let mut i = 0;
+    while i < units.len() {
+        match units[i] {
+            // Unusable and paragraph end; nothing after them is text
+            0x0000 | 0x000D => break,
+            // Line break
+            0x000A => i += 1,
+            // Inline and extended controls, with seven units of control data
+            0x0001..=0x0017 => i += 8,
+            // Other char controls
+            0x0018..=0x001F => i += 1,
+            _ => {
+                let start = i;
+                while i < units.len() && units[i] >= 0x0020 {
+                    i += 1;
+                }
+                count += redact_units(&mut units[start..i], patterns);
+            }
+        }
+    }
+
+    let mut redacted: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+    // Keep a stray odd byte as it was
+    redacted.extend_from_slice(&data[units.len() * 2..]);
+    (redacted, count)
+}
+
+/// Overwrite the units of every match in a run of text units
+fn redact_units(units: &mut [u16], patterns: &[Regex]) -> usize {
+    // Decode the run, remembering the unit each character starts at
+    let mut text = String::new();
+    let mut unit_at = Vec::new();
+    let mut unit = 0;
+    for decoded in char::decode_utf16(units.iter().copied()) {
+        let (c, len) = match decoded {
+            Ok(c) => (c, c.len_utf16()),
+            Err(_) => (char::REPLACEMENT_CHARACTER, 1),
+        };
+        unit_at.resize(text.len(), 0);
+        unit_at.push(unit);
+        text.push(c);
+        unit += len;
+    }
+    unit_at.resize(text.len(), 0);
+    unit_at.push(units.len());
+
+    let mut count = 0;
+    for pattern in patterns {
+        for found in pattern.find_iter(&text) {
+            if found.is_empty() {
+                continue;
+            }
+            for unit in &mut units[unit_at[found.start()]..unit_at[found.end()]] {
+                *unit = REDACTION_CHAR as u16;
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Redact every PARA_TEXT record of a decompressed BodyText section stream
+///
+/// Records are re-encoded in their original order with only their text
+/// changed, so the result can replace the section in the document once
+/// compressed again. Writing the CFB container back out is up to the
+/// caller. Returns the new stream and the number of matches replaced.
+pub fn redact_section_stream(data: &[u8], patterns: &[Regex]) -> Result<(Vec<u8>, usize)> {
+    let mut parser = RecordParser::new_with_context(data, RecordContext::BodyText);
+
+    let mut stream = Vec::with_capacity(data.len());
+    let mut count = 0;
+    while let Some(mut record) = parser.parse_next_record()? {
+        if record.tag_id == section::PARA_TEXT {
+            let (text, found) = redact_para_text(&record.data, patterns);
+            record.data = text;
+            count += found;
+        }
+        stream.extend(record.to_bytes());
+    }
+    Ok((stream, count))
+}
@@ -0,0 +1,143 @@
+use hwp_core::{HwpDocument, HwpError, Result};
+use regex::Regex;
+
+/// Options controlling how a document is searched
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    /// Treat the query as a regular expression
+    pub regex: bool,
+    /// Ignore case when matching
+    pub case_insensitive: bool,
+    /// Report lines that do not match instead
+    pub invert_match: bool,
+    /// Stop after this many matches
+    pub max_results: Option<usize>,
+    /// Lines of context to collect before each match
+    pub before_context: usize,
+    /// Lines of context to collect after each match
+    pub after_context: usize,
+}
+
+/// A single matching line within a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub section: usize,
+    pub paragraph: usize,
+    pub line: usize,
+    pub text: String,
+    pub context_before: Vec<String>,
+    pub context_after: Vec<String>,
+    /// Where the line's first match lies in the paragraph; `None` for
+    /// lines reported by an inverted search
+    pub span: Option<MatchSpan>,
+}
+
+/// Offsets of a match within its paragraph's text
+///
+/// Character offsets count Unicode scalar values, so they index Hangul
+/// and other CJK text the way a viewer does. End offsets are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchSpan {
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+impl MatchSpan {
+    /// Span of the byte range `start..end` of `text`
+    fn new(text: &str, start: usize, end: usize) -> Self {
+        let char_start = text[..start].chars().count();
+        Self {
+            byte_start: start,
+            byte_end: end,
+            char_start,
+            char_end: char_start + text[start..end].chars().count(),
+        }
+    }
+}
+
+/// Build the search pattern for a query
+///
+/// Plain-text queries are escaped unless `options.regex` is set.
+pub fn build_pattern(query: &str, options: &SearchOptions) -> Result<Regex> {
+    let pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+
+    let pattern = if options.case_insensitive {
+        format!("(?i){}", pattern)
+    } else {
+        pattern
+    };
+
+    Regex::new(&pattern).map_err(|e| HwpError::InvalidFormat {
+        reason: format!("Invalid search pattern '{}': {}", pattern, e),
+    })
+}
+
+/// Search every paragraph line of a document for a pattern
+pub fn search_document(
+    document: &HwpDocument,
+    pattern: &Regex,
+    options: &SearchOptions,
+) -> Vec<Match> {
+    let max_results = options.max_results.unwrap_or(usize::MAX);
+    let mut matches = Vec::new();
+
+    for (section_idx, section) in document.sections.iter().enumerate() {
+        for (para_idx, paragraph) in section.paragraphs.iter().enumerate() {
+            let text = &paragraph.text;
+            let lines: Vec<&str> = text.lines().collect();
+            // `lines` splits after each '\n', so this gives each line's
+            // byte offset in the paragraph
+            let line_starts: Vec<usize> = text
+                .split_inclusive('\n')
+                .scan(0, |offset, piece| {
+                    let start = *offset;
+                    *offset += piece.len();
+                    Some(start)
+                })
+                .collect();
+
+            for (line_idx, line) in lines.iter().enumerate() {
+                let found = pattern.find(line);
+                if found.is_some() == options.invert_match {
+                    continue;
+                }
+                let span = found.map(|m| {
+                    let line_start = line_starts[line_idx];
+                    MatchSpan::new(text, line_start + m.start(), line_start + m.end())
+                });
+
+                // Context never crosses paragraph boundaries
+                let start = line_idx.saturating_sub(options.before_context);
+                let end = std::cmp::min(line_idx + options.after_context + 1, lines.len());
+
+                matches.push(Match {
+                    section: section_idx,
+                    paragraph: para_idx,
+                    line: line_idx,
+                    text: line.to_string(),
+                    context_before: lines[start..line_idx]
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect(),
+                    context_after: lines[(line_idx + 1)..end]
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect(),
+                    span,
+                });
+
+                if matches.len() >= max_results {
+                    return matches;
+                }
+            }
+        }
+    }
+
+    matches
+}
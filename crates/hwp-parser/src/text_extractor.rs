@@ -1,6 +1,7 @@
 use crate::cfb::parse_cfb_bytes;
 use crate::parser::section::parse_body_text;
-use hwp_core::{HwpDocument, HwpError, Result};
+use hwp_core::models::document::DocInfo;
+use hwp_core::{HwpDocument, HwpError, Paragraph, Result, TextItem};
 use std::io::Cursor;
 
 /// Text extractor for HWP documents
@@ -76,10 +77,10 @@ impl TextExtractor {
     pub fn extract_from_document(doc: &HwpDocument) -> Result<String> {
         let mut text = String::new();
 
-        for section in &doc.sections {
-            for paragraph in &section.paragraphs {
-                if !paragraph.text.is_empty() {
-                    text.push_str(&paragraph.text);
+        for item in Self::paragraphs(doc) {
+            if let TextItem::Paragraph { text: t, .. } = item {
+                if !t.is_empty() {
+                    text.push_str(t);
                     text.push('\n');
                 }
             }
@@ -88,6 +89,20 @@ impl TextExtractor {
         Ok(text.trim().to_string())
     }
 
+    /// Stream paragraph text without building the whole document string
+    ///
+    /// Yields borrowed text for each paragraph, with a
+    /// [`TextItem::SectionEnd`] marker closing every section.
+    pub fn paragraphs(doc: &HwpDocument) -> impl Iterator<Item = TextItem<'_>> {
+        doc.text_items()
+    }
+
+    /// Extract text from a parsed HWP document, drawing tables as ASCII grids
+    pub fn extract_with_tables(doc: &HwpDocument) -> Result<String> {
+        let text: String = doc.sections.iter().map(|s| s.text_with_tables()).collect();
+        Ok(text.trim().to_string())
+    }
+
     /// Extract text from a single section's raw data
     pub fn extract_from_section(section_data: &[u8]) -> Result<String> {
         let sections = parse_body_text(section_data)?;
@@ -106,7 +121,118 @@ impl TextExtractor {
     }
 }
 
+impl TextExtractor {
+    /// Get a paragraph's text with superscript and subscript runs rewritten
+    ///
+    /// Runs come from the paragraph's char shape positions. A run whose
+    /// characters all have Unicode script forms is converted (`x²`, `H₂O`);
+    /// otherwise it is wrapped as `^{...}` or `_{...}`.
+    pub fn text_with_scripts(paragraph: &Paragraph, doc_info: &DocInfo) -> String {
+        if paragraph.char_shapes.is_empty() {
+            return paragraph.text.clone();
+        }
+
+        let mut text = String::with_capacity(paragraph.text.len());
+        for run in TextRun::split(paragraph) {
+            let segment: Vec<char> = run.text.chars().collect();
+            let shape = doc_info.char_shapes.get(run.char_shape_id as usize);
+            match shape {
+                Some(shape) if shape.is_superscript() => {
+                    push_script(&mut text, &segment, superscript_char, '^')
+                }
+                Some(shape) if shape.is_subscript() => {
+                    push_script(&mut text, &segment, subscript_char, '_')
+                }
+                _ => text.push_str(&run.text),
+            }
+        }
+
+        text
+    }
+}
+
+/// Append a script run, converted if every character has a Unicode form
+fn push_script(text: &mut String, segment: &[char], map: fn(char) -> Option<char>, marker: char) {
+    if segment.is_empty() {
+        return;
+    }
+
+    match segment.iter().map(|&c| map(c)).collect::<Option<String>>() {
+        Some(converted) => text.push_str(&converted),
+        None => {
+            text.push(marker);
+            text.push('{');
+            text.extend(segment);
+            text.push('}');
+        }
+    }
+}
+
+fn superscript_char(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'i' => 'ⁱ',
+        'n' => 'ⁿ',
+        _ => return None,
+    })
+}
+
+fn subscript_char(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'o' => 'ₒ',
+        'x' => 'ₓ',
+        _ => return None,
+    })
+}
+
 /// Formatted text with paragraph structure preserved
+///
+/// Paragraph text has HWP's inline control codes already decoded:
+///
+/// | Control                                  | Text    |
+/// |------------------------------------------|---------|
+/// | Tab                                      | `\t`    |
+/// | Line break                               | `\n`    |
+/// | Hyphen                                   | `-`     |
+/// | Non-breaking space                       | U+00A0  |
+/// | Fixed-width space                        | space   |
+/// | Picture, drawing object, equation        | U+FFFC  |
+/// | Table, note, field, header/footer, other | nothing |
+///
+/// Hard page breaks are a paragraph property rather than a control code;
+/// see [`Paragraph::starts_new_page`](hwp_core::Paragraph::starts_new_page)
+/// and [`FormatOptions::text_page_breaks`](crate::FormatOptions::text_page_breaks).
 #[derive(Debug, Clone)]
 pub struct FormattedText {
     pub paragraphs: Vec<FormattedParagraph>,
@@ -118,6 +244,67 @@ pub struct FormattedParagraph {
     pub text: String,
     pub level: u8, // Heading level, 0 for normal text
     pub is_list_item: bool,
+    /// The paragraph text split at character shape changes; the run texts
+    /// concatenate to `text`
+    pub runs: Vec<TextRun>,
+}
+
+/// A span of paragraph text drawn with one character shape
+///
+/// `char_shape_id` indexes `doc_info.char_shapes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRun {
+    pub text: String,
+    pub char_shape_id: u16,
+}
+
+impl TextRun {
+    /// Split a paragraph's text at its PARA_CHAR_SHAPE positions
+    ///
+    /// Positions are character indices into the text. Text before the
+    /// first position, which well-formed files do not have, takes the first
+    /// run's shape; a paragraph without char shape records is one run with
+    /// shape 0. Empty runs are left out and adjacent runs with the same
+    /// shape are joined. The formatters all style text through this.
+    pub fn split(paragraph: &Paragraph) -> Vec<TextRun> {
+        let chars: Vec<char> = paragraph.text.chars().collect();
+        let mut runs: Vec<TextRun> = Vec::new();
+
+        for (i, run) in paragraph.char_shapes.iter().enumerate() {
+            let start = if i == 0 {
+                0
+            } else {
+                (run.position as usize).min(chars.len())
+            };
+            let end = paragraph
+                .char_shapes
+                .get(i + 1)
+                .map_or(chars.len(), |next| {
+                    (next.position as usize).min(chars.len())
+                });
+            if end <= start {
+                continue;
+            }
+
+            let text: String = chars[start..end].iter().collect();
+            match runs.last_mut() {
+                Some(last) if last.char_shape_id == run.shape_id => last.text.push_str(&text),
+                _ => runs.push(TextRun {
+                    text,
+                    char_shape_id: run.shape_id,
+                }),
+            }
+        }
+
+        if paragraph.char_shapes.is_empty() && !chars.is_empty() {
+            runs.push(TextRun {
+                text: paragraph.text.clone(),
+                char_shape_id: 0,
+            });
+        }
+
+        runs
+    }
 }
 
 impl TextExtractor {
@@ -134,6 +321,7 @@ impl TextExtractor {
                         text: paragraph.text.clone(),
                         level: 0,            // TODO: Determine from paragraph properties
                         is_list_item: false, // TODO: Determine from paragraph properties
+                        runs: TextRun::split(paragraph),
                     });
                 }
             }
@@ -237,10 +237,8 @@ pub mod recovery {
         // Scan byte by byte looking for a valid header
         while search_pos + 4 <= reader.len() {
             if let Ok(()) = reader.seek(search_pos) {
-                if let Ok(header_bytes) = reader.peek_bytes(4) {
-                    let mut array = [0u8; 4];
-                    array.copy_from_slice(&header_bytes);
-                    let header = RecordHeader::from_bytes(array);
+                if let Ok(value) = reader.peek_u32() {
+                    let header = RecordHeader::from_bytes(value.to_le_bytes());
 
                     // Check if this could be a valid record
                     if validator.validate_tag_id(header.tag_id(), context) {
@@ -260,18 +258,31 @@ pub mod recovery {
     }
 
     /// Skip to the next record boundary
+    ///
+    /// Expects the reader to be just past `current_header`; for extended-size
+    /// records the 4-byte size that follows the header is read first.
     pub fn skip_to_next_record(
         reader: &mut ByteReader,
         current_header: &RecordHeader,
     ) -> Result<()> {
-        let skip_bytes = if current_header.has_extended_size() {
-            // Skip extended size (4 bytes) + actual data
-            4 // We'll read the actual size and skip that amount
+        let size = if current_header.has_extended_size() {
+            reader.read_u32()? as usize
         } else {
             current_header.size() as usize
         };
 
-        reader.skip(skip_bytes)
+        if size > reader.remaining() {
+            return Err(HwpError::ValidationError {
+                message: format!(
+                    "Record data of {} bytes for tag 0x{:04X} extends beyond the stream ({} bytes remaining)",
+                    size,
+                    current_header.tag_id(),
+                    reader.remaining()
+                ),
+            });
+        }
+
+        reader.skip(size)
     }
 }
 
@@ -340,4 +351,28 @@ mod tests {
         // Invalid boundaries
         assert!(validator.validate_boundaries(&header, 10, 17).is_err()); // Not enough space
     }
+
+    #[test]
+    fn test_skip_to_next_record() {
+        use crate::reader::ByteReader;
+
+        // Extended-size record: header, 4-byte size, 6 bytes of data, then one more byte
+        let header = RecordHeader::from_bytes((0x10_u32 | (0xFFF << 20)).to_le_bytes());
+        let mut data = 6u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&[0; 7]);
+
+        let mut reader = ByteReader::new(&data);
+        recovery::skip_to_next_record(&mut reader, &header).unwrap();
+        assert_eq!(reader.position(), 10);
+
+        // A record running past the stream is a validation error
+        let header = RecordHeader::from_bytes((0x10_u32 | (8 << 20)).to_le_bytes());
+        let mut reader = ByteReader::new(&[0; 4]);
+        let err = recovery::skip_to_next_record(&mut reader, &header).unwrap_err();
+        assert!(matches!(err, HwpError::ValidationError { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Record data of 8 bytes for tag 0x0010 extends beyond the stream (4 bytes remaining)"
+        );
+    }
 }
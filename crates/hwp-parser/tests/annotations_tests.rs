@@ -0,0 +1,89 @@
+mod common;
+
+use hwp_core::models::document::{MemoShape, TrackChange, TrackChangeAuthor};
+use hwp_core::models::paragraph::RangeTag;
+use hwp_core::{HwpDocument, Paragraph, Section};
+use hwp_parser::annotations::{collect_annotations, AnnotationKind};
+
+/// Build a document with one memo, one tracked change and one range tag
+fn create_reviewed_document() -> HwpDocument {
+    let mut document = common::empty_document();
+
+    document.doc_info.memo_shapes.push(MemoShape {
+        properties: 0,
+        memo_id: 7,
+        width: 0,
+        line_count: 0,
+        line_spacing: 0,
+        line_type: 0,
+        line_color: 0,
+    });
+    document
+        .doc_info
+        .track_change_authors
+        .push(TrackChangeAuthor {
+            id: 1,
+            name: "김검토".to_string(),
+        });
+    document.doc_info.track_changes.push(TrackChange {
+        properties: 0,
+        author_id: 1,
+        timestamp: 1_700_000_000,
+        change_type: 0,
+        data: Vec::new(),
+    });
+
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "검토가 필요한 문장".to_string();
+    paragraph.range_tags.push(RangeTag {
+        start: 0,
+        end: 2,
+        tag_data: 0x0100_0005u32.to_le_bytes().to_vec(),
+    });
+    let mut section = Section::new();
+    section.paragraphs.push(paragraph);
+    document.sections.push(section);
+
+    document
+}
+
+#[test]
+fn test_collect_memo_and_track_change() {
+    let annotations = collect_annotations(&create_reviewed_document());
+
+    let memo = annotations
+        .iter()
+        .find(|a| a.kind == AnnotationKind::Memo)
+        .expect("memo should be exported");
+    assert_eq!(memo.id, Some(7));
+
+    let change = annotations
+        .iter()
+        .find(|a| a.kind == AnnotationKind::TrackChange)
+        .expect("tracked change should be exported");
+    assert_eq!(change.author.as_deref(), Some("김검토"));
+    assert_eq!(change.timestamp, Some(1_700_000_000));
+}
+
+#[test]
+fn test_collect_range_tag_anchored_text() {
+    let annotations = collect_annotations(&create_reviewed_document());
+
+    let tag = annotations
+        .iter()
+        .find(|a| a.kind == AnnotationKind::RangeTag)
+        .expect("range tag should be exported");
+    assert_eq!(tag.id, Some(5));
+    assert_eq!((tag.section, tag.paragraph), (Some(0), Some(0)));
+    assert_eq!(tag.anchored_text.as_deref(), Some("검토"));
+}
+
+#[test]
+fn test_annotations_serialize_kind() {
+    let annotations = collect_annotations(&create_reviewed_document());
+    let json = serde_json::to_value(&annotations).unwrap();
+
+    assert_eq!(json[0]["kind"], "memo");
+    assert_eq!(json[1]["kind"], "track_change");
+    assert!(json[0].get("author").is_none());
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use hwp_parser::{parse, parse_async, parse_file_async};
+
+fn sample_document() -> Vec<u8> {
+    common::build_hwp(&[], &[common::paragraph_records(&["Async"])])
+}
+
+#[tokio::test]
+async fn test_parse_async_matches_parse() {
+    let data = sample_document();
+
+    let document = parse_async(&data).await.unwrap();
+    assert_eq!(document.get_text(), parse(&data).unwrap().get_text());
+    assert!(document.get_text().contains("Async"));
+
+    assert!(parse_async(b"not an hwp file").await.is_err());
+}
+
+#[tokio::test]
+async fn test_parse_file_async() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(file.path(), sample_document()).unwrap();
+
+    let document = parse_file_async(file.path()).await.unwrap();
+    assert!(document.get_text().contains("Async"));
+
+    assert!(parse_file_async(file.path().with_extension("missing"))
+        .await
+        .is_err());
+}
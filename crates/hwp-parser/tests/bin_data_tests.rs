@@ -0,0 +1,223 @@
+mod common;
+
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use hwp_core::models::document::BinDataEntry;
+use hwp_parser::bin_data::{
+    decode_entry, decode_stream, extract_images, image_extension, image_mime_type, linked_path,
+    stream_id, LINK_TYPE_LINK,
+};
+use hwp_parser::parser::doc_info_records::parse_bin_data;
+use std::io::Write;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n-png-";
+const JPEG: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00];
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn entry(id: u16, compression_type: u8, data: Vec<u8>) -> BinDataEntry {
+    BinDataEntry {
+        id,
+        link_type: 1,
+        compression_type,
+        data,
+    }
+}
+
+#[test]
+fn test_decode_entry_compression() {
+    let compressed = entry(1, 1, deflate(PNG));
+    assert_eq!(decode_entry(&compressed, false).unwrap(), PNG);
+
+    // Default compression follows the document flag
+    let default = entry(2, 0, deflate(PNG));
+    assert_eq!(decode_entry(&default, true).unwrap(), PNG);
+    assert_eq!(decode_entry(&default, false).unwrap(), default.data);
+
+    let stored = entry(3, 2, PNG.to_vec());
+    assert_eq!(decode_entry(&stored, true).unwrap(), PNG);
+}
+
+#[test]
+fn test_decode_stream_follows_entry() {
+    let deflated = deflate(PNG);
+
+    // Without an entry the document flag decides
+    assert_eq!(decode_stream(&deflated, None, true), PNG);
+    assert_eq!(decode_stream(&deflated, None, false), deflated);
+
+    let compressed = entry(1, 1, Vec::new());
+    assert_eq!(decode_stream(&deflated, Some(&compressed), false), PNG);
+
+    // Stored items stay as they are in a compressed document
+    let stored = entry(2, 2, Vec::new());
+    assert_eq!(decode_stream(&deflated, Some(&stored), true), deflated);
+    assert_eq!(decode_stream(PNG, Some(&stored), true), PNG);
+}
+
+#[test]
+fn test_image_extension() {
+    assert_eq!(image_extension(PNG), Some("png"));
+    assert_eq!(image_extension(JPEG), Some("jpg"));
+    assert_eq!(image_extension(b"BM\x00\x00"), Some("bmp"));
+    assert_eq!(image_extension(b"GIF89a"), Some("gif"));
+    assert_eq!(image_extension(b"text"), None);
+}
+
+#[test]
+fn test_image_mime_type() {
+    assert_eq!(image_mime_type(PNG), Some("image/png"));
+    assert_eq!(image_mime_type(JPEG), Some("image/jpeg"));
+    assert_eq!(image_mime_type(b"GIF89a"), Some("image/gif"));
+    assert_eq!(image_mime_type(b"text"), None);
+}
+
+#[test]
+fn test_stream_id() {
+    assert_eq!(stream_id("BIN0001.png"), Some(1));
+    assert_eq!(stream_id("BinData/BIN000A.jpg"), Some(10));
+    assert_eq!(stream_id("BIN1.png"), None);
+    assert_eq!(stream_id("Section0"), None);
+}
+
+#[test]
+fn test_extract_images_ordered_by_id() {
+    let mut document = common::empty_document();
+    document.bin_data.insert(3, JPEG.to_vec());
+    document.bin_data.insert(2, b"not an image".to_vec());
+    document
+        .doc_info
+        .bin_data_entries
+        .push(entry(1, 1, deflate(PNG)));
+
+    let images = extract_images(&document);
+    let summary: Vec<(u16, &str)> = images.iter().map(|i| (i.id, i.extension)).collect();
+    assert_eq!(summary, vec![(1, "png"), (3, "jpg")]);
+    assert_eq!(images[0].data, PNG);
+}
+
+#[test]
+fn test_linked_path() {
+    let path = |text: &str| {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        let mut data = (units.len() as u16).to_le_bytes().to_vec();
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        data
+    };
+
+    let mut linked = entry(
+        1,
+        0,
+        [path("C:\\images\\logo.png"), path("logo.png")].concat(),
+    );
+    linked.link_type = LINK_TYPE_LINK;
+    assert_eq!(
+        linked_path(&linked).as_deref(),
+        Some("C:\\images\\logo.png")
+    );
+
+    // Falls back to the relative path
+    linked.data = [path(""), path("logo.png")].concat();
+    assert_eq!(linked_path(&linked).as_deref(), Some("logo.png"));
+
+    assert_eq!(linked_path(&entry(2, 0, path("logo.png"))), None);
+
+    // A LINK record as stored in DocInfo: properties, then the paths
+    let mut record = 0x0020u16.to_le_bytes().to_vec();
+    record.extend([path("D:\\scan.bmp"), path("scan.bmp")].concat());
+    let parsed = parse_bin_data(&record).unwrap();
+    assert_eq!(linked_path(&parsed).as_deref(), Some("D:\\scan.bmp"));
+
+    // An embedded item whose ID has a zero low byte is not a link
+    let mut record = 0x0001u16.to_le_bytes().to_vec();
+    record.extend(0x0100u16.to_le_bytes());
+    record.extend(path("bmp"));
+    let parsed = parse_bin_data(&record).unwrap();
+    assert_eq!(linked_path(&parsed), None);
+}
+
+#[test]
+fn test_bin_data_streams_matched_to_entries() {
+    use hwp_core::constants::tag_id::doc_info;
+
+    let extension = |text: &str| {
+        let mut data = (text.len() as u16).to_le_bytes().to_vec();
+        data.extend(text.encode_utf16().flat_map(|u| u.to_le_bytes()));
+        data
+    };
+    let embedded = |properties: u16, id: u16| {
+        let mut data = properties.to_le_bytes().to_vec();
+        data.extend(id.to_le_bytes());
+        data.extend(extension("png"));
+        common::record(doc_info::BIN_DATA, 0, &data)
+    };
+
+    // A link, then a deflated item and a stored one
+    let mut doc_info = common::record(doc_info::BIN_DATA, 0, &[0; 6]);
+    doc_info.extend(embedded(0x0011, 1));
+    doc_info.extend(embedded(0x0021, 2));
+
+    let deflated = deflate(PNG);
+    let section = common::record(
+        hwp_core::constants::tag_id::section::PARA_HEADER,
+        0,
+        &[0; 22],
+    );
+    let file = common::build_cfb(&[
+        ("FileHeader", common::file_header()),
+        ("DocInfo", common::compress_stream(&doc_info)),
+        ("BodyText/Section0", common::compress_stream(&section)),
+        ("BinData/BIN0001.png", deflated.clone()),
+        ("BinData/BIN0002.png", deflated.clone()),
+    ]);
+    let document = hwp_parser::parse(&file).unwrap();
+
+    let entries: Vec<(u16, u8)> = document
+        .doc_info
+        .bin_data_entries
+        .iter()
+        .map(|entry| (entry.id, entry.link_type))
+        .collect();
+    assert_eq!(entries, [(1, LINK_TYPE_LINK), (1, 1), (2, 1)]);
+
+    assert_eq!(document.bin_data[&1], PNG);
+    // Stored items stay as they are even in a compressed document
+    assert_eq!(document.bin_data[&2], deflated);
+}
+
+#[test]
+fn test_unreadable_bin_data_stream_skipped() {
+    let section = common::record(
+        hwp_core::constants::tag_id::section::PARA_HEADER,
+        0,
+        &[0; 22],
+    );
+    let mut file = common::build_cfb(&[
+        ("FileHeader", common::file_header()),
+        ("DocInfo", common::compress_stream(&[])),
+        ("BodyText/Section0", common::compress_stream(&section)),
+        ("BinData/BIN0001.png", deflate(PNG)),
+        ("BinData/BIN0002.png", vec![0; 100]),
+    ]);
+
+    // Directory entries start at file offset 1024; make BIN0002 claim more
+    // than its two mini sectors hold
+    let size = 1024 + 5 * 128 + 120;
+    file[size..size + 4].copy_from_slice(&200u32.to_le_bytes());
+
+    let document = hwp_parser::parse(&file).unwrap();
+    assert_eq!(document.bin_data[&1], PNG);
+    assert!(!document.bin_data.contains_key(&2));
+
+    // A header-only parse leaves binary data unread, like the body
+    let options = hwp_parser::ParseOptions {
+        skip_body: true,
+        ..hwp_parser::ParseOptions::default()
+    };
+    let document = hwp_parser::parse_with_options(&file, &options).unwrap();
+    assert!(document.bin_data.is_empty());
+}
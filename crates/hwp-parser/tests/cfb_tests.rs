@@ -1,11 +1,14 @@
-use hwp_parser::cfb::{parse_cfb_bytes, CfbStream};
+mod common;
+
+use hwp_parser::cfb::{parse_cfb_bytes, CfbContainer, CfbContainerOptions, CfbStream};
+use std::io::Cursor;
 
 /// Create a minimal valid CFB file for testing
 fn create_test_cfb() -> Vec<u8> {
     // This creates a minimal CFB structure with proper headers and FAT
     // In a real implementation, you'd use a proper CFB writer
 
-    let mut data = vec![0u8; 3072]; // Enough for header + FAT + directory + one data sector
+    let mut data = vec![0u8; 5632]; // Header + FAT + directory + eight data sectors
 
     // CFB Header (512 bytes)
     // Signature
@@ -103,14 +106,15 @@ fn create_test_cfb() -> Vec<u8> {
     data[518] = 0xFF;
     data[519] = 0xFF;
 
-    // FAT[2] = ENDOFCHAIN - for FileHeader stream
-    data[520] = 0xFE;
-    data[521] = 0xFF;
-    data[522] = 0xFF;
-    data[523] = 0xFF;
+    // FAT[2..=9] - the FileHeader stream's 4096 bytes, ending at sector 9
+    for i in 2..10u32 {
+        let next = if i == 9 { 0xFFFFFFFE } else { i + 1 };
+        let offset = 512 + (i as usize * 4);
+        data[offset..offset + 4].copy_from_slice(&next.to_le_bytes());
+    }
 
     // Rest of FAT is FREESECT
-    for i in 3..128 {
+    for i in 10..128 {
         let offset = 512 + (i * 4);
         data[offset] = 0xFF;
         data[offset + 1] = 0xFF;
@@ -378,3 +382,185 @@ fn test_directory_tree_traversal() {
     let root = tree.root().unwrap();
     assert_eq!(root.name, "Root Entry");
 }
+
+#[test]
+fn test_unallocated_entry_between_streams() {
+    let mut cfb_data = create_test_cfb();
+
+    // DID 2 is left unallocated; a second stream follows it at DID 3
+    create_directory_entry(
+        &mut cfb_data[1408..],
+        "DocInfo",
+        2,
+        0xFFFFFFFF,
+        0xFFFFFFFF,
+        0xFFFFFFFF,
+        2,
+        4096,
+    );
+    // Root's child is DocInfo, with FileHeader as its left sibling
+    cfb_data[1024 + 76..1024 + 80].copy_from_slice(&3u32.to_le_bytes());
+    cfb_data[1408 + 68..1408 + 72].copy_from_slice(&1u32.to_le_bytes());
+
+    let container = parse_cfb_bytes(&cfb_data).unwrap();
+
+    let streams = container.list_streams();
+    assert_eq!(
+        streams,
+        vec!["FileHeader".to_string(), "DocInfo".to_string()]
+    );
+
+    let directory = &container.directory;
+    assert_eq!(directory.get(1).unwrap().name, "FileHeader");
+    assert!(!directory.get(2).unwrap().is_valid());
+    assert_eq!(directory.get(3).unwrap().name, "DocInfo");
+    assert!(directory.find("").is_none());
+
+    let root = container.root_entry().unwrap();
+    let children: Vec<&str> = directory
+        .get_children(root)
+        .iter()
+        .map(|e| e.name.as_str())
+        .collect();
+    assert_eq!(children, vec!["FileHeader", "DocInfo"]);
+}
+
+#[test]
+fn test_stream_cache_limit() {
+    let data = common::build_cfb(&[
+        ("A", vec![1; 1000]),
+        ("B", vec![2; 1000]),
+        ("C", vec![3; 1000]),
+        ("D", vec![4; 5000]),
+    ]);
+    let mut cursor = Cursor::new(&data);
+    let options = CfbContainerOptions {
+        cache_limit_bytes: Some(2500),
+    };
+    let mut container = CfbContainer::from_reader_with_options(&mut cursor, options).unwrap();
+    assert_eq!(container.cached_bytes(), 0);
+
+    container.read_stream(&mut cursor, "A").unwrap();
+    container.read_stream(&mut cursor, "B").unwrap();
+    assert_eq!(container.cached_bytes(), 2000);
+
+    // Reading A again makes B the least recently read, so B makes room for C
+    container.read_stream(&mut cursor, "A").unwrap();
+    container.read_stream(&mut cursor, "C").unwrap();
+    assert!(container.get_stream("A").is_some());
+    assert!(container.get_stream("B").is_none());
+    assert_eq!(container.cached_bytes(), 2000);
+
+    // A stream over the limit is still kept on its own
+    let d = container.read_stream(&mut cursor, "D").unwrap();
+    assert_eq!(d.as_bytes(), &[4; 5000][..]);
+    assert_eq!(container.cached_bytes(), 5000);
+
+    // Evicted streams are read again on demand
+    let b = container.read_stream(&mut cursor, "B").unwrap();
+    assert_eq!(b.as_bytes(), &[2; 1000][..]);
+    assert_eq!(container.cached_bytes(), 1000);
+}
+
+#[test]
+fn test_declared_size_beyond_chain() {
+    let mut data = common::build_cfb(&[("A", vec![1; 100]), ("B", vec![2; 5000])]);
+
+    // Directory entries start at file offset 1024; claim more than the
+    // chains of A (two mini sectors) and B (ten sectors) hold
+    let size_offset = |entry: usize| 1024 + entry * 128 + 120;
+    data[size_offset(1)..size_offset(1) + 4].copy_from_slice(&200u32.to_le_bytes());
+    data[size_offset(2)..size_offset(2) + 4].copy_from_slice(&6000u32.to_le_bytes());
+
+    let mut cursor = Cursor::new(&data);
+    let mut container = CfbContainer::from_reader(&mut cursor).unwrap();
+    for name in ["A", "B"] {
+        let error = container.read_stream(&mut cursor, name).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("Stream '{}' declares", name)),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_stream_at_mini_stream_cutoff() {
+    // Only streams strictly smaller than the 4096-byte cutoff live in the
+    // mini stream; one of exactly 4096 bytes is stored in regular sectors
+    let below: Vec<u8> = (0..4095u32).map(|i| (i % 251) as u8).collect();
+    let at: Vec<u8> = (0..4096u32).map(|i| (i % 241) as u8).collect();
+    let data = common::build_cfb(&[("Below", below.clone()), ("At", at.clone())]);
+
+    let mut cursor = Cursor::new(&data);
+    let mut container = CfbContainer::from_reader(&mut cursor).unwrap();
+    for (name, expected) in [("Below", &below), ("At", &at)] {
+        container.verify_chain(name).unwrap();
+        let stream = container.read_stream(&mut cursor, name).unwrap();
+        assert_eq!(stream.as_bytes(), &expected[..], "{}", name);
+    }
+}
+
+#[test]
+fn test_case_insensitive_stream_fallback() {
+    let data = common::build_cfb(&[
+        ("FileHeader", vec![1; 100]),
+        ("docinfo", vec![2; 100]),
+        ("Section", vec![3; 100]),
+        ("SECTION", vec![4; 100]),
+        ("Ambiguous", vec![5; 100]),
+        ("AMBIGUOUS", vec![6; 100]),
+    ]);
+    let mut cursor = Cursor::new(&data);
+    let mut container = CfbContainer::from_reader(&mut cursor).unwrap();
+
+    // Resolved through the fallback
+    assert!(container.has_stream("DocInfo"));
+    let doc_info = container.read_stream(&mut cursor, "DocInfo").unwrap();
+    assert_eq!(doc_info.as_bytes(), &[2; 100][..]);
+    container.verify_chain("DocInfo").unwrap();
+
+    // An exact match is preferred over case variants
+    let section = container.read_stream(&mut cursor, "SECTION").unwrap();
+    assert_eq!(section.as_bytes(), &[4; 100][..]);
+
+    // Several case variants and no exact match resolve to nothing
+    assert!(!container.has_stream("ambiguous"));
+    assert!(container.read_stream(&mut cursor, "ambiguous").is_err());
+    assert!(!container.has_stream("BodyText/Section0"));
+}
+
+#[test]
+fn test_stream_in_nested_storage() {
+    let mut data = common::build_cfb(&[
+        ("FileHeader", vec![1; 100]),
+        ("BodyText", Vec::new()),
+        ("Section0", vec![3; 100]),
+    ]);
+
+    // Directory entries start at file offset 1024; link Root -> FileHeader
+    // -> BodyText as siblings and make BodyText a storage holding Section0
+    let entry = |index: usize| 1024 + index * 128;
+    data[entry(0) + 76..entry(0) + 80].copy_from_slice(&1u32.to_le_bytes());
+    data[entry(1) + 72..entry(1) + 76].copy_from_slice(&2u32.to_le_bytes());
+    data[entry(2) + 66] = 1;
+    data[entry(2) + 76..entry(2) + 80].copy_from_slice(&3u32.to_le_bytes());
+
+    let mut cursor = Cursor::new(&data);
+    let mut container = CfbContainer::from_reader(&mut cursor).unwrap();
+    assert!(container.has_stream("BodyText/Section0"));
+    assert!(!container.has_stream("BodyText/Section1"));
+    assert!(!container.has_stream("FileHeader/Section0"));
+    let section = container
+        .read_stream_by_path(&mut cursor, "BodyText/Section0")
+        .unwrap();
+    assert_eq!(section.as_bytes(), &[3; 100][..]);
+
+    // A sibling link back to itself ends the walk rather than looping
+    data[entry(3) + 72..entry(3) + 76].copy_from_slice(&3u32.to_le_bytes());
+    let mut cursor = Cursor::new(&data);
+    let container = CfbContainer::from_reader(&mut cursor).unwrap();
+    assert!(container.has_stream("BodyText/Section0"));
+}
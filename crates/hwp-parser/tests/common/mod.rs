@@ -0,0 +1,234 @@
+//! Helpers for building HWP containers and documents in integration tests
+#![allow(dead_code)]
+
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use hwp_core::models::document::CharShape;
+use hwp_core::models::header::HwpHeader;
+use hwp_core::{HwpDocument, HwpVersion};
+use std::io::Write;
+
+const ENDOFCHAIN: u32 = 0xFFFF_FFFE;
+const FATSECT: u32 = 0xFFFF_FFFD;
+const SECTOR_SIZE: usize = 512;
+const MINI_SECTOR_SIZE: usize = 64;
+const MINI_STREAM_CUTOFF: usize = 4096;
+
+/// Build a version 3 CFB container holding `streams` as top-level streams
+///
+/// Streams under 4096 bytes go in the mini stream, larger ones in regular
+/// sectors, as a conforming writer lays them out. Everything must fit in
+/// the 128 sectors a single FAT sector can describe.
+pub fn build_cfb(streams: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    let sectors = |len: usize, size: usize| (len + size - 1) / size;
+
+    // Mini stream contents and their mini FAT chains
+    let mut mini_stream = Vec::new();
+    let mut mini_fat = Vec::new();
+    let mut mini_starts = Vec::new();
+    for (_, data) in streams {
+        if data.len() >= MINI_STREAM_CUTOFF || data.is_empty() {
+            mini_starts.push(None);
+            continue;
+        }
+        let start = mini_fat.len() as u32;
+        mini_starts.push(Some(start));
+        chain(&mut mini_fat, start, sectors(data.len(), MINI_SECTOR_SIZE));
+        mini_stream.extend_from_slice(data);
+        mini_stream.resize(mini_fat.len() * MINI_SECTOR_SIZE, 0);
+    }
+
+    // Sector layout: FAT, directory, mini FAT, mini stream, regular streams
+    let dir_sectors = sectors((streams.len() + 1) * 128, SECTOR_SIZE);
+    let mini_fat_sectors = sectors(mini_fat.len() * 4, SECTOR_SIZE);
+    let mini_stream_sectors = sectors(mini_stream.len(), SECTOR_SIZE);
+
+    let mut fat = vec![FATSECT];
+    chain(&mut fat, 1, dir_sectors);
+    let mini_fat_start = fat.len() as u32;
+    chain(&mut fat, mini_fat_start, mini_fat_sectors);
+    let mini_stream_start = fat.len() as u32;
+    chain(&mut fat, mini_stream_start, mini_stream_sectors);
+
+    let mut body = Vec::new();
+    let mut directory = Vec::new();
+    directory.extend(dir_entry(
+        "Root Entry",
+        5,
+        if mini_stream.is_empty() {
+            ENDOFCHAIN
+        } else {
+            mini_stream_start
+        },
+        mini_stream.len(),
+    ));
+    for ((name, data), mini_start) in streams.iter().zip(&mini_starts) {
+        let start = match mini_start {
+            Some(start) => *start,
+            None if data.is_empty() => ENDOFCHAIN,
+            None => {
+                let start = fat.len() as u32;
+                let count = sectors(data.len(), SECTOR_SIZE);
+                chain(&mut fat, start, count);
+                body.extend_from_slice(data);
+                body.resize(body.len() + count * SECTOR_SIZE - data.len(), 0);
+                start
+            }
+        };
+        directory.extend(dir_entry(name, 2, start, data.len()));
+    }
+    assert!(fat.len() <= SECTOR_SIZE / 4, "test container too large");
+    directory.resize(dir_sectors * SECTOR_SIZE, 0);
+
+    let mut cfb = vec![0u8; SECTOR_SIZE];
+    cfb[0..8].copy_from_slice(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]);
+    cfb[0x18..0x1A].copy_from_slice(&0x3Eu16.to_le_bytes());
+    cfb[0x1A..0x1C].copy_from_slice(&3u16.to_le_bytes());
+    cfb[0x1C..0x1E].copy_from_slice(&0xFFFEu16.to_le_bytes());
+    cfb[0x1E..0x20].copy_from_slice(&9u16.to_le_bytes());
+    cfb[0x20..0x22].copy_from_slice(&6u16.to_le_bytes());
+    cfb[0x2C..0x30].copy_from_slice(&1u32.to_le_bytes());
+    cfb[0x30..0x34].copy_from_slice(&1u32.to_le_bytes());
+    cfb[0x38..0x3C].copy_from_slice(&(MINI_STREAM_CUTOFF as u32).to_le_bytes());
+    let first_mini_fat = if mini_fat_sectors == 0 {
+        ENDOFCHAIN
+    } else {
+        mini_fat_start
+    };
+    cfb[0x3C..0x40].copy_from_slice(&first_mini_fat.to_le_bytes());
+    cfb[0x40..0x44].copy_from_slice(&(mini_fat_sectors as u32).to_le_bytes());
+    cfb[0x44..0x48].copy_from_slice(&ENDOFCHAIN.to_le_bytes());
+    cfb[0x4C..0x200].fill(0xFF);
+    cfb[0x4C..0x50].copy_from_slice(&0u32.to_le_bytes());
+
+    cfb.extend(sector_of(&fat));
+    cfb.extend(directory);
+    let mut mini_fat_bytes: Vec<u8> = mini_fat.iter().flat_map(|e| e.to_le_bytes()).collect();
+    mini_fat_bytes.resize(mini_fat_sectors * SECTOR_SIZE, 0xFF);
+    cfb.extend(mini_fat_bytes);
+    mini_stream.resize(mini_stream_sectors * SECTOR_SIZE, 0);
+    cfb.extend(mini_stream);
+    cfb.extend(body);
+    cfb
+}
+
+/// Build an HWP 5.0 container from its DocInfo and section record streams
+///
+/// The FileHeader marks the document as compressed and the record streams
+/// are compressed to match.
+pub fn build_hwp(doc_info: &[u8], sections: &[Vec<u8>]) -> Vec<u8> {
+    let names: Vec<String> = (0..sections.len())
+        .map(|i| format!("BodyText/Section{}", i))
+        .collect();
+    let mut streams = vec![
+        ("FileHeader", file_header()),
+        ("DocInfo", compress_stream(doc_info)),
+    ];
+    for (name, section) in names.iter().zip(sections) {
+        streams.push((name.as_str(), compress_stream(section)));
+    }
+    build_cfb(&streams)
+}
+
+/// A version 5.0 FileHeader stream with the compressed flag set
+pub fn file_header() -> Vec<u8> {
+    let mut header = b"HWP Document File".to_vec();
+    header.resize(32, 0);
+    header.extend_from_slice(&0x0500_0000u32.to_le_bytes());
+    header.extend_from_slice(&1u32.to_le_bytes()); // compressed
+    header.resize(256, 0);
+    header
+}
+
+/// Compress a stream the way HWP does: a size prefix then raw deflate
+pub fn compress_stream(data: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    let mut stream = (data.len() as u32).to_le_bytes().to_vec();
+    stream.extend(encoder.finish().unwrap());
+    stream
+}
+
+/// Section records for one plain paragraph per entry of `texts`: a
+/// PARA_HEADER, then the text with its closing paragraph break
+pub fn paragraph_records(texts: &[&str]) -> Vec<u8> {
+    let mut section = Vec::new();
+    for text in texts {
+        let text: Vec<u8> = text
+            .encode_utf16()
+            .chain(Some(0x000D))
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+        section.extend(record(0x0050, 0, &[0; 22]));
+        section.extend(record(0x0051, 1, &text));
+    }
+    section
+}
+
+/// Encode a record header followed by its data
+pub fn record(tag_id: u16, level: u8, data: &[u8]) -> Vec<u8> {
+    let value = (tag_id as u32) | ((level as u32) << 10) | ((data.len() as u32) << 20);
+    let mut bytes = value.to_le_bytes().to_vec();
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Append a chain of `count` consecutive sectors starting at `start`
+fn chain(table: &mut Vec<u32>, start: u32, count: usize) {
+    for i in 0..count as u32 {
+        table.push(if i + 1 == count as u32 {
+            ENDOFCHAIN
+        } else {
+            start + i + 1
+        });
+    }
+}
+
+/// A 128-byte directory entry with no siblings or children
+fn dir_entry(name: &str, object_type: u8, start: u32, size: usize) -> Vec<u8> {
+    let mut raw = vec![0u8; 128];
+    let units: Vec<u16> = name.encode_utf16().chain(Some(0)).collect();
+    for (i, unit) in units.iter().enumerate() {
+        raw[i * 2..i * 2 + 2].copy_from_slice(&unit.to_le_bytes());
+    }
+    raw[64..66].copy_from_slice(&((units.len() * 2) as u16).to_le_bytes());
+    raw[66] = object_type;
+    raw[67] = 1; // black
+    raw[68..80].copy_from_slice(&[0xFF; 12]);
+    raw[116..120].copy_from_slice(&start.to_le_bytes());
+    raw[120..124].copy_from_slice(&(size as u32).to_le_bytes());
+    raw
+}
+
+/// FAT entries padded out to a full sector of free entries
+fn sector_of(entries: &[u32]) -> Vec<u8> {
+    let mut sector: Vec<u8> = entries.iter().flat_map(|e| e.to_le_bytes()).collect();
+    sector.resize(SECTOR_SIZE, 0xFF);
+    sector
+}
+
+/// An empty version 5.0.0.0 document
+pub fn empty_document() -> HwpDocument {
+    HwpDocument::new(HwpHeader::new(HwpVersion::new(5, 0, 0, 0)))
+}
+
+/// A 10pt character shape in face 0 with the given property bits and
+/// text color (0x00BBGGRR)
+pub fn char_shape(properties: u32, text_color: u32) -> CharShape {
+    CharShape {
+        face_name_ids: vec![0; 7],
+        ratios: vec![100; 7],
+        char_spaces: vec![0; 7],
+        rel_sizes: vec![100; 7],
+        char_offsets: vec![0; 7],
+        base_size: 1000,
+        properties,
+        shadow_gap_x: 0,
+        shadow_gap_y: 0,
+        text_color,
+        underline_color: 0,
+        shade_color: 0xFFFFFF,
+        shadow_color: 0,
+        border_fill_id: None,
+    }
+}
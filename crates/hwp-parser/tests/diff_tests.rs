@@ -0,0 +1,137 @@
+mod common;
+
+use hwp_core::diff::ChangeKind;
+use hwp_parser::parse;
+
+/// A single-section document with one paragraph per entry of `paragraphs`
+fn document(paragraphs: &[&str]) -> hwp_core::HwpDocument {
+    sectioned_document(&[paragraphs])
+}
+
+#[test]
+fn test_diff_reports_paragraph_hunks() {
+    let old = document(&["Title", "First", "Second", "Closing"]);
+    let new = document(&["Title", "First, revised", "Closing", "Appendix"]);
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.hunks.len(), 3);
+
+    let modified = &diff.hunks[0];
+    assert_eq!(modified.kind, ChangeKind::Modified);
+    assert_eq!(modified.old_paragraph, Some(1));
+    assert_eq!(modified.new_paragraph, Some(1));
+    assert_eq!(modified.new_text.as_deref(), Some("First, revised"));
+
+    // The rest of the run before the unchanged "Closing" was removed
+    assert_eq!(diff.hunks[1].kind, ChangeKind::Removed);
+    assert_eq!(diff.hunks[1].old_text.as_deref(), Some("Second"));
+    assert_eq!(diff.hunks[1].new_paragraph, None);
+
+    assert_eq!(diff.hunks[2].kind, ChangeKind::Added);
+    assert_eq!(diff.hunks[2].new_paragraph, Some(3));
+    assert_eq!(diff.hunks[2].new_text.as_deref(), Some("Appendix"));
+
+    assert!(old.diff(&old).is_empty());
+}
+
+/// A document with one section per entry of `sections`
+fn sectioned_document(sections: &[&[&str]]) -> hwp_core::HwpDocument {
+    let streams: Vec<Vec<u8>> = sections
+        .iter()
+        .map(|paragraphs| common::paragraph_records(paragraphs))
+        .collect();
+    parse(&common::build_hwp(&[], &streams)).unwrap()
+}
+
+#[test]
+fn test_diff_inserted_section_does_not_cascade() {
+    let old = sectioned_document(&[&["Intro", "Scope"], &["Results", "Tables"]]);
+    let new = sectioned_document(&[
+        &["Intro", "Scope"],
+        &["Method", "Setup"],
+        &["Results", "Tables, updated"],
+    ]);
+
+    let diff = old.diff(&new);
+    let added = diff.hunks_of(ChangeKind::Added);
+    assert_eq!(added.len(), 2);
+    assert!(added
+        .iter()
+        .all(|h| h.new_section == Some(1) && h.old_section.is_none()));
+
+    // The old second section lines up with the new third one
+    let changed = diff.hunks_of(ChangeKind::Modified);
+    assert_eq!(changed.len(), 1);
+    assert_eq!(
+        (changed[0].old_section, changed[0].new_section),
+        (Some(1), Some(2))
+    );
+    assert_eq!(changed[0].new_text.as_deref(), Some("Tables, updated"));
+    assert!(diff.hunks_of(ChangeKind::Removed).is_empty());
+}
+
+/// A one-section document built in memory from paragraph texts
+fn built_document(paragraphs: impl IntoIterator<Item = String>) -> hwp_core::HwpDocument {
+    let mut section = hwp_core::Section::new();
+    for text in paragraphs {
+        let mut paragraph = hwp_core::Paragraph::new();
+        paragraph.text = text;
+        section.paragraphs.push(paragraph);
+    }
+    let mut document = common::empty_document();
+    document.sections.push(section);
+    document
+}
+
+#[test]
+fn test_diff_keeps_longest_common_subsequence() {
+    // Pseudo-random paragraphs over a small alphabet, so many differ
+    let mut seed = 12345u32;
+    let mut texts = |len: usize| -> Vec<String> {
+        (0..len)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((seed >> 16) % 4).to_string()
+            })
+            .collect()
+    };
+    let (a, b) = (texts(150), texts(120));
+
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let diff = built_document(a.clone()).diff(&built_document(b.clone()));
+    let unchanged = a.len() - diff.count(ChangeKind::Removed) - diff.count(ChangeKind::Modified);
+    assert_eq!(unchanged, lcs[0][0]);
+    assert_eq!(
+        b.len() - diff.count(ChangeKind::Added) - diff.count(ChangeKind::Modified),
+        lcs[0][0]
+    );
+}
+
+#[test]
+fn test_diff_of_long_documents() {
+    // Every tenth paragraph is shared; the nine between are all rewritten
+    let text = |prefix: &str, i: usize| {
+        if i % 10 == 0 {
+            format!("same {}", i)
+        } else {
+            format!("{} {}", prefix, i)
+        }
+    };
+    let old = built_document((0..2_000).map(|i| text("old", i)));
+    let new = built_document((0..2_000).map(|i| text("new", i)));
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.count(ChangeKind::Modified), 1_800);
+    assert_eq!(diff.count(ChangeKind::Added), 0);
+    assert_eq!(diff.count(ChangeKind::Removed), 0);
+}
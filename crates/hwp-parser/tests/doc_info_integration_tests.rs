@@ -1,4 +1,5 @@
-use hwp_parser::parser::doc_info::parse_doc_info;
+use hwp_parser::parser::doc_info::{parse_doc_info, parse_doc_info_with_options};
+use hwp_parser::parser::ParseOptions;
 
 /// Helper function to create a proper record header
 fn create_header(tag_id: u16, level: u8, size: usize) -> Vec<u8> {
@@ -266,19 +267,44 @@ fn test_unknown_record_handling() {
     data.extend_from_slice(&[0x00; 8]); // two u32 fields (8 bytes)
     data.extend_from_slice(&[0x00; 14]); // padding (14 bytes)
 
-    // Test without unknown record since strict validation is enabled
     let doc_info = parse_doc_info(&data).unwrap();
 
     // Should parse the valid record
     assert_eq!(doc_info.properties.section_count, 1);
 
-    // Test with unknown record should fail with strict validation
+    // A well-formed record with an unknown tag is skipped with a warning
     data.extend(create_header(0x3FF, 0, 4)); // header: unknown tag, size=4
     data.extend_from_slice(&[0x01, 0x02, 0x03, 0x04]); // unknown data
 
+    // A valid record after it is still parsed
+    data.extend(create_header(0x0013, 0, 13)); // FACE_NAME
+    data.push(0x00); // properties
+    data.extend_from_slice(&[0x05, 0x00]); // length: 5
+    data.extend(
+        "Arial"
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes())
+            .collect::<Vec<_>>(),
+    );
+
+    let doc_info = parse_doc_info(&data).unwrap();
+    assert_eq!(doc_info.properties.section_count, 1);
+    assert_eq!(doc_info.face_names.len(), 1);
+    assert_eq!(doc_info.face_names[0].name, "Arial");
+    assert_eq!(doc_info.warnings.len(), 1);
+    assert_eq!(doc_info.warnings[0].tag_id, Some(0x3FF));
+
     // With strict validation, unknown records cause an error
-    let result = parse_doc_info(&data);
-    assert!(result.is_err());
+    let strict = ParseOptions {
+        strict: true,
+        ..ParseOptions::default()
+    };
+    assert!(parse_doc_info_with_options(&data, &strict).is_err());
+
+    // A malformed record still fails in non-strict mode
+    data.extend(create_header(0x3FF, 0, 16));
+    data.extend_from_slice(&[0x00; 4]);
+    assert!(parse_doc_info(&data).is_err());
 }
 
 #[test]
@@ -331,3 +357,71 @@ fn test_extended_size_record() {
     let doc_info = parse_doc_info(&data).unwrap();
     assert_eq!(doc_info.properties.section_count, 1);
 }
+
+#[test]
+fn test_duplicate_document_properties() {
+    let mut data = Vec::new();
+
+    // Two DOCUMENT_PROPERTIES records with different section counts
+    for section_count in [2u16, 9u16] {
+        data.extend(create_header(0x0010, 0, 22));
+        data.extend_from_slice(&section_count.to_le_bytes());
+        data.extend_from_slice(&[0x00; 20]);
+    }
+
+    let doc_info = parse_doc_info(&data).unwrap();
+
+    // The first record wins and the extra one is reported
+    assert_eq!(doc_info.properties.section_count, 2);
+    assert_eq!(doc_info.warnings.len(), 1);
+    assert_eq!(doc_info.warnings[0].tag_id, Some(0x0010));
+}
+
+#[test]
+fn test_face_name_resolution_by_language() {
+    use hwp_core::FontLanguage;
+
+    let mut data = Vec::new();
+
+    // Face names for two languages: one Korean font, then two English fonts
+    for name in ["Batang", "Arial", "Courier"] {
+        let utf16: Vec<u8> = name.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+        data.extend(create_header(0x0013, 0, 1 + 2 + utf16.len()));
+        data.push(0x00);
+        data.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        data.extend_from_slice(&utf16);
+    }
+
+    // Char shape uses the first Korean font and the second English font
+    let mut char_shape = create_sample_char_shape_data();
+    char_shape[0..2].copy_from_slice(&0u16.to_le_bytes());
+    char_shape[2..4].copy_from_slice(&1u16.to_le_bytes());
+    data.extend(create_header(0x0015, 0, char_shape.len()));
+    data.extend(char_shape);
+
+    // ID_MAPPINGS arrives after the records it describes
+    let counts = [0u32, 1, 2, 0, 0, 0, 0, 0];
+    data.extend(create_header(0x0011, 0, 4 + counts.len() * 4));
+    data.extend_from_slice(&(counts.len() as u32).to_le_bytes());
+    for count in counts {
+        data.extend_from_slice(&count.to_le_bytes());
+    }
+
+    let doc_info = parse_doc_info(&data).unwrap();
+    let shape = &doc_info.char_shapes[0];
+
+    let korean = doc_info
+        .char_shape_font(shape, FontLanguage::Korean)
+        .unwrap();
+    assert_eq!(korean.name, "Batang");
+
+    // Positional lookup would pick "Arial" here
+    let english = doc_info
+        .char_shape_font(shape, FontLanguage::English)
+        .unwrap();
+    assert_eq!(english.name, "Courier");
+
+    // Ids outside a language's group do not resolve
+    assert!(doc_info.face_name(FontLanguage::Korean, 1).is_none());
+    assert!(doc_info.face_name(FontLanguage::Hanja, 0).is_none());
+}
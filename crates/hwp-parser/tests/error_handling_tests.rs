@@ -1,5 +1,7 @@
+mod common;
+
 use hwp_core::HwpError;
-use hwp_parser::parse;
+use hwp_parser::{parse, parse_with_options, ParseOptions};
 use std::fs;
 use std::path::Path;
 
@@ -229,29 +231,120 @@ fn test_password_protected_handling() {
     password_data.extend(vec![0u8; 216]);
 
     let result = parse(&password_data);
+    assert!(
+        matches!(result, Err(HwpError::PasswordProtected)),
+        "Expected PasswordProtected, got: {:?}",
+        result.map(|_| ())
+    );
+    println!("  ✓ Failed on password-protected file");
+}
 
-    // Parser should detect password protection
-    match result {
-        Ok(doc) => {
-            println!("  Version: {}", doc.header.version);
-            println!("  Has password: {}", doc.header.has_password());
-            assert!(doc.header.has_password(), "Should detect password flag");
-            println!("  ✓ Password protection detected");
-        }
-        Err(e) => {
-            // Or it might fail immediately
-            println!("  ✓ Failed on password-protected file: {:?}", e);
-            assert!(
-                format!("{:?}", e).contains("Password")
-                    || format!("{:?}", e).contains("Protected")
-                    || format!("{:?}", e).contains("Unsupported"),
-                "Expected password/protected error, got: {:?}",
-                e
-            );
-        }
+/// Password-protected CFB documents fail before their encrypted streams
+/// are read
+#[test]
+fn test_password_protected_cfb() {
+    let mut file_header = common::file_header();
+    file_header[36..40].copy_from_slice(&0x0003u32.to_le_bytes()); // compressed, password
+    let data = common::build_cfb(&[
+        ("FileHeader", file_header),
+        ("DocInfo", vec![0xA5; 64]),
+        ("BodyText/Section0", vec![0x5A; 64]),
+    ]);
+
+    match parse(&data) {
+        Err(HwpError::PasswordProtected) => {}
+        other => panic!("Expected PasswordProtected, got {:?}", other.map(|_| ())),
+    }
+    assert_eq!(
+        HwpError::PasswordProtected.to_string(),
+        "Document is password protected"
+    );
+}
+
+/// DRM-protected CFB documents fail before their encrypted streams are
+/// read
+#[test]
+fn test_drm_protected_cfb() {
+    let mut file_header = common::file_header();
+    file_header[36..40].copy_from_slice(&0x0011u32.to_le_bytes()); // compressed, DRM
+    let data = common::build_cfb(&[
+        ("FileHeader", file_header),
+        ("DocInfo", vec![0xA5; 64]),
+        ("BodyText/Section0", vec![0x5A; 64]),
+    ]);
+
+    match parse(&data) {
+        Err(HwpError::DrmProtected) => {}
+        other => panic!("Expected DrmProtected, got {:?}", other.map(|_| ())),
     }
 }
 
+/// A header-only parse of a protected document succeeds without reading
+/// its encrypted streams, so the flags can still be reported
+#[test]
+fn test_protected_cfb_header_only() {
+    let options = ParseOptions {
+        skip_body: true,
+        ..ParseOptions::default()
+    };
+    for flags in [0x0003u32, 0x0011] {
+        let mut file_header = common::file_header();
+        file_header[36..40].copy_from_slice(&flags.to_le_bytes());
+        let data = common::build_cfb(&[
+            ("FileHeader", file_header),
+            ("DocInfo", vec![0xA5; 64]),
+            ("BodyText/Section0", vec![0x5A; 64]),
+        ]);
+
+        let document = parse_with_options(&data, &options).unwrap();
+        assert_eq!(document.header.has_password(), flags & 0x02 != 0);
+        assert_eq!(document.header.is_drm_document(), flags & 0x10 != 0);
+        assert!(document.doc_info.face_names.is_empty());
+        assert_eq!(document.sections.len(), 1);
+    }
+}
+
+/// An unreadable summary stream leaves the summary empty rather than
+/// failing the parse
+#[test]
+fn test_unreadable_summary_stream() {
+    let mut data = common::build_cfb(&[
+        ("FileHeader", common::file_header()),
+        ("DocInfo", common::compress_stream(&[])),
+        ("BodyText/Section0", common::compress_stream(&[])),
+        ("\u{5}HwpSummaryInformation", vec![0; 100]),
+    ]);
+
+    // Directory entries start at file offset 1024; make the summary claim
+    // more than its two mini sectors hold
+    let size = 1024 + 4 * 128 + 120;
+    data[size..size + 4].copy_from_slice(&200u32.to_le_bytes());
+
+    let document = parse(&data).unwrap();
+    assert!(document.summary.is_none());
+    assert_eq!(document.sections.len(), 1);
+}
+
+/// Error codes are stable identifiers, independent of the message text
+#[test]
+fn test_error_codes() {
+    let error = parse(&[0x20; 512]).unwrap_err();
+    assert_eq!(error.code(), "INVALID_SIGNATURE");
+
+    assert_eq!(HwpError::PasswordProtected.code(), "PASSWORD_PROTECTED");
+    assert_eq!(
+        HwpError::DecompressionError("bad block".to_string()).code(),
+        "DECOMPRESSION_FAILED"
+    );
+    assert_eq!(
+        HwpError::UnsupportedVersion {
+            version: "3.0.0.0".to_string()
+        }
+        .code(),
+        "UNSUPPORTED_VERSION"
+    );
+}
+
 /// Test recovery from partial corruption
 #[test]
 fn test_partial_corruption_recovery() {
@@ -272,3 +365,171 @@ fn test_partial_corruption_recovery() {
     let _ = parse(&partially_corrupt); // Should not panic
     println!("  ✓ No panic on partial corruption");
 }
+
+#[test]
+fn test_record_limit_per_stream() {
+    use hwp_core::constants::tag_id::section::PARA_TEXT;
+    use hwp_parser::parser::section::parse_section_with_options;
+    use hwp_parser::ParseOptions;
+
+    // Five small PARA_TEXT records
+    let mut data = Vec::new();
+    for _ in 0..5 {
+        let header = (PARA_TEXT as u32) | (2u32 << 20);
+        data.extend_from_slice(&header.to_le_bytes());
+        data.extend_from_slice(&[0x41, 0x00]);
+    }
+
+    let options = ParseOptions {
+        max_records_per_stream: 3,
+        ..ParseOptions::default()
+    };
+    match parse_section_with_options(&data, 0, &options) {
+        Err(HwpError::ValidationError { message }) => {
+            assert!(
+                message.contains("3 records"),
+                "unexpected message: {}",
+                message
+            );
+        }
+        other => panic!("Expected ValidationError, got {:?}", other.map(|_| ())),
+    }
+
+    let options = ParseOptions {
+        max_records_per_stream: 5,
+        ..ParseOptions::default()
+    };
+    assert!(parse_section_with_options(&data, 0, &options).is_ok());
+}
+
+/// Test that invalid record errors name the tag
+#[test]
+fn test_invalid_record_message_includes_tag_name() {
+    let err = HwpError::InvalidRecord {
+        tag: 0x0013,
+        level: 0,
+        size: 0,
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid record: FACE_NAME(0x0013) level=0 size=0"
+    );
+
+    let err = HwpError::InvalidRecord {
+        tag: 0x03FF,
+        level: 1,
+        size: 8,
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invalid record: UNKNOWN(0x03FF) level=1 size=8"
+    );
+}
+
+#[test]
+fn test_table_nesting_limit() {
+    use hwp_core::constants::tag_id::section::{CTRL_HEADER, LIST_HEADER, TABLE};
+    use hwp_parser::parser::section::parse_section_with_options;
+    use hwp_parser::ParseOptions;
+
+    // Each table's only cell holds the next table, two levels further in
+    let nested = |depth: u8| {
+        let mut data = Vec::new();
+        for i in 0..depth {
+            let level = i * 2;
+            let mut table = 0u32.to_le_bytes().to_vec();
+            table.extend_from_slice(&1u16.to_le_bytes());
+            table.extend_from_slice(&1u16.to_le_bytes());
+            table.extend_from_slice(&[0; 10]);
+            let mut cell = 0u32.to_le_bytes().to_vec();
+            cell.extend_from_slice(&[0; 30]);
+
+            data.extend(common::record(CTRL_HEADER, level + 1, b" lbt"));
+            data.extend(common::record(TABLE, level + 2, &table));
+            data.extend(common::record(LIST_HEADER, level + 2, &cell));
+        }
+        data
+    };
+    let options = ParseOptions {
+        max_nesting: 4,
+        ..ParseOptions::default()
+    };
+
+    let section = parse_section_with_options(&nested(4), 0, &options).unwrap();
+    assert_eq!(section.tables.len(), 1);
+
+    match parse_section_with_options(&nested(5), 0, &options) {
+        Err(HwpError::ValidationError { message }) => {
+            assert!(
+                message.contains("4 levels"),
+                "unexpected message: {}",
+                message
+            );
+        }
+        other => panic!("Expected ValidationError, got {:?}", other.map(|_| ())),
+    }
+
+    // The default limit stops a crafted file long before the stack would
+    let deep = nested(120);
+    assert!(parse_section_with_options(&deep, 0, &ParseOptions::default()).is_err());
+}
+
+/// A stream that does not decompress as detected is retried with every
+/// method before the parse fails
+#[test]
+fn test_misdetected_stream_decompressed() {
+    use hwp_parser::compression::{detect_compression, CompressionKind};
+
+    let text: Vec<u8> = format!("{}\r", "x".repeat(111))
+        .encode_utf16()
+        .flat_map(|u| u.to_le_bytes())
+        .collect();
+    let mut section = common::record(0x0050, 0, &[0; 23]);
+    section.extend(common::record(0x0051, 1, &text));
+    assert_eq!(section.len(), 255);
+
+    // Raw deflate with one final stored block of 255 bytes, whose block
+    // header also reads as a plausible size prefix
+    let mut stream = vec![0x01, 0xFF, 0x00, 0x00, 0xFF];
+    stream.extend(section);
+    assert_eq!(
+        detect_compression(&stream, true),
+        CompressionKind::SizedDeflate
+    );
+
+    let data = common::build_cfb(&[
+        ("FileHeader", common::file_header()),
+        ("DocInfo", common::compress_stream(&[])),
+        ("BodyText/Section0", stream),
+    ]);
+    let document = parse(&data).unwrap();
+    assert_eq!(document.sections[0].paragraphs[0].text, "x".repeat(111));
+}
+
+/// A stream that does not decompress by any method fails the parse unless
+/// lenient parsing falls back to its raw bytes
+#[test]
+fn test_undecodable_stream_needs_lenient() {
+    use hwp_parser::{parse_with_options, ParseOptions};
+
+    let section = common::paragraph_records(&["Stored"]);
+
+    // Marked compressed, but the section is stored as is
+    let data = common::build_cfb(&[
+        ("FileHeader", common::file_header()),
+        ("DocInfo", common::compress_stream(&[])),
+        ("BodyText/Section0", section),
+    ]);
+
+    match parse(&data) {
+        Err(e) => assert_eq!(e.code(), "DECOMPRESSION_FAILED"),
+        Ok(_) => panic!("Expected a decompression error"),
+    }
+
+    let options = ParseOptions {
+        lenient: true,
+        ..ParseOptions::default()
+    };
+    let document = parse_with_options(&data, &options).unwrap();
+    assert_eq!(document.sections[0].paragraphs[0].text, "Stored");
+}
@@ -1,3 +1,7 @@
+mod common;
+
+use hwp_core::models::document::BinDataEntry;
+use hwp_core::{HwpDocument, Paragraph, ParagraphRange, Section};
 use hwp_parser::parse;
 use std::fs;
 use std::path::Path;
@@ -186,3 +190,274 @@ fn test_empty_document_extraction() {
         }
     }
 }
+
+/// Build a two-section document with paragraphs "P0".."P5"
+fn create_ranged_document() -> HwpDocument {
+    let mut document = common::empty_document();
+    for texts in [["P0", "P1", "P2"], ["P3", "P4", "P5"]] {
+        let mut section = Section::new();
+        for text in texts {
+            let mut paragraph = Paragraph::new();
+            paragraph.text = text.to_string();
+            section.paragraphs.push(paragraph);
+        }
+        document.sections.push(section);
+    }
+    document
+}
+
+/// Test ranged extraction across section boundaries
+#[test]
+fn test_extract_paragraph_range() {
+    let doc = create_ranged_document();
+
+    let range: ParagraphRange = "2-4".parse().unwrap();
+    assert_eq!(doc.extract_range(range), "P2\nP3\nP4\n");
+
+    let open_end: ParagraphRange = "4-".parse().unwrap();
+    assert_eq!(doc.extract_range(open_end), "P4\nP5\n");
+
+    let open_start: ParagraphRange = "-1".parse().unwrap();
+    assert_eq!(doc.extract_range(open_start), "P0\nP1\n");
+
+    let single: ParagraphRange = "3".parse().unwrap();
+    assert_eq!(doc.extract_range(single), "P3\n");
+}
+
+/// Test that malformed ranges are rejected
+#[test]
+fn test_paragraph_range_parse_errors() {
+    assert!("".parse::<ParagraphRange>().is_err());
+    assert!("a-b".parse::<ParagraphRange>().is_err());
+    assert!("1-2-3".parse::<ParagraphRange>().is_err());
+}
+
+/// Test empty-document detection
+#[test]
+fn test_document_is_empty() {
+    let doc = create_ranged_document();
+    assert!(!doc.is_empty());
+    assert!(doc.has_content());
+
+    // Whitespace-only paragraphs do not count as content
+    let mut blank = create_ranged_document();
+    for paragraph in blank.sections.iter_mut().flat_map(|s| &mut s.paragraphs) {
+        paragraph.text = " \t\n".to_string();
+    }
+    assert!(blank.is_empty());
+    assert!(!blank.has_content());
+
+    // A linked image, or binary data that is not an image, is not content
+    blank.doc_info.bin_data_entries.push(BinDataEntry {
+        id: 1,
+        link_type: hwp_parser::bin_data::LINK_TYPE_LINK,
+        compression_type: 0,
+        data: Vec::new(),
+    });
+    blank.bin_data.insert(2, b"OLE data".to_vec());
+    assert!(blank.is_empty());
+
+    // An embedded image alone is content
+    blank.bin_data.insert(3, b"GIF89a".to_vec());
+    assert!(blank.has_content());
+    blank.bin_data.remove(&3);
+
+    // A table alone is content
+    blank.sections[0]
+        .tables
+        .push(hwp_core::models::section::Table::default());
+    assert!(blank.has_content());
+
+    let file_path = Path::new("tests/fixtures/basic/empty.hwp");
+    if let Ok(doc) = fs::read(file_path)
+        .map_err(|_| ())
+        .and_then(|d| parse(&d).map_err(|_| ()))
+    {
+        assert!(doc.is_empty());
+    }
+}
+
+/// Test that the computed character count matches HWP's stored count
+#[test]
+fn test_char_count_matches_stored_count() {
+    use hwp_core::models::section::{Table, TableCell};
+
+    let mut doc = create_ranged_document();
+    // Controls are not counted; decomposed 한 (ᄒ ᅡ ᆫ) counts as one syllable
+    doc.sections[0].paragraphs[0].text = "한글\t문서\n".to_string();
+    doc.sections[0].paragraphs[1].text = "\u{1112}\u{1161}\u{11AB} A".to_string();
+
+    let mut cell = TableCell::new(0, 0);
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "표".to_string();
+    cell.paragraphs.push(paragraph);
+    doc.sections[1].tables.push(Table {
+        cells: vec![cell],
+        ..Table::default()
+    });
+
+    // 4 + 3 + 2 + 6 (P3-P5) + 1 (cell)
+    assert_eq!(doc.char_count(), 16);
+
+    // A parsed document agrees with the count HWP stored for it: 4 for
+    // 한글, 문서 around a tab control, then 한 from its jamo, a space and A
+    let mut properties = vec![0u8; 26];
+    properties[0..2].copy_from_slice(&1u16.to_le_bytes());
+    properties[14..18].copy_from_slice(&7u32.to_le_bytes());
+    let doc_info = common::record(0x0010, 0, &properties);
+    // The tab control takes 8 code units
+    let section =
+        common::paragraph_records(&["한글\t\t\t\t\t\t\t\t문서\n", "\u{1112}\u{1161}\u{11AB} A"]);
+
+    let doc = parse(&common::build_hwp(&doc_info, &[section])).unwrap();
+    assert_eq!(doc.doc_info.properties.total_character_count, 7);
+    assert_eq!(doc.char_count(), 7);
+}
+
+/// Test word counts and per-script character counts
+#[test]
+fn test_word_and_script_counts() {
+    use hwp_core::models::section::{Table, TableCell};
+
+    let mut doc = create_ranged_document();
+    doc.sections[0].paragraphs[0].text = "한글 문서는 don't count 3.14 twice.".to_string();
+    doc.sections[0].paragraphs[1].text = "漢字かな Café".to_string();
+    doc.sections[0].paragraphs[2].text = "  -- ".to_string();
+
+    let mut cell = TableCell::new(0, 0);
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "표 cell".to_string();
+    cell.paragraphs.push(paragraph);
+    doc.sections[1].tables.push(Table {
+        cells: vec![cell],
+        ..Table::default()
+    });
+
+    // 6 + (4 ideographs and kana + Café) + 0 + P3-P5 + 2 (cell)
+    assert_eq!(doc.word_count(), 6 + 5 + 3 + 2);
+    assert_eq!(doc.char_count_hangul(), 5 + 1);
+    assert_eq!(doc.char_count_latin(), 14 + 4 + 3 + 4);
+    assert_eq!(doc.char_count_cjk(), 4);
+}
+
+/// Test page counts from stored line segments and from estimated layout
+#[test]
+fn test_section_page_count() {
+    use hwp_core::models::document::HwpUnit;
+    use hwp_core::models::paragraph::LineSegment;
+    use hwp_core::models::section::PageDef;
+
+    let paragraph = |text: &str| {
+        let mut paragraph = Paragraph::new();
+        paragraph.text = text.to_string();
+        paragraph
+    };
+    let segment = |flags: u32| LineSegment {
+        text_start_pos: 0,
+        line_height: 0,
+        text_height: 1000,
+        baseline_gap: 850,
+        line_spacing: 600,
+        column_start_pos: 0,
+        segment_width: 42520,
+        flags,
+    };
+
+    // Stored layout: the second paragraph's last line starts page 2, and
+    // the third paragraph has a hard page break
+    let mut section = Section::new();
+    for flags in [[0x01, 0x00], [0x00, 0x01], [0x00, 0x00]] {
+        let mut p = paragraph("line");
+        p.line_segments = flags.iter().map(|&f| segment(f)).collect();
+        section.paragraphs.push(p);
+    }
+    section.paragraphs[2].header.division_type = 0x04;
+    assert_eq!(section.page_count(), 3);
+
+    // Estimated layout: A4 with the default margins leaves 65764 units of
+    // body height, 41 lines of 10pt text at 160%
+    let mut section = Section::new();
+    assert_eq!(section.page_count(), 1);
+    for _ in 0..41 {
+        section.paragraphs.push(paragraph("한 줄"));
+    }
+    assert_eq!(section.page_count(), 1);
+    section.paragraphs.push(paragraph("다음 쪽"));
+    assert_eq!(section.page_count(), 2);
+
+    // 43 full-width characters of 10pt overflow one 42520-unit line
+    section.paragraphs.push(paragraph(&"가".repeat(43)));
+    assert_eq!(section.page_count(), 2);
+    let mut wrapped = paragraph(&"가".repeat(41 * 43));
+    wrapped.header.division_type = 0x04;
+    section.paragraphs.push(wrapped);
+    assert_eq!(section.page_count(), 4);
+
+    // Margins beyond the page leave no body area rather than overflowing
+    section.page_defs.push(PageDef {
+        margin_top: HwpUnit(i32::MAX),
+        margin_bottom: HwpUnit(i32::MAX),
+        margin_left: HwpUnit(i32::MIN),
+        margin_right: HwpUnit(i32::MAX),
+        ..Default::default()
+    });
+    let (width, height) = section.page_def().body_size();
+    assert_eq!(height.0, 0);
+    assert_eq!(width.0, 59528 + 1);
+    assert!(section.page_count() >= 4);
+}
+
+/// Test grouping paragraphs under their resolved style names
+#[test]
+fn test_paragraphs_grouped_by_style() {
+    let utf16 =
+        |text: &str| -> Vec<u8> { text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect() };
+    let style = |name: &str, english: &str| {
+        let mut data = (name.encode_utf16().count() as u16).to_le_bytes().to_vec();
+        data.extend(utf16(name));
+        data.extend_from_slice(&(english.encode_utf16().count() as u16).to_le_bytes());
+        data.extend(utf16(english));
+        data.extend_from_slice(&[0, 0, 0x12, 0x04, 0, 0, 0, 0]);
+        common::record(0x001A, 0, &data)
+    };
+    let paragraph = |style_id: u8, text: &str| {
+        let mut header = [0u8; 22];
+        header[10] = style_id;
+        let mut records = common::record(0x0050, 0, &header);
+        records.extend(common::record(0x0051, 1, &utf16(&format!("{}\r", text))));
+        records
+    };
+
+    let mut doc_info = style("바탕글", "Normal");
+    doc_info.extend(style("", "Heading 1"));
+
+    let mut section = Vec::new();
+    for (style_id, text) in [
+        (1, "Introduction"),
+        (0, "Some body text."),
+        (1, "Methods"),
+        (0, "More body text."),
+        (7, "Unknown style"),
+    ] {
+        section.extend(paragraph(style_id, text));
+    }
+
+    let document = parse(&common::build_hwp(&doc_info, &[section])).unwrap();
+    let groups: Vec<(String, Vec<&str>)> = document
+        .paragraphs_by_style()
+        .into_iter()
+        .map(|(name, paragraphs)| (name, paragraphs.iter().map(|p| p.text.as_str()).collect()))
+        .collect();
+
+    assert_eq!(
+        groups,
+        vec![
+            ("Heading 1".to_string(), vec!["Introduction", "Methods"]),
+            (
+                "바탕글".to_string(),
+                vec!["Some body text.", "More body text."]
+            ),
+            ("Style 7".to_string(), vec!["Unknown style"]),
+        ]
+    );
+}
@@ -1,3 +1,5 @@
+mod common;
+
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::HwpDocument;
 use hwp_parser::formatters::{FormatOptions, MarkdownFlavor, OutputFormat};
@@ -58,6 +60,26 @@ fn test_plain_text_formatter() {
     assert!(result.contains("한글 텍스트도 포함되어 있습니다"));
 }
 
+#[test]
+fn test_plain_text_page_breaks() {
+    let mut doc = create_test_document();
+    doc.sections[0].paragraphs[2].header.division_type = 0x04; // Page break before
+
+    let mut options = FormatOptions::default();
+    let plain = OutputFormat::PlainText
+        .create_formatter(options.clone())
+        .format_document(&doc)
+        .unwrap();
+    assert!(!plain.contains('\u{000C}'));
+
+    options.text_page_breaks = true;
+    let paged = OutputFormat::PlainText
+        .create_formatter(options)
+        .format_document(&doc)
+        .unwrap();
+    assert!(paged.contains("content.\n\u{000C}• First list item"));
+}
+
 #[test]
 fn test_plain_text_with_wrapping() {
     let doc = create_test_document();
@@ -157,6 +179,103 @@ fn test_markdown_formatter_with_toc() {
     assert!(result.contains("[Section 1]"));
 }
 
+#[test]
+fn test_markdown_formatter_with_front_matter() {
+    let doc = create_test_document();
+    let options = FormatOptions {
+        markdown_front_matter: true,
+        ..FormatOptions::default()
+    };
+
+    let formatter = OutputFormat::Markdown.create_formatter(options);
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(result.starts_with("---\ntitle: Test Document Title\n"));
+    let body_start = result[3..].find("---\n").unwrap() + 3;
+    assert!(result[body_start..].contains("# Document"));
+}
+
+#[test]
+fn test_front_matter_title_falls_back_to_source_name() {
+    let mut doc = create_test_document();
+    doc.sections.clear();
+    let options = FormatOptions {
+        markdown_front_matter: true,
+        source_name: Some("quarterly-report".to_string()),
+        ..FormatOptions::default()
+    };
+
+    let formatter = OutputFormat::Markdown.create_formatter(options);
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(result.starts_with("---\ntitle: quarterly-report\n---\n"));
+}
+
+#[test]
+fn test_markdown_front_matter_with_metadata() {
+    use hwp_core::models::document::{DocumentSummary, FaceName, FaceNameType};
+
+    let mut doc = create_test_document();
+    doc.summary = Some(DocumentSummary {
+        author: Some("홍길동".to_string()),
+        created: Some("2024-03-01T09:30:00Z".to_string()),
+        ..DocumentSummary::default()
+    });
+    doc.doc_info.properties.total_page_count = 12;
+    let face_name = |name: &str| FaceName {
+        properties: 0,
+        name: name.to_string(),
+        substitute_font_type: None,
+        substitute_font_name: None,
+        type_info: FaceNameType::default(),
+        base_font_name: None,
+    };
+    doc.doc_info.face_names = vec![face_name("바탕"), face_name("Arial")];
+    let options = FormatOptions {
+        include_metadata: true,
+        ..FormatOptions::default()
+    };
+
+    let formatter = OutputFormat::Markdown.create_formatter(options);
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(
+        result.starts_with(
+            "---\ntitle: Test Document Title\nauthor: 홍길동\nfonts:\n- 바탕\n- Arial\n\
+         date: 2024-03-01T09:30:00Z\nversion: 5.0.0.0\npages: 12\nsections: 1\n---\n"
+        ),
+        "{}",
+        result
+    );
+}
+
+#[test]
+fn test_metadata_prefers_document_summary() {
+    use hwp_core::models::document::DocumentSummary;
+    use hwp_parser::DocumentMetadata;
+
+    let mut doc = create_test_document();
+    doc.summary = Some(DocumentSummary {
+        title: Some("연간 보고서".to_string()),
+        author: Some("홍길동".to_string()),
+        created: Some("2024-03-01T09:30:00Z".to_string()),
+        ..DocumentSummary::default()
+    });
+
+    let metadata = DocumentMetadata::resolve(&doc, &FormatOptions::default());
+    assert_eq!(metadata.title.as_deref(), Some("연간 보고서"));
+    assert_eq!(metadata.author.as_deref(), Some("홍길동"));
+    assert_eq!(metadata.created.as_deref(), Some("2024-03-01T09:30:00Z"));
+
+    // An empty summary title falls back to the first paragraph
+    doc.summary = Some(DocumentSummary {
+        title: Some(" ".to_string()),
+        ..DocumentSummary::default()
+    });
+    let metadata = DocumentMetadata::resolve(&doc, &FormatOptions::default());
+    assert_eq!(metadata.title.as_deref(), Some("Test Document Title"));
+}
+
 #[test]
 fn test_markdown_list_detection() {
     let mut doc = create_test_document();
@@ -181,24 +300,184 @@ fn test_markdown_list_detection() {
 }
 
 #[test]
-fn test_output_format_parsing() {
-    assert_eq!(OutputFormat::from_str("json"), Some(OutputFormat::Json));
-    assert_eq!(OutputFormat::from_str("JSON"), Some(OutputFormat::Json));
+fn test_markdown_tables() {
+    use hwp_core::models::section::{Table, TableCell};
+    use hwp_parser::MarkdownTableStyle;
+
+    let cell = |row: u16, col: u16, text: &str| {
+        let mut cell = TableCell::new(row, col);
+        let mut paragraph = Paragraph::new();
+        paragraph.text = text.to_string();
+        cell.paragraphs.push(paragraph);
+        cell
+    };
+
+    let mut doc = create_test_document();
+    let mut merged = cell(1, 0, "a|b & c");
+    merged.col_span = 2;
+    doc.sections[0].tables.push(Table {
+        rows: 2,
+        cols: 2,
+        cells: vec![cell(0, 0, "Name"), cell(0, 1, "Value"), merged],
+        paragraph_index: 1,
+    });
+
+    let render = |flavor: MarkdownFlavor, style: MarkdownTableStyle| {
+        let options = FormatOptions {
+            markdown_flavor: flavor,
+            markdown_table_style: style,
+            ..FormatOptions::default()
+        };
+        OutputFormat::Markdown
+            .create_formatter(options)
+            .format_document(&doc)
+            .unwrap()
+    };
+
+    // GitHub Flavored Markdown gets a pipe table after the anchor paragraph
+    let gfm = render(MarkdownFlavor::GitHubFlavored, MarkdownTableStyle::Auto);
+    assert!(gfm.contains(
+        "some content.\n\n\
+         | Name | Value |\n\
+         | --- | --- |\n\
+         | a\\|b & c |  |\n\n\
+         - First list item"
+    ));
+
+    // CommonMark has no tables, so they are embedded as HTML
+    let commonmark = render(MarkdownFlavor::CommonMark, MarkdownTableStyle::Auto);
+    assert!(commonmark.contains(
+        "<table>\n\
+         <tr><td>Name</td><td>Value</td></tr>\n\
+         <tr><td colspan=\"2\">a|b &amp; c</td></tr>\n\
+         </table>\n"
+    ));
     assert_eq!(
-        OutputFormat::from_str("text"),
-        Some(OutputFormat::PlainText)
+        render(MarkdownFlavor::GitHubFlavored, MarkdownTableStyle::Html),
+        commonmark
     );
-    assert_eq!(OutputFormat::from_str("txt"), Some(OutputFormat::PlainText));
     assert_eq!(
-        OutputFormat::from_str("plain"),
-        Some(OutputFormat::PlainText)
+        render(MarkdownFlavor::CommonMark, MarkdownTableStyle::Pipe),
+        gfm
     );
+}
+
+#[test]
+fn test_markdown_lists_from_para_shapes() {
+    use hwp_core::models::document::{Bullet, HwpUnit, Numbering, NumberingLevel, ParaShape};
+
+    // Heading type in bits 23-24 (2 numbering, 3 bullet), level in 25-27
+    let shape = |properties1: u32| ParaShape {
+        properties1,
+        left_margin: HwpUnit(0),
+        right_margin: HwpUnit(0),
+        indent: HwpUnit(0),
+        prev_spacing: HwpUnit(0),
+        next_spacing: HwpUnit(0),
+        line_spacing: 160,
+        tab_def_id: 0,
+        numbering_id: 1,
+        border_fill_id: 0,
+        border_offset_left: 0,
+        border_offset_right: 0,
+        border_offset_top: 0,
+        border_offset_bottom: 0,
+        properties2: 0,
+        properties3: 0,
+        line_spacing_type: 0,
+    };
+    let level = |start_number: u16| NumberingLevel {
+        properties: 0,
+        paragraph_shape_id: 0,
+        format: "^1.".to_string(),
+        start_number,
+    };
+
+    let mut doc = create_test_document();
+    doc.doc_info.para_shapes = vec![
+        shape(0),
+        shape(2 << 23),
+        shape(2 << 23 | 1 << 25),
+        shape(3 << 23),
+    ];
+    doc.doc_info.numberings = vec![Numbering {
+        levels: vec![level(3), level(1)],
+    }];
+    doc.doc_info.bullets = vec![Bullet {
+        properties: 0,
+        paragraph_shape_id: 0,
+        bullet_char: Some("●".to_string()),
+        image_id: None,
+    }];
+
+    let mut section = Section::new();
+    for (shape_id, text) in [
+        (0, "Intro"),
+        (1, "Alpha"),
+        (2, "Detail"),
+        (1, "Beta"),
+        (2, "Again"),
+        (3, "Point"),
+        (0, "Break"),
+        (1, "Gamma"),
+    ] {
+        let mut paragraph = Paragraph::new();
+        paragraph.header.para_shape_id = shape_id;
+        paragraph.text = text.to_string();
+        section.paragraphs.push(paragraph);
+    }
+    doc.sections = vec![section];
+
+    let formatter = OutputFormat::Markdown.create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    // Levels nest and restart under each parent; a new list after a
+    // plain paragraph starts over at the level's start number
     assert_eq!(
-        OutputFormat::from_str("markdown"),
-        Some(OutputFormat::Markdown)
+        result,
+        "# Document\n\nIntro\n\n\
+         3. Alpha\n    1. Detail\n4. Beta\n    1. Again\n- Point\n\n\
+         Break\n\n\
+         3. Gamma"
     );
-    assert_eq!(OutputFormat::from_str("md"), Some(OutputFormat::Markdown));
-    assert_eq!(OutputFormat::from_str("unknown"), None);
+}
+
+#[test]
+fn test_output_format_parsing() {
+    let parse = |s: &str| s.parse::<OutputFormat>().ok();
+
+    assert_eq!(parse("json"), Some(OutputFormat::Json));
+    assert_eq!(parse("JSON"), Some(OutputFormat::Json));
+    assert_eq!(parse("text"), Some(OutputFormat::PlainText));
+    assert_eq!(parse("txt"), Some(OutputFormat::PlainText));
+    assert_eq!(parse("plain"), Some(OutputFormat::PlainText));
+    assert_eq!(parse("markdown"), Some(OutputFormat::Markdown));
+    assert_eq!(parse("md"), Some(OutputFormat::Markdown));
+    assert_eq!(parse("unknown"), None);
+
+    let error = "unknown".parse::<OutputFormat>().unwrap_err();
+    assert_eq!(error.name, "unknown");
+    assert_eq!(error.to_string(), "Unknown output format 'unknown'");
+}
+
+#[test]
+fn test_output_format_display_round_trips() {
+    for format in [
+        OutputFormat::Json,
+        OutputFormat::PlainText,
+        OutputFormat::Markdown,
+        OutputFormat::Html,
+        OutputFormat::Yaml,
+        OutputFormat::Rtf,
+        OutputFormat::Csv,
+        OutputFormat::Docx,
+    ] {
+        assert_eq!(
+            format.to_string().parse::<OutputFormat>().ok(),
+            Some(format)
+        );
+    }
+    assert_eq!(OutputFormat::PlainText.to_string(), "text");
 }
 
 #[test]
@@ -232,3 +511,552 @@ fn test_format_single_paragraph() {
     let result = formatter.format_paragraph(&para, 0).unwrap();
     assert!(result.contains("Single paragraph test"));
 }
+
+#[test]
+fn test_paragraph_char_len_vs_byte_len() {
+    let mut para = Paragraph::new();
+    para.text = "한글 텍스트".to_string();
+
+    // Hangul syllables are 3 bytes each in UTF-8
+    assert_eq!(para.char_len(), 6);
+    assert_eq!(para.byte_len(), 16);
+    assert_ne!(para.char_len(), para.byte_len());
+}
+
+#[test]
+fn test_yaml_statistics_count_characters() {
+    let doc = create_test_document();
+    let formatter = OutputFormat::Yaml.create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&result).unwrap();
+    let expected: usize = doc.sections[0]
+        .paragraphs
+        .iter()
+        .map(|p| p.text.chars().count())
+        .sum();
+
+    assert_eq!(
+        yaml["statistics"]["total_characters"].as_u64(),
+        Some(expected as u64)
+    );
+}
+
+#[test]
+fn test_plain_text_paragraph_wrapping() {
+    let wrap = |text: &str, width: usize| {
+        let options = FormatOptions {
+            text_width: Some(width),
+            ..FormatOptions::default()
+        };
+        let mut paragraph = Paragraph::new();
+        paragraph.text = text.to_string();
+        OutputFormat::PlainText
+            .create_formatter(options)
+            .format_paragraph(&paragraph, 0)
+            .unwrap()
+    };
+
+    assert_eq!(
+        wrap("the quick brown fox jumps", 10),
+        "the quick\nbrown fox\njumps\n"
+    );
+
+    // Hangul syllables are two columns wide and are never split
+    assert_eq!(wrap("한글 텍스트도 포함", 8), "한글\n텍스트도\n포함\n");
+
+    // Words wider than the line are broken between characters
+    assert_eq!(
+        wrap("see https://example.com/path", 8),
+        "see\nhttps://\nexample.\ncom/path\n"
+    );
+    assert_eq!(wrap("가나다라마바", 5), "가나\n다라\n마바\n");
+}
+
+#[test]
+fn test_plain_text_paragraph_without_width() {
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "unwrapped text stays on one line".to_string();
+
+    let formatter = OutputFormat::PlainText.create_formatter(FormatOptions::default());
+    assert_eq!(
+        formatter.format_paragraph(&paragraph, 0).unwrap(),
+        paragraph.text
+    );
+}
+
+#[test]
+fn test_html_character_formatting_spans() {
+    use hwp_core::models::paragraph::CharShapePos;
+
+    let mut doc = create_test_document();
+    // 0: plain, 1: bold red (0x00BBGGRR), 2: italic underlined
+    doc.doc_info.char_shapes = vec![
+        common::char_shape(0, 0),
+        common::char_shape(1 << 1, 0x0000FF),
+        common::char_shape(1 | (1 << 2), 0),
+    ];
+
+    let paragraph = &mut doc.sections[0].paragraphs[0];
+    paragraph.text = "a <b> word & more".to_string();
+    paragraph.char_shapes = vec![
+        CharShapePos {
+            position: 0,
+            shape_id: 0,
+        },
+        CharShapePos {
+            position: 6,
+            shape_id: 1,
+        },
+        CharShapePos {
+            position: 10,
+            shape_id: 0,
+        },
+        CharShapePos {
+            position: 13,
+            shape_id: 2,
+        },
+    ];
+
+    let formatter = OutputFormat::Html.create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(result.contains(
+        "<p class=\"hwp-paragraph\">a &lt;b&gt; \
+         <span style=\"font-weight:bold;color:#FF0000\">word</span> &amp; \
+         <span style=\"font-style:italic;text-decoration:underline\">more</span></p>"
+    ));
+}
+
+#[test]
+fn test_text_before_first_char_shape_takes_its_shape() {
+    use hwp_core::models::paragraph::CharShapePos;
+
+    let mut doc = create_test_document();
+    doc.doc_info.char_shapes = vec![common::char_shape(0, 0), common::char_shape(1 << 1, 0)];
+    doc.sections[0].paragraphs.truncate(1);
+    let paragraph = &mut doc.sections[0].paragraphs[0];
+    paragraph.text = "ab".to_string();
+    paragraph.char_shapes = vec![CharShapePos {
+        position: 1,
+        shape_id: 1,
+    }];
+
+    let html = OutputFormat::Html
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(html.contains("<span style=\"font-weight:bold\">ab</span>"));
+
+    let rtf = OutputFormat::Rtf
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(rtf.contains("{\\b ab}\\par"));
+}
+
+#[test]
+fn test_html_character_formatting_classes() {
+    use hwp_core::models::paragraph::CharShapePos;
+
+    let mut doc = create_test_document();
+    // 0: plain, 1: bold
+    doc.doc_info.char_shapes = vec![common::char_shape(0, 0), common::char_shape(1 << 1, 0)];
+
+    let paragraph = &mut doc.sections[0].paragraphs[0];
+    paragraph.text = "plain bold".to_string();
+    paragraph.char_shapes = vec![
+        CharShapePos {
+            position: 0,
+            shape_id: 0,
+        },
+        CharShapePos {
+            position: 6,
+            shape_id: 1,
+        },
+    ];
+
+    let options = FormatOptions {
+        html_css_inline: false,
+        html_css_prefix: "doc-".to_string(),
+        ..FormatOptions::default()
+    };
+    let result = OutputFormat::Html
+        .create_formatter(options)
+        .format_document(&doc)
+        .unwrap();
+
+    assert!(result.contains(".doc-cs-1 { font-weight:bold }"));
+    assert!(!result.contains(".doc-cs-0"));
+    assert!(result
+        .contains("<p class=\"hwp-paragraph\">plain <span class=\"doc-cs-1\">bold</span></p>"));
+    assert!(!result.contains("<span style="));
+}
+
+#[test]
+fn test_hyperlinks_rendered() {
+    use hwp_core::models::paragraph::CharShapePos;
+    use hwp_core::models::section::Hyperlink;
+
+    let bold = common::char_shape(1 << 1, 0);
+    let link = |text: &str, url: &str, char_offset| Hyperlink {
+        text: text.to_string(),
+        url: url.to_string(),
+        para_index: 0,
+        char_offset,
+    };
+
+    let mut doc = create_test_document();
+    doc.doc_info.char_shapes = vec![bold.clone(), bold];
+    doc.doc_info.char_shapes[0].properties = 0;
+
+    let section = &mut doc.sections[0];
+    section.paragraphs[0].text = "Read the manual (v2) now".to_string();
+    // Bold from "manual" through "(v2)", crossing the end of the link
+    section.paragraphs[0].char_shapes = [(0, 0), (9, 1), (20, 0)]
+        .iter()
+        .map(|&(position, shape_id)| CharShapePos { position, shape_id })
+        .collect();
+    section.hyperlinks = vec![
+        link("the manual", "https://example.com/a b", 5),
+        // Nested inside the first link, so not rendered
+        link("manual", "https://example.com/inner", 9),
+    ];
+
+    let markdown = OutputFormat::Markdown
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(markdown.contains("Read [the manual](https://example.com/a%20b) (v2) now"));
+    assert!(!markdown.contains("inner"));
+
+    let html = OutputFormat::Html
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(html.contains(
+        "<p class=\"hwp-paragraph\">Read <a href=\"https://example.com/a b\">the \
+         <span style=\"font-weight:bold\">manual</span></a>\
+         <span style=\"font-weight:bold\"> (v2)</span> now</p>"
+    ));
+}
+
+#[test]
+fn test_unsafe_hyperlinks_not_linked() {
+    use hwp_core::models::section::Hyperlink;
+
+    let link = |text: &str, url: &str, char_offset| Hyperlink {
+        text: text.to_string(),
+        url: url.to_string(),
+        para_index: 0,
+        char_offset,
+    };
+
+    let mut doc = create_test_document();
+    let section = &mut doc.sections[0];
+    section.paragraphs[0].text = "Click here or there".to_string();
+    section.hyperlinks = vec![
+        link("here", "JavaScript:alert(1)", 6),
+        link("there", "docs/guide.html", 14),
+        link("", "java\tscript:alert(2)", 19),
+    ];
+
+    let html = OutputFormat::Html
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(html.contains(
+        "<p class=\"hwp-paragraph\">Click here or <a href=\"docs/guide.html\">there</a>"
+    ));
+    assert!(!html.contains("href=\"java"));
+}
+
+#[test]
+fn test_rtf_formatting_and_korean_escapes() {
+    use hwp_core::models::document::{HwpUnit, ParaShape};
+    use hwp_core::models::paragraph::CharShapePos;
+
+    let mut doc = create_test_document();
+    // 0: plain, 1: bold italic red (0x00BBGGRR)
+    doc.doc_info.char_shapes = vec![
+        common::char_shape(0, 0),
+        common::char_shape(1 | (1 << 1), 0x0000FF),
+    ];
+    // 1000 HWPUNIT = 200 twips
+    doc.doc_info.para_shapes = vec![ParaShape {
+        properties1: 0,
+        left_margin: HwpUnit(1000),
+        right_margin: HwpUnit(0),
+        indent: HwpUnit(0),
+        prev_spacing: HwpUnit(0),
+        next_spacing: HwpUnit(0),
+        line_spacing: 160,
+        tab_def_id: 0,
+        numbering_id: 0,
+        border_fill_id: 0,
+        border_offset_left: 0,
+        border_offset_right: 0,
+        border_offset_top: 0,
+        border_offset_bottom: 0,
+        properties2: 0,
+        properties3: 0,
+        line_spacing_type: 0,
+    }];
+
+    let paragraph = &mut doc.sections[0].paragraphs[0];
+    paragraph.text = "한글 {x}".to_string();
+    paragraph.char_shapes = vec![
+        CharShapePos {
+            position: 0,
+            shape_id: 1,
+        },
+        CharShapePos {
+            position: 2,
+            shape_id: 0,
+        },
+    ];
+
+    let formatter = "rtf"
+        .parse::<OutputFormat>()
+        .unwrap()
+        .create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(result.starts_with("{\\rtf1"));
+    assert!(result.contains("{\\colortbl;\\red255\\green0\\blue0;}"));
+    // 한 = U+D55C (-10916), 글 = U+AE00 (-20992)
+    assert!(result.contains("\\pard\\li200 {\\b\\i\\cf1 \\u-10916?\\u-20992?} \\{x\\}\\par"));
+    assert!(result.is_ascii());
+    assert!(result.trim_end().ends_with('}'));
+}
+
+#[test]
+fn test_html_required_fonts_block() {
+    use hwp_core::models::document::{CharShape, FaceName, FaceNameType};
+    use hwp_core::models::paragraph::CharShapePos;
+
+    let face = |name: &str| FaceName {
+        properties: 0,
+        name: name.to_string(),
+        substitute_font_type: None,
+        substitute_font_name: None,
+        type_info: FaceNameType {
+            family: 0,
+            serif: 0,
+            weight: 0,
+            proportion: 0,
+            contrast: 0,
+            stroke_variation: 0,
+            arm_style: 0,
+            letter_form: 0,
+            midline: 0,
+            x_height: 0,
+        },
+        base_font_name: None,
+    };
+
+    let mut doc = create_test_document();
+    doc.doc_info.face_names = vec![face("함초롬바탕"), face("Arial"), face("Unused")];
+    // Korean text in face 0, everything else in face 1
+    doc.doc_info.char_shapes = vec![CharShape {
+        face_name_ids: vec![0, 1, 1, 1, 1, 1, 1],
+        ..common::char_shape(0, 0)
+    }];
+    let paragraph = &mut doc.sections[0].paragraphs[4];
+    paragraph.text = "한글 OK".to_string();
+    paragraph.char_shapes = vec![CharShapePos {
+        position: 0,
+        shape_id: 0,
+    }];
+
+    assert_eq!(
+        doc.doc_info.unique_font_names(),
+        vec!["함초롬바탕", "Arial"]
+    );
+    assert_eq!(doc.font_usage(), vec![("함초롬바탕", 2), ("Arial", 2)]);
+
+    let options = FormatOptions {
+        list_required_fonts: true,
+        ..FormatOptions::default()
+    };
+    let formatter = OutputFormat::Html.create_formatter(options);
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert!(result.contains(
+        "<!-- Required fonts:\n         함초롬바탕 (2 characters)\n         Arial (2 characters)\n    -->"
+    ));
+    assert!(!result.contains("Unused"));
+
+    let formatter = OutputFormat::Html.create_formatter(FormatOptions::default());
+    assert!(!formatter
+        .format_document(&doc)
+        .unwrap()
+        .contains("Required fonts"));
+}
+
+#[test]
+fn test_csv_paragraph_rows() {
+    let mut doc = create_test_document();
+    doc.sections[0].paragraphs[1].text = "Say \"hi\", then\nleave".to_string();
+
+    let formatter = "csv"
+        .parse::<OutputFormat>()
+        .unwrap()
+        .create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    // RFC 4180: CRLF between records, quoted fields may hold bare line breaks
+    assert!(result.starts_with("section,paragraph,text\r\n0,0,Test Document Title\r\n"));
+    assert!(result.contains("\r\n0,1,\"Say \"\"hi\"\", then\nleave\"\r\n"));
+    assert!(result.contains("0,4,한글 텍스트도 포함되어 있습니다.\r\n"));
+
+    let options = FormatOptions {
+        csv_delimiter: ';',
+        csv_quote_char: '\'',
+        ..FormatOptions::default()
+    };
+    let result = OutputFormat::Csv
+        .create_formatter(options)
+        .format_document(&doc)
+        .unwrap();
+    assert!(result.starts_with("section;paragraph;text\r\n0;0;Test Document Title\r\n"));
+    assert!(result.contains("0;1;'Say \"hi\", then\nleave'\r\n"));
+}
+
+#[test]
+fn test_csv_table_cell_rows() {
+    use hwp_core::models::section::{Table, TableCell};
+
+    let mut doc = create_test_document();
+    let mut cells = Vec::new();
+    for (row, col, text) in [
+        (0, 0, "Name"),
+        (0, 1, "Amount"),
+        (1, 0, "A"),
+        (1, 1, "1,000"),
+    ] {
+        let mut cell = TableCell::new(row, col);
+        let mut paragraph = Paragraph::new();
+        paragraph.text = text.to_string();
+        cell.paragraphs.push(paragraph);
+        cells.push(cell);
+    }
+    doc.sections[0].tables.push(Table {
+        rows: 2,
+        cols: 2,
+        cells,
+        ..Table::default()
+    });
+
+    let formatter = OutputFormat::Csv.create_formatter(FormatOptions::default());
+    let result = formatter.format_document(&doc).unwrap();
+
+    assert_eq!(
+        result,
+        "section,table,row,col,text\r\n\
+         0,0,0,0,Name\r\n\
+         0,0,0,1,Amount\r\n\
+         0,0,1,0,A\r\n\
+         0,0,1,1,\"1,000\"\r\n"
+    );
+}
+
+#[test]
+fn test_docx_archive_parts() {
+    use hwp_core::models::document::{CharShape, Style};
+    use hwp_core::models::paragraph::CharShapePos;
+    use std::io::{Cursor, Read};
+
+    let mut doc = create_test_document();
+    // Bold underlined red 12pt (0x00BBGGRR, 1/100 pt)
+    doc.doc_info.char_shapes = vec![CharShape {
+        base_size: 1200,
+        ..common::char_shape(1 << 1 | 1 << 2, 0x0000FF)
+    }];
+    doc.doc_info.styles = vec![Style {
+        name: "바탕글".to_string(),
+        english_name: "Normal".to_string(),
+        properties: 0,
+        next_style_id: 0,
+        lang_id: 0x0412,
+        para_shape_id: 0,
+        char_shape_id: 0,
+    }];
+
+    let paragraph = &mut doc.sections[0].paragraphs[0];
+    paragraph.text = "한글 <b>".to_string();
+    paragraph.char_shapes = vec![CharShapePos {
+        position: 0,
+        shape_id: 0,
+    }];
+
+    let formatter = "docx"
+        .parse::<OutputFormat>()
+        .unwrap()
+        .create_formatter(FormatOptions::default());
+    let bytes = formatter.format_bytes(&doc).unwrap();
+    assert!(bytes.starts_with(b"PK"));
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut part = |name: &str| {
+        let mut content = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    };
+
+    assert!(part("[Content_Types].xml").contains("/word/document.xml"));
+    assert!(part("_rels/.rels").contains("Target=\"word/document.xml\""));
+
+    let document = part("word/document.xml");
+    assert!(document.contains(
+        "<w:p><w:pPr><w:pStyle w:val=\"Style0\"/></w:pPr><w:r><w:rPr><w:b/><w:color w:val=\"FF0000\"/><w:sz w:val=\"24\"/><w:u w:val=\"single\"/></w:rPr><w:t xml:space=\"preserve\">한글 &lt;b&gt;</w:t></w:r></w:p>"
+    ));
+
+    let styles = part("word/styles.xml");
+    assert!(styles
+        .contains("<w:style w:type=\"paragraph\" w:styleId=\"Style0\"><w:name w:val=\"Normal\"/>"));
+}
+
+#[test]
+fn test_html_picture_embedding() {
+    use hwp_core::models::document::BinDataEntry;
+    use hwp_core::models::section::Picture;
+
+    let png = b"\x89PNG\r\n\x1a\nIHDR".to_vec();
+    let mut doc = create_test_document();
+    doc.doc_info.bin_data_entries.push(BinDataEntry {
+        id: 1,
+        link_type: 1,
+        compression_type: 2,
+        data: png,
+    });
+    let section = &mut doc.sections[0];
+    section.paragraphs[0].text.clear();
+    section.pictures = vec![Picture {
+        paragraph_index: 0,
+        bin_data_id: 1,
+        ..Picture::default()
+    }];
+
+    let placeholder = OutputFormat::Html
+        .create_formatter(FormatOptions::default())
+        .format_document(&doc)
+        .unwrap();
+    assert!(placeholder.contains("<p class=\"hwp-paragraph\"><img alt=\"[image 1]\"></p>"));
+
+    let options = FormatOptions {
+        html_embed_images: true,
+        ..FormatOptions::default()
+    };
+    let embedded = OutputFormat::Html
+        .create_formatter(options)
+        .format_document(&doc)
+        .unwrap();
+    assert!(
+        embedded.contains("<img src=\"data:image/png;base64,iVBORw0KGgpJSERS\" alt=\"[image 1]\">")
+    );
+}
@@ -1,4 +1,6 @@
-use hwp_core::{HwpVersion, HWP_SIGNATURE};
+mod common;
+
+use hwp_core::{HwpDocument, HwpError, HwpVersion, HWP_SIGNATURE};
 use hwp_parser;
 
 #[test]
@@ -66,3 +68,115 @@ fn test_unsupported_version() {
         assert!(e.to_string().contains("Unsupported"));
     }
 }
+
+#[test]
+fn test_hwp_file_open() {
+    let mut data = Vec::new();
+    data.extend_from_slice(HWP_SIGNATURE);
+    data.extend_from_slice(&HwpVersion::new(5, 0, 0, 0).to_u32().to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&[0u8; 216]);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("minutes.hwp");
+    std::fs::write(&path, &data).unwrap();
+
+    let file = hwp_parser::HwpFile::open(&path).unwrap();
+    assert_eq!(file.path(), path.as_path());
+    assert_eq!(file.size(), data.len() as u64);
+    assert_eq!(file.document().header.version.major, 5);
+    assert!(file.text().is_empty());
+    assert_eq!(file.metadata().title.as_deref(), Some("minutes"));
+
+    assert!(hwp_parser::HwpFile::open(dir.path().join("missing.hwp")).is_err());
+}
+
+#[test]
+fn test_hwp_file_open_fixture() {
+    let path = std::path::Path::new("tests/fixtures/basic/simple_text.hwp");
+    if !path.exists() {
+        eprintln!("Skipping test: fixture not found");
+        return;
+    }
+
+    let file = hwp_parser::HwpFile::open(path).unwrap();
+    assert_eq!(file.size(), std::fs::metadata(path).unwrap().len());
+    assert_eq!(file.text(), file.document().get_text());
+    assert!(!file.text().trim().is_empty());
+}
+
+/// A parsed document survives a JSON round trip, so it can be cached
+#[test]
+fn test_document_serde_round_trip() {
+    let section = common::paragraph_records(&["First paragraph", "두 번째 문단"]);
+    let document = hwp_parser::parse(&common::build_hwp(&[], &[section])).unwrap();
+
+    let json = serde_json::to_string(&document).unwrap();
+    let restored: HwpDocument = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.get_text(), document.get_text());
+    assert_eq!(restored.header.version, document.header.version);
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+    let error = serde_json::to_value(HwpError::PasswordProtected).unwrap();
+    assert_eq!(
+        error,
+        serde_json::json!({
+            "code": "PASSWORD_PROTECTED",
+            "message": "Document is password protected"
+        })
+    );
+}
+
+/// Nested models survive the JSON round trip of `from_json`
+#[test]
+fn test_from_json_round_trip() {
+    use hwp_core::models::document::{BinDataEntry, HwpUnit};
+    use hwp_core::models::paragraph::CharShapePos;
+    use hwp_core::models::section::{Picture, Table, TableCell};
+    use hwp_core::models::Paragraph;
+
+    let section = common::paragraph_records(&["Before the table", "표 다음 문단"]);
+    let mut document = hwp_parser::parse(&common::build_hwp(&[], &[section])).unwrap();
+
+    let section = &mut document.sections[0];
+    section.paragraphs[0].char_shapes = vec![CharShapePos {
+        position: 0,
+        shape_id: 1,
+    }];
+    let mut cell = TableCell::new(0, 1);
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "cell".to_string();
+    cell.paragraphs.push(paragraph);
+    section.tables.push(Table {
+        rows: 1,
+        cols: 2,
+        cells: vec![cell],
+        ..Table::default()
+    });
+    section.pictures.push(Picture {
+        bin_data_id: 1,
+        width: HwpUnit(7200),
+        caption: Some("Figure".to_string()),
+        ..Picture::default()
+    });
+    document.bin_data.insert(1, vec![0x89, b'P', b'N', b'G']);
+    document.doc_info.bin_data_entries.push(BinDataEntry {
+        id: 1,
+        link_type: 1,
+        compression_type: 2,
+        data: Vec::new(),
+    });
+
+    let json = serde_json::to_string(&document).unwrap();
+    let restored = hwp_parser::from_json(&json).unwrap();
+
+    assert_eq!(restored.get_text(), document.get_text());
+    assert_eq!(restored.sections[0].tables[0].to_tsv(), "\tcell\n");
+    assert_eq!(restored.sections[0].pictures, document.sections[0].pictures);
+    assert_eq!(restored.bin_data, document.bin_data);
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+    let error = hwp_parser::from_json("{\"header\": 1}").unwrap_err();
+    assert_eq!(error.code(), "INVALID_FORMAT");
+}
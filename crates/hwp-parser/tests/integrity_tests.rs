@@ -0,0 +1,72 @@
+mod common;
+
+use common::{build_cfb, build_hwp, compress_stream, file_header, record};
+use hwp_parser::integrity::{check_integrity, IntegrityStatus};
+
+const HWPTAG_DOCUMENT_PROPERTIES: u16 = 0x10;
+const HWPTAG_PARA_HEADER: u16 = 0x50;
+
+fn statuses(data: &[u8]) -> Vec<(String, IntegrityStatus)> {
+    check_integrity(data)
+        .into_iter()
+        .map(|r| (r.stream_name, r.status))
+        .collect()
+}
+
+#[test]
+fn test_valid_document_passes() {
+    let section = record(HWPTAG_PARA_HEADER, 0, &[0; 22]);
+    let doc_info = record(HWPTAG_DOCUMENT_PROPERTIES, 0, &[0; 26]);
+    let data = build_hwp(&doc_info, &[section]);
+
+    let reports = check_integrity(&data);
+    assert!(reports.iter().all(|r| r.is_ok()), "{:?}", reports);
+    assert_eq!(
+        reports
+            .iter()
+            .map(|r| r.stream_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["CFB", "FileHeader", "DocInfo", "BodyText/Section0"]
+    );
+}
+
+#[test]
+fn test_reports_every_problem() {
+    let mut data = build_cfb(&[
+        ("FileHeader", file_header()),
+        ("DocInfo", compress_stream(&[0; 100])),
+        ("BodyText/Section0", vec![0xFF; 100]),
+    ]);
+
+    // Declare DocInfo longer than its mini sector chain: the directory
+    // starts at file offset 1024 and DocInfo is its third entry
+    let size_offset = 1024 + 2 * 128 + 120;
+    data[size_offset..size_offset + 4].copy_from_slice(&3000u32.to_le_bytes());
+
+    let reports = check_integrity(&data);
+    assert_eq!(
+        statuses(&data),
+        vec![
+            ("CFB".to_string(), IntegrityStatus::Ok),
+            ("FileHeader".to_string(), IntegrityStatus::Ok),
+            ("DocInfo".to_string(), IntegrityStatus::Corrupt),
+            ("BodyText/Section0".to_string(), IntegrityStatus::Corrupt),
+        ]
+    );
+    assert!(reports[2].issue.as_ref().unwrap().contains("sector chain"));
+}
+
+#[test]
+fn test_missing_streams_and_bad_signature() {
+    let data = build_cfb(&[("FileHeader", vec![0; 256])]);
+
+    assert_eq!(
+        statuses(&data),
+        vec![
+            ("CFB".to_string(), IntegrityStatus::Ok),
+            ("DocInfo".to_string(), IntegrityStatus::Missing),
+            ("BodyText/Section0".to_string(), IntegrityStatus::Missing),
+            ("FileHeader".to_string(), IntegrityStatus::Corrupt),
+        ]
+    );
+}
@@ -248,10 +248,12 @@ fn test_para_shapes() {
 
             for (i, shape) in para_shapes.iter().take(3).enumerate() {
                 println!(
-                    "    [{}] Margins: L={} R={}",
-                    i, shape.left_margin, shape.right_margin
+                    "    [{}] Margins: L={:.1}mm R={:.1}mm",
+                    i,
+                    shape.left_margin.to_mm(),
+                    shape.right_margin.to_mm()
                 );
-                println!("        Indent: {}", shape.indent);
+                println!("        Indent: {:.1}pt", shape.indent.to_pt());
                 println!("        Line spacing: {}", shape.line_spacing);
             }
 
@@ -1,5 +1,10 @@
-use hwp_parser::parse;
+mod common;
+
+use hwp_parser::{
+    parse, parse_reader, parse_reader_with_options, parse_with_options, ParseOptions,
+};
 use std::fs;
+use std::io::Cursor;
 use std::path::Path;
 
 /// Test that all fixture files can be parsed without panicking
@@ -148,3 +153,57 @@ fn test_memory_stability() {
 
     println!("✓ Memory stability test passed");
 }
+
+/// Test that `skip_body` reads fonts without touching BodyText streams
+#[test]
+fn test_skip_body_reads_doc_info_only() {
+    // A single FACE_NAME record for "Batang"
+    let name: Vec<u8> = "Batang"
+        .encode_utf16()
+        .flat_map(|u| u.to_le_bytes())
+        .collect();
+    let mut face_name = vec![0x00];
+    face_name.extend_from_slice(&6u16.to_le_bytes());
+    face_name.extend(name);
+    let doc_info = common::record(0x0013, 0, &face_name);
+
+    // A PARA_TEXT record that claims more data than the stream holds
+    let mut section = (0x0043u32 | (100u32 << 20)).to_le_bytes().to_vec();
+    section.extend_from_slice(&[0x41, 0x00]);
+
+    let data = common::build_hwp(&doc_info, &[section]);
+
+    assert!(
+        parse(&data).is_err(),
+        "the body stream should be unreadable"
+    );
+
+    let options = ParseOptions {
+        skip_body: true,
+        ..ParseOptions::default()
+    };
+    let document = parse_with_options(&data, &options).unwrap();
+    assert_eq!(document.doc_info.face_names.len(), 1);
+    assert_eq!(document.doc_info.face_names[0].name, "Batang");
+    assert_eq!(document.sections.len(), 1);
+    assert!(document.sections[0].paragraphs.is_empty());
+
+    // The options apply the same way when reading from a reader
+    let document = parse_reader_with_options(Cursor::new(&data), &options).unwrap();
+    assert_eq!(document.doc_info.face_names[0].name, "Batang");
+    assert!(document.sections[0].paragraphs.is_empty());
+}
+
+#[test]
+fn test_parse_reader_matches_parse() {
+    let data = common::build_hwp(&[], &[common::paragraph_records(&["Hello"])]);
+
+    let from_bytes = parse(&data).unwrap();
+    let from_reader = parse_reader(Cursor::new(&data)).unwrap();
+    assert_eq!(from_reader.get_text(), from_bytes.get_text());
+    assert_eq!(from_reader.sections.len(), 1);
+    assert!(from_reader.get_text().contains("Hello"));
+
+    // Input too short to hold a signature falls through to the legacy parser
+    assert!(parse_reader(Cursor::new(b"HWP")).is_err());
+}
@@ -1,4 +1,5 @@
 use hwp_core::constants::tag_id::doc_info;
+use hwp_core::models::document::{HwpUnit, LineSpacing};
 use hwp_core::models::record::RecordHeader;
 use hwp_parser::parser::doc_info_records::*;
 use hwp_parser::parser::record::{RecordDataParser, RecordParser};
@@ -254,11 +255,11 @@ fn test_para_shape_parsing() {
 
     let para_shape = parse_para_shape(&data).unwrap();
     assert_eq!(para_shape.properties1, 0x12345678);
-    assert_eq!(para_shape.left_margin, 4096);
-    assert_eq!(para_shape.right_margin, 2048);
-    assert_eq!(para_shape.indent, 512);
-    assert_eq!(para_shape.prev_spacing, 256);
-    assert_eq!(para_shape.next_spacing, 256);
+    assert_eq!(para_shape.left_margin, HwpUnit(4096));
+    assert_eq!(para_shape.right_margin, HwpUnit(2048));
+    assert_eq!(para_shape.indent, HwpUnit(512));
+    assert_eq!(para_shape.prev_spacing, HwpUnit(256));
+    assert_eq!(para_shape.next_spacing, HwpUnit(256));
     assert_eq!(para_shape.line_spacing, 1600);
     assert_eq!(para_shape.tab_def_id, 1);
     assert_eq!(para_shape.numbering_id, 2);
@@ -272,6 +273,57 @@ fn test_para_shape_parsing() {
     assert_eq!(para_shape.line_spacing_type, 1);
 }
 
+/// Build PARA_SHAPE record data with the given line spacing
+fn create_para_shape_data(line_spacing: i32, line_spacing_type: u32) -> Vec<u8> {
+    let mut data = vec![0u8; 24]; // properties1 .. next_spacing
+    data.extend_from_slice(&line_spacing.to_le_bytes());
+    data.extend_from_slice(&[0u8; 14]); // ids and border offsets
+    data.extend_from_slice(&[0u8; 8]); // properties2, properties3
+    data.extend_from_slice(&line_spacing_type.to_le_bytes());
+    data
+}
+
+#[test]
+fn test_para_shape_line_spacing_percent() {
+    let para_shape = parse_para_shape(&create_para_shape_data(160, 0)).unwrap();
+    assert_eq!(para_shape.line_spacing(), LineSpacing::Percent(160));
+    assert_eq!(para_shape.line_spacing_pt(10.0), 16.0);
+}
+
+#[test]
+fn test_para_shape_line_spacing_fixed() {
+    let para_shape = parse_para_shape(&create_para_shape_data(1800, 1)).unwrap();
+    assert_eq!(para_shape.line_spacing(), LineSpacing::Fixed(HwpUnit(1800)));
+    assert_eq!(para_shape.line_spacing_pt(10.0), 18.0);
+}
+
+#[test]
+fn test_para_shape_line_spacing_between_lines() {
+    let para_shape = parse_para_shape(&create_para_shape_data(400, 2)).unwrap();
+    assert_eq!(
+        para_shape.line_spacing(),
+        LineSpacing::BetweenLines(HwpUnit(400))
+    );
+    assert_eq!(para_shape.line_spacing_pt(10.0), 14.0);
+}
+
+#[test]
+fn test_para_shape_line_spacing_at_least() {
+    let para_shape = parse_para_shape(&create_para_shape_data(1200, 3)).unwrap();
+    assert_eq!(
+        para_shape.line_spacing(),
+        LineSpacing::AtLeast(HwpUnit(1200))
+    );
+    assert_eq!(para_shape.line_spacing_pt(10.0), 12.0);
+    assert_eq!(para_shape.line_spacing_pt(14.0), 14.0);
+}
+
+#[test]
+fn test_para_shape_line_spacing_unknown_type() {
+    let para_shape = parse_para_shape(&create_para_shape_data(100, 7)).unwrap();
+    assert_eq!(para_shape.line_spacing(), LineSpacing::Percent(100));
+}
+
 #[test]
 fn test_style_parsing() {
     let data = vec![
@@ -406,3 +458,59 @@ fn test_malformed_record_handling() {
     let result = parser.parse_next_record();
     assert!(result.is_err()); // Should return error for insufficient data
 }
+
+#[test]
+fn test_position_of_tag() {
+    let record = |tag: u16, data: &[u8]| {
+        let mut bytes = (tag as u32 | ((data.len() as u32) << 20))
+            .to_le_bytes()
+            .to_vec();
+        bytes.extend_from_slice(data);
+        bytes
+    };
+
+    let face_name_data = [0x00, 0x01, 0x00, 0x41, 0x00]; // properties, "A"
+    let mut data = record(doc_info::DOCUMENT_PROPERTIES, &[0; 30]);
+    data.extend(record(doc_info::ID_MAPPINGS, &[0; 72]));
+    data.extend(record(doc_info::BIN_DATA, &[0; 6]));
+    let expected = data.len();
+    data.extend(record(doc_info::FACE_NAME, &face_name_data));
+    data.extend(record(doc_info::FACE_NAME, &face_name_data));
+
+    let mut parser = RecordParser::new_with_context(&data, RecordContext::DocInfo);
+    let offset = parser.position_of_tag(doc_info::FACE_NAME).unwrap();
+    assert_eq!(offset, Some(expected));
+
+    // The offset points at a valid header, and the parser is left on it
+    let header_bytes: [u8; 4] = data[expected..expected + 4].try_into().unwrap();
+    let header = RecordHeader::from_bytes(header_bytes);
+    assert_eq!(header.tag_id(), doc_info::FACE_NAME);
+    assert_eq!(header.size() as usize, face_name_data.len());
+
+    let record = parser.parse_next_record().unwrap().unwrap();
+    assert_eq!(record.tag_id, doc_info::FACE_NAME);
+    assert_eq!(parse_face_name(&record.data).unwrap().name, "A");
+
+    assert_eq!(parser.position_of_tag(doc_info::STYLE).unwrap(), None);
+    assert!(parser.is_eof());
+}
+
+#[test]
+fn test_iter_records_from_crate_root() {
+    let record = |tag: u32, data: &[u8]| {
+        let mut bytes = (tag | ((data.len() as u32) << 20)).to_le_bytes().to_vec();
+        bytes.extend_from_slice(data);
+        bytes
+    };
+    let mut data = record(0x0010, &[7; 26]);
+    data.extend(record(0x0013, &[0, 0, 0]));
+    data.extend(record(0x0050, &[])); // not a DocInfo tag
+
+    let mut records = hwp_parser::iter_records(&data, hwp_parser::RecordContext::DocInfo);
+    let first: hwp_parser::Record = records.next().unwrap().unwrap();
+    assert_eq!(first.tag_id, 0x0010);
+    assert_eq!(first.data, vec![7; 26]);
+    assert_eq!(records.next().unwrap().unwrap().tag_id, 0x0013);
+    assert!(records.next().unwrap().is_err());
+    assert!(records.next().is_none());
+}
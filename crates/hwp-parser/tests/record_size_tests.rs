@@ -0,0 +1,289 @@
+//! Checks that each DocInfo record parser reads exactly the bytes of a
+//! well-formed record, so a misread field cannot shift the fields after it
+
+use hwp_core::Result;
+use hwp_parser::parser::doc_info_records::*;
+use hwp_parser::parser::record::RecordDataParser;
+
+/// Little-endian record body builder
+#[derive(Default)]
+struct Body(Vec<u8>);
+
+impl Body {
+    fn u8(mut self, value: u8) -> Self {
+        self.0.push(value);
+        self
+    }
+
+    fn u16(mut self, value: u16) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn u32(mut self, value: u32) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn u64(mut self, value: u64) -> Self {
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn bytes(mut self, value: &[u8]) -> Self {
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    /// A length-prefixed UTF-16LE string
+    fn string(self, value: &str) -> Self {
+        let units: Vec<u16> = value.encode_utf16().collect();
+        let mut body = self.u16(units.len() as u16);
+        for unit in units {
+            body = body.u16(unit);
+        }
+        body
+    }
+}
+
+/// Read `data` with `read` and assert nothing was left unread
+fn read_exactly<T>(name: &str, data: &[u8], read: fn(&mut RecordDataParser) -> Result<T>) -> T {
+    let mut parser = RecordDataParser::new(data);
+    let value = read(&mut parser).unwrap_or_else(|e| panic!("{}: {}", name, e));
+    assert_eq!(
+        parser.remaining(),
+        0,
+        "{} left {} of {} bytes unread",
+        name,
+        parser.remaining(),
+        data.len()
+    );
+    value
+}
+
+#[test]
+fn test_document_properties_size() {
+    let data = Body::default()
+        .u16(1)
+        .u16(1)
+        .u16(1)
+        .u16(1)
+        .u16(1)
+        .u16(1)
+        .u16(1)
+        .u32(1200)
+        .u32(3);
+    let properties = read_exactly("DOCUMENT_PROPERTIES", &data.0, read_document_properties);
+    assert_eq!(properties.total_page_count, 3);
+}
+
+#[test]
+fn test_face_name_size() {
+    let data = Body::default()
+        .u8(0x07)
+        .string("Batang")
+        .bytes(&[2, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        .u8(1)
+        .string("Dotum")
+        .string("Gulim");
+    let face_name = read_exactly("FACE_NAME", &data.0, read_face_name);
+    assert_eq!(face_name.base_font_name.as_deref(), Some("Gulim"));
+}
+
+#[test]
+fn test_char_shape_size() {
+    let data = Body::default()
+        .bytes(&[0; 14]) // face name IDs
+        .bytes(&[100; 7]) // ratios
+        .bytes(&[0; 7]) // character spaces
+        .bytes(&[100; 7]) // relative sizes
+        .bytes(&[0; 7]) // character offsets
+        .u32(1000)
+        .u32(0)
+        .u8(0)
+        .u8(0)
+        .u32(0)
+        .u32(0)
+        .u32(0)
+        .u32(0)
+        .u16(1);
+    let char_shape = read_exactly("CHAR_SHAPE", &data.0, read_char_shape);
+    assert_eq!(char_shape.border_fill_id, Some(1));
+}
+
+#[test]
+fn test_para_shape_size() {
+    let mut data = Body::default().u32(0);
+    for _ in 0..6 {
+        data = data.u32(0);
+    }
+    let data = data
+        .u16(0)
+        .u16(0)
+        .u16(0)
+        .bytes(&[0; 8]) // border offsets
+        .u32(0)
+        .u32(0)
+        .u32(2);
+    let para_shape = read_exactly("PARA_SHAPE", &data.0, read_para_shape);
+    assert_eq!(para_shape.line_spacing_type, 2);
+}
+
+#[test]
+fn test_style_size() {
+    let data = Body::default()
+        .string("바탕글")
+        .string("Normal")
+        .u8(0)
+        .u8(0)
+        .u16(0x0412)
+        .u16(0)
+        .u16(0);
+    let style = read_exactly("STYLE", &data.0, read_style);
+    assert_eq!(style.english_name, "Normal");
+}
+
+#[test]
+fn test_border_fill_size() {
+    let mut data = Body::default().u16(0);
+    for _ in 0..5 {
+        data = data.u8(1).u8(1).u32(0);
+    }
+    let data = data.u8(1).bytes(&[0xAA, 0xBB, 0xCC, 0xDD]);
+    let border_fill = read_exactly("BORDER_FILL", &data.0, read_border_fill);
+    assert_eq!(border_fill.fill_data.len(), 4);
+}
+
+#[test]
+fn test_id_mappings_size() {
+    let data = Body::default().u32(2).u32(5).u32(7);
+    let mappings = read_exactly("ID_MAPPINGS", &data.0, read_id_mappings);
+    assert_eq!(mappings, vec![5, 7]);
+}
+
+#[test]
+fn test_bin_data_size() {
+    let data = Body::default().u16(0x0001).u16(1).bytes(b"PNG");
+    let entry = read_exactly("BIN_DATA", &data.0, read_bin_data);
+    assert_eq!(entry.id, 1);
+    assert_eq!(entry.data, b"PNG");
+}
+
+#[test]
+fn test_doc_data_size() {
+    let data = Body::default().bytes(&[1, 2, 3]);
+    assert_eq!(read_exactly("DOC_DATA", &data.0, read_doc_data), [1, 2, 3]);
+
+    let data = Body::default()
+        .u16(0)
+        .u16(1)
+        .u16(1)
+        .u16(0x0001)
+        .string("Title");
+    let doc_data = read_exactly("DOC_DATA parameter set", &data.0, read_doc_data_set);
+    assert_eq!(doc_data.variables["1"], "Title");
+}
+
+#[test]
+fn test_tab_def_size() {
+    let data = Body::default()
+        .u32(0)
+        .u32(2)
+        .u32(4000)
+        .u8(0)
+        .u8(0)
+        .u16(0)
+        .u32(8000)
+        .u8(1)
+        .u8(0)
+        .u16(0);
+    let tab_def = read_exactly("TAB_DEF", &data.0, read_tab_def);
+    assert_eq!(tab_def.tabs.len(), 2);
+}
+
+#[test]
+fn test_numbering_size() {
+    let data = Body::default()
+        .u32(0)
+        .u16(0)
+        .string("^1.")
+        .u16(1)
+        .u32(0)
+        .u16(0)
+        .string("^2)")
+        .u16(1);
+    let numbering = read_exactly("NUMBERING", &data.0, read_numbering);
+    assert_eq!(numbering.levels.len(), 2);
+}
+
+#[test]
+fn test_bullet_size() {
+    let data = Body::default().u32(0).u16(0).string("•");
+    let bullet = read_exactly("BULLET", &data.0, read_bullet);
+    assert_eq!(bullet.bullet_char.as_deref(), Some("•"));
+
+    let data = Body::default().u32(0x01).u16(0).u16(3);
+    let bullet = read_exactly("BULLET (image)", &data.0, read_bullet);
+    assert_eq!(bullet.image_id, Some(3));
+}
+
+#[test]
+fn test_distribute_doc_data_size() {
+    let data = Body::default().bytes(&[0x5A; 256]);
+    let distribute = read_exactly("DISTRIBUTE_DOC_DATA", &data.0, read_distribute_doc_data);
+    assert_eq!(distribute.data.len(), 256);
+}
+
+#[test]
+fn test_compatible_document_size() {
+    let data = Body::default().u32(1);
+    let compatible = read_exactly("COMPATIBLE_DOCUMENT", &data.0, read_compatible_document);
+    assert_eq!(compatible.target_program, 1);
+}
+
+#[test]
+fn test_layout_compatibility_size() {
+    let data = Body::default().u32(1).u32(2).u32(3).u32(4).u32(5);
+    let layout = read_exactly("LAYOUT_COMPATIBILITY", &data.0, read_layout_compatibility);
+    assert_eq!(layout.snap_to_grid, 5);
+}
+
+#[test]
+fn test_track_change_size() {
+    let data = Body::default()
+        .u32(0)
+        .u16(1)
+        .u64(132_000_000_000_000_000)
+        .u16(2)
+        .bytes(&[9, 9]);
+    let change = read_exactly("TRACK_CHANGE", &data.0, read_track_change);
+    assert_eq!(change.data, [9, 9]);
+}
+
+#[test]
+fn test_track_change_author_size() {
+    let data = Body::default().u16(1).string("Reviewer");
+    let author = read_exactly("TRACK_CHANGE_AUTHOR", &data.0, read_track_change_author);
+    assert_eq!(author.name, "Reviewer");
+}
+
+#[test]
+fn test_memo_shape_size() {
+    let data = Body::default()
+        .u32(0)
+        .u32(1)
+        .u32(15000)
+        .u16(1)
+        .u16(0)
+        .u8(1)
+        .u32(0x00FF_0000);
+    let memo_shape = read_exactly("MEMO_SHAPE", &data.0, read_memo_shape);
+    assert_eq!(memo_shape.line_color, 0x00FF_0000);
+}
+
+#[test]
+fn test_forbidden_char_size() {
+    let data = Body::default().string(")]}").string("([{");
+    let forbidden = read_exactly("FORBIDDEN_CHAR", &data.0, read_forbidden_char);
+    assert_eq!(forbidden.allowed_chars, "([{");
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use hwp_core::constants::tag_id::section::{PARA_HEADER, PARA_TEXT};
+use hwp_core::models::document::DocumentSummary;
+use hwp_core::models::section::{Equation, Hyperlink, Picture};
+use hwp_parser::parse;
+use hwp_parser::parser::section::parse_section;
+use hwp_parser::redact::{redact_document, redact_section_stream, REDACTION_CHAR};
+use regex::Regex;
+
+fn utf16(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+}
+
+fn ssn() -> Vec<Regex> {
+    vec![Regex::new(r"\d{6}-\d{7}").unwrap()]
+}
+
+#[test]
+fn test_redact_document_text() {
+    let data = common::build_hwp(
+        &[],
+        &[common::paragraph_records(&[
+            "Name: Hong, ID 900101-1234567",
+            "No secrets here",
+        ])],
+    );
+    let mut document = parse(&data).unwrap();
+
+    assert_eq!(redact_document(&mut document, &ssn()), 1);
+    assert_eq!(
+        document.sections[0].paragraphs[0].text,
+        format!("Name: Hong, ID {}", REDACTION_CHAR.to_string().repeat(14))
+    );
+    assert_eq!(document.sections[0].paragraphs[1].text, "No secrets here");
+}
+
+#[test]
+fn test_redact_document_beyond_paragraphs() {
+    let mut document = common::empty_document();
+    document.summary = Some(DocumentSummary {
+        title: Some("Record 900101-1234567".to_string()),
+        keywords: Some("ssn".to_string()),
+        ..DocumentSummary::default()
+    });
+
+    let mut script = 0u32.to_le_bytes().to_vec();
+    script.extend(14u16.to_le_bytes());
+    script.extend(utf16("900101-1234567"));
+    script.extend(utf16("font"));
+    let mut section = hwp_core::models::Section::default();
+    section.hyperlinks.push(Hyperlink {
+        text: "ID 900101-1234567 here".to_string(),
+        url: "https://example.com/?id=900101-1234567".to_string(),
+        para_index: 0,
+        char_offset: 3,
+    });
+    section.equations.push(Equation {
+        paragraph_index: 0,
+        raw_data: script,
+        text_representation: "900101-1234567".to_string(),
+    });
+    section.pictures.push(Picture {
+        caption: Some("Scan of 900101-1234567".to_string()),
+        ..Picture::default()
+    });
+    document.sections.push(section);
+
+    assert_eq!(redact_document(&mut document, &ssn()), 5);
+
+    let blacked_out = REDACTION_CHAR.to_string().repeat(14);
+    let summary = document.summary.as_ref().unwrap();
+    assert_eq!(
+        summary.title.as_deref(),
+        Some(&*format!("Record {}", blacked_out))
+    );
+    assert_eq!(summary.keywords.as_deref(), Some("ssn"));
+
+    // Replacements keep the character count, so offsets stay valid
+    let section = &document.sections[0];
+    let link = &section.hyperlinks[0];
+    assert_eq!(link.text, format!("ID {} here", blacked_out));
+    assert_eq!(link.text.chars().count(), "ID 900101-1234567 here".len());
+    assert_eq!(link.url, format!("https://example.com/?id={}", blacked_out));
+    let equation = &section.equations[0];
+    assert_eq!(equation.text_representation, blacked_out);
+    assert_eq!(equation.raw_data[6..34], utf16(&blacked_out)[..]);
+    assert_eq!(equation.raw_data[34..], utf16("font")[..]);
+    assert_eq!(
+        section.pictures[0].caption.as_deref(),
+        Some(&*format!("Scan of {}", blacked_out))
+    );
+}
+
+#[test]
+fn test_redact_section_stream_keeps_layout() {
+    // A tab control sits between the two numbers
+    let mut text = utf16("ID 900101-1234567");
+    text.extend(utf16("\t"));
+    text.extend([0u8; 14]);
+    text.extend(utf16("800202-2345678 end\r"));
+
+    let mut data = common::record(PARA_HEADER, 0, &[0; 22]);
+    data.extend(common::record(PARA_TEXT, 1, &text));
+
+    let (redacted, count) = redact_section_stream(&data, &ssn()).unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(redacted.len(), data.len());
+
+    let section = parse_section(&redacted, 0).unwrap();
+    let blacked_out = REDACTION_CHAR.to_string().repeat(14);
+    assert_eq!(
+        section.paragraphs[0].text,
+        format!("ID {}\t{} end", blacked_out, blacked_out)
+    );
+}
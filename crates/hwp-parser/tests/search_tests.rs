@@ -0,0 +1,155 @@
+mod common;
+
+use hwp_core::{HwpDocument, Paragraph, Section};
+use hwp_parser::parse;
+use hwp_parser::search::{build_pattern, search_document, MatchSpan, SearchOptions};
+use std::fs;
+use std::path::Path;
+
+fn create_search_document() -> HwpDocument {
+    let mut document = common::empty_document();
+    for texts in [
+        vec!["Introduction", "first line\nsecond match\nthird line"],
+        vec!["Another Match here"],
+    ] {
+        let mut section = Section::new();
+        for text in texts {
+            let mut paragraph = Paragraph::new();
+            paragraph.text = text.to_string();
+            section.paragraphs.push(paragraph);
+        }
+        document.sections.push(section);
+    }
+    document
+}
+
+/// Test matching positions and context lines
+#[test]
+fn test_search_document_positions_and_context() {
+    let doc = create_search_document();
+    let options = SearchOptions {
+        case_insensitive: true,
+        before_context: 1,
+        after_context: 1,
+        ..Default::default()
+    };
+    let pattern = build_pattern("match", &options).unwrap();
+
+    let matches = search_document(&doc, &pattern, &options);
+    assert_eq!(matches.len(), 2);
+
+    assert_eq!(
+        (matches[0].section, matches[0].paragraph, matches[0].line),
+        (0, 1, 1)
+    );
+    assert_eq!(matches[0].text, "second match");
+    assert_eq!(matches[0].context_before, vec!["first line"]);
+    assert_eq!(matches[0].context_after, vec!["third line"]);
+
+    assert_eq!(
+        (matches[1].section, matches[1].paragraph, matches[1].line),
+        (1, 0, 0)
+    );
+    assert!(matches[1].context_before.is_empty());
+    assert!(matches[1].context_after.is_empty());
+}
+
+/// Test case sensitivity, inversion and result limits
+#[test]
+fn test_search_document_options() {
+    let doc = create_search_document();
+
+    let options = SearchOptions::default();
+    let pattern = build_pattern("Match", &options).unwrap();
+    assert_eq!(search_document(&doc, &pattern, &options).len(), 1);
+
+    let options = SearchOptions {
+        invert_match: true,
+        ..Default::default()
+    };
+    assert_eq!(search_document(&doc, &pattern, &options).len(), 4);
+
+    let options = SearchOptions {
+        case_insensitive: true,
+        max_results: Some(1),
+        ..Default::default()
+    };
+    let pattern = build_pattern("match", &options).unwrap();
+    assert_eq!(search_document(&doc, &pattern, &options).len(), 1);
+}
+
+/// Test that match offsets count characters from the paragraph start
+#[test]
+fn test_search_document_offsets() {
+    let mut doc = create_search_document();
+    let mut paragraph = Paragraph::new();
+    paragraph.text = "첫 줄\r\n한글 검색어 위치".to_string();
+    doc.sections[1].paragraphs.push(paragraph);
+
+    let options = SearchOptions::default();
+    let pattern = build_pattern("검색어", &options).unwrap();
+    let matches = search_document(&doc, &pattern, &options);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(
+        matches[0].span,
+        Some(MatchSpan {
+            byte_start: 16,
+            byte_end: 25,
+            char_start: 8,
+            char_end: 11,
+        })
+    );
+
+    let pattern = build_pattern("match", &options).unwrap();
+    let span = search_document(&doc, &pattern, &options)[0].span.unwrap();
+    assert_eq!((span.char_start, span.char_end), (18, 23));
+
+    // Inverted matches have nothing to point at
+    let options = SearchOptions {
+        invert_match: true,
+        ..Default::default()
+    };
+    assert!(search_document(&doc, &pattern, &options)
+        .iter()
+        .all(|m| m.span.is_none()));
+}
+
+/// Test that plain queries are escaped and invalid regexes are rejected
+#[test]
+fn test_build_pattern() {
+    let pattern = build_pattern("a.b", &SearchOptions::default()).unwrap();
+    assert!(pattern.is_match("a.b"));
+    assert!(!pattern.is_match("axb"));
+
+    let options = SearchOptions {
+        regex: true,
+        ..Default::default()
+    };
+    assert!(build_pattern("(unclosed", &options).is_err());
+}
+
+/// Test searching a parsed fixture
+#[test]
+fn test_search_parsed_fixture() {
+    let file_path = Path::new("tests/fixtures/basic/simple_text.hwp");
+
+    if !file_path.exists() {
+        eprintln!("Skipping test: simple_text.hwp not found");
+        return;
+    }
+
+    let data = fs::read(file_path).expect("Failed to read simple_text.hwp");
+    let doc = parse(&data).expect("Failed to parse simple_text.hwp");
+
+    let options = SearchOptions::default();
+    let pattern = build_pattern(".", &options).unwrap();
+    let matches = search_document(&doc, &pattern, &options);
+
+    let expected: usize = doc
+        .sections
+        .iter()
+        .flat_map(|s| &s.paragraphs)
+        .map(|p| p.text.lines().filter(|l| l.contains('.')).count())
+        .sum();
+    assert_eq!(matches.len(), expected);
+}
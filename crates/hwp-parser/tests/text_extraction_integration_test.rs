@@ -1,3 +1,5 @@
+mod common;
+
 use hwp_core::models::{Paragraph, Section};
 use hwp_core::HwpDocument;
 use hwp_parser::TextExtractor;
@@ -234,3 +236,53 @@ fn test_skip_empty_paragraphs() {
     let text = TextExtractor::extract_from_document(&document).unwrap();
     assert_eq!(text, "Text 1\nText 2");
 }
+
+#[test]
+fn test_streaming_paragraphs_with_section_markers() {
+    use hwp_core::TextItem;
+
+    let mut document = common::empty_document();
+    for texts in [&["One", "Two"][..], &["Three"][..]] {
+        let mut section = Section::new();
+        for text in texts {
+            let mut paragraph = Paragraph::new();
+            paragraph.text = text.to_string();
+            section.paragraphs.push(paragraph);
+        }
+        document.sections.push(section);
+    }
+
+    let items: Vec<TextItem> = TextExtractor::paragraphs(&document).collect();
+    assert_eq!(
+        items,
+        vec![
+            TextItem::Paragraph {
+                section: 0,
+                index: 0,
+                text: "One"
+            },
+            TextItem::Paragraph {
+                section: 0,
+                index: 1,
+                text: "Two"
+            },
+            TextItem::SectionEnd { section: 0 },
+            TextItem::Paragraph {
+                section: 1,
+                index: 0,
+                text: "Three"
+            },
+            TextItem::SectionEnd { section: 1 },
+        ]
+    );
+
+    // Text is borrowed straight from the document
+    if let TextItem::Paragraph { text, .. } = items[0] {
+        assert!(std::ptr::eq(
+            text,
+            document.sections[0].paragraphs[0].text.as_str()
+        ));
+    }
+
+    assert_eq!(document.get_text(), "One\nTwo\n\nThree\n\n");
+}
@@ -1,3 +1,5 @@
+mod common;
+
 use hwp_core::HwpDocument;
 use hwp_parser::parse;
 
@@ -74,3 +76,209 @@ fn test_section_text_extraction() {
     // The text should have paragraphs separated by newlines
     assert_eq!(text.trim(), "Hello, World!\nThis is a test.");
 }
+
+fn cell(row: u16, col: u16, text: &str) -> hwp_core::models::section::TableCell {
+    let mut cell = hwp_core::models::section::TableCell::new(row, col);
+    let mut paragraph = hwp_core::Paragraph::new();
+    paragraph.text = text.to_string();
+    cell.paragraphs.push(paragraph);
+    cell
+}
+
+fn create_section_with_table() -> hwp_core::Section {
+    let mut section = hwp_core::Section::new();
+    for text in ["Report", "", "End"] {
+        let mut paragraph = hwp_core::Paragraph::new();
+        paragraph.text = text.to_string();
+        section.paragraphs.push(paragraph);
+    }
+    section.tables.push(hwp_core::models::section::Table {
+        paragraph_index: 1,
+        rows: 2,
+        cols: 2,
+        cells: vec![
+            cell(0, 0, "Name"),
+            cell(0, 1, "Score"),
+            cell(1, 0, "김철수"),
+            cell(1, 1, "9"),
+        ],
+    });
+    section
+}
+
+#[test]
+fn test_section_text_with_ascii_table() {
+    let section = create_section_with_table();
+
+    let expected = "\
+Report
++--------+-------+
+| Name   | Score |
++--------+-------+
+| 김철수 | 9     |
++--------+-------+
+End
+";
+    assert_eq!(section.text_with_tables(), expected);
+}
+
+#[test]
+fn test_plain_text_formatter_renders_tables() {
+    use hwp_parser::{FormatOptions, OutputFormat};
+
+    let mut document = common::empty_document();
+    document.sections.push(create_section_with_table());
+
+    let formatter = OutputFormat::PlainText.create_formatter(FormatOptions::default());
+    let output = formatter.format_document(&document).unwrap();
+    assert!(output.contains("| Name   | Score |"));
+
+    // Wrapping applies to paragraphs only, never to the grid
+    let options = FormatOptions {
+        text_width: Some(5),
+        ..FormatOptions::default()
+    };
+    let formatter = OutputFormat::PlainText.create_formatter(options);
+    let output = formatter.format_document(&document).unwrap();
+    assert!(output.contains("| 김철수 | 9     |"));
+}
+
+#[test]
+fn test_table_tsv_with_merged_and_empty_cells() {
+    let mut merged = cell(0, 0, "Title\tline");
+    merged.col_span = 2;
+    let table = hwp_core::models::section::Table {
+        paragraph_index: 0,
+        rows: 2,
+        cols: 2,
+        cells: vec![
+            merged,
+            cell(1, 0, "a\nb"),
+            hwp_core::models::section::TableCell::new(1, 1),
+        ],
+    };
+
+    assert_eq!(table.to_tsv(), "Title line\t\na b\t\n");
+}
+
+#[test]
+fn test_table_grid_sized_by_cells() {
+    // Header counts far larger than the cells are not allocated
+    let table = hwp_core::models::section::Table {
+        paragraph_index: 0,
+        rows: u16::MAX,
+        cols: u16::MAX,
+        cells: vec![cell(0, 0, "a"), cell(1, 1, "b")],
+    };
+    assert_eq!(table.text_grid(), vec![vec!["a", ""], vec!["", "b"]]);
+
+    let empty = hwp_core::models::section::Table {
+        paragraph_index: 0,
+        rows: u16::MAX,
+        cols: u16::MAX,
+        cells: Vec::new(),
+    };
+    assert!(empty.text_grid().is_empty());
+    assert_eq!(empty.render_ascii(), "");
+
+    // Cells outside the header's counts are still dropped
+    let clipped = hwp_core::models::section::Table {
+        paragraph_index: 0,
+        rows: 1,
+        cols: 1,
+        cells: vec![cell(0, 0, "a"), cell(3, 0, "b")],
+    };
+    assert_eq!(clipped.text_grid(), vec![vec!["a"]]);
+}
+
+#[test]
+fn test_unicode_superscript_and_subscript() {
+    use hwp_core::models::paragraph::CharShapePos;
+    use hwp_parser::{FormatOptions, OutputFormat, TextExtractor};
+
+    let mut document = common::empty_document();
+    // 0: normal, 1: superscript, 2: subscript
+    document.doc_info.char_shapes = vec![
+        common::char_shape(0, 0),
+        common::char_shape(1 << 15, 0),
+        common::char_shape(1 << 16, 0),
+    ];
+
+    let runs = |spans: &[(u32, u16)]| -> Vec<CharShapePos> {
+        spans
+            .iter()
+            .map(|&(position, shape_id)| CharShapePos { position, shape_id })
+            .collect()
+    };
+
+    let mut squared = hwp_core::Paragraph::new();
+    squared.text = "x2 + H2O".to_string();
+    squared.char_shapes = runs(&[(0, 0), (1, 1), (2, 0), (6, 2), (7, 0)]);
+    assert_eq!(
+        TextExtractor::text_with_scripts(&squared, &document.doc_info),
+        "x² + H₂O"
+    );
+
+    // Letters without a Unicode form fall back to markers
+    let mut fallback = hwp_core::Paragraph::new();
+    fallback.text = "eKt".to_string();
+    fallback.char_shapes = runs(&[(0, 0), (1, 1), (2, 2)]);
+    assert_eq!(
+        TextExtractor::text_with_scripts(&fallback, &document.doc_info),
+        "e^{K}_{t}"
+    );
+
+    let mut section = hwp_core::Section::new();
+    section.paragraphs.push(squared);
+    document.sections.push(section);
+
+    let options = FormatOptions {
+        text_unicode_scripts: true,
+        ..FormatOptions::default()
+    };
+    let output = OutputFormat::PlainText
+        .create_formatter(options)
+        .format_document(&document)
+        .unwrap();
+    assert_eq!(output, "x² + H₂O");
+
+    // Off by default
+    let output = OutputFormat::PlainText
+        .create_formatter(FormatOptions::default())
+        .format_document(&document)
+        .unwrap();
+    assert_eq!(output, "x2 + H2O");
+}
+
+#[test]
+fn test_formatted_paragraph_runs() {
+    use hwp_core::models::paragraph::CharShapePos;
+    use hwp_parser::{TextExtractor, TextRun};
+
+    let mut document = common::empty_document();
+
+    let mut paragraph = hwp_core::Paragraph::new();
+    paragraph.text = "plain bold plain".to_string();
+    paragraph.char_shapes = [(0, 0), (6, 3), (10, 0), (10, 0)]
+        .iter()
+        .map(|&(position, shape_id)| CharShapePos { position, shape_id })
+        .collect();
+    let mut unshaped = hwp_core::Paragraph::new();
+    unshaped.text = "no runs".to_string();
+
+    let mut section = hwp_core::Section::new();
+    section.paragraphs.push(paragraph);
+    section.paragraphs.push(unshaped);
+    document.sections.push(section);
+
+    let formatted = TextExtractor::extract_with_formatting(&document).unwrap();
+    let run = |text: &str, char_shape_id| TextRun {
+        text: text.to_string(),
+        char_shape_id,
+    };
+    assert_eq!(
+        formatted.paragraphs[0].runs,
+        vec![run("plain ", 0), run("bold", 3), run(" plain", 0)]
+    );
+    assert_eq!(formatted.paragraphs[1].runs, vec![run("no runs", 0)]);
+}
@@ -1,3 +1,5 @@
+use hwp_parser::search::{build_pattern, search_document, Match, SearchOptions};
+use js_sys::{Array, Object, Promise, Reflect};
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global allocator.
@@ -22,6 +24,9 @@ impl HwpParser {
     }
 
     /// Parse HWP file from bytes
+    ///
+    /// On failure throws an object `{ code, message }`, where `code` is one
+    /// of the stable codes of `HwpError::code`.
     #[wasm_bindgen]
     pub fn parse(&mut self, data: &[u8]) -> Result<(), JsValue> {
         match hwp_parser::parse(data) {
@@ -29,10 +34,41 @@ impl HwpParser {
                 self.document = Some(doc);
                 Ok(())
             }
-            Err(e) => Err(JsValue::from_str(&format!("Parse error: {}", e))),
+            Err(e) => Err(parse_error(&e)),
         }
     }
 
+    /// Parse HWP file bytes without blocking the caller
+    ///
+    /// Returns a `Promise` resolving to a new `HwpParser` holding the
+    /// document, or rejecting with a `{ code, message }` object as `parse`
+    /// throws. Parsing starts on a later turn of the event loop, so the
+    /// calling code runs to completion first; the parse itself still runs
+    /// on the main thread.
+    #[wasm_bindgen]
+    pub fn parse_async_js(data: Vec<u8>) -> Promise {
+        #[cfg(feature = "console_error_panic_hook")]
+        console_error_panic_hook::set_once();
+
+        let mut data = Some(data);
+        Promise::new(&mut |resolve, reject| {
+            let data = data.take().unwrap_or_default();
+            wasm_bindgen_futures::spawn_local(async move {
+                let settled = match hwp_parser::parse(&data) {
+                    Ok(doc) => {
+                        let parser = HwpParser {
+                            document: Some(doc),
+                        };
+                        resolve.call1(&JsValue::NULL, &parser.into())
+                    }
+                    Err(e) => reject.call1(&JsValue::NULL, &parse_error(&e)),
+                };
+                // Settling a promise only fails if its callbacks throw
+                let _ = settled;
+            });
+        })
+    }
+
     /// Get document as JSON
     #[wasm_bindgen]
     pub fn to_json(&self) -> Result<String, JsValue> {
@@ -51,4 +87,200 @@ impl HwpParser {
             None => Err(JsValue::from_str("No document parsed yet")),
         }
     }
+
+    /// Get the number of sections, or 0 if no document has been parsed
+    #[wasm_bindgen]
+    pub fn get_section_count(&self) -> usize {
+        self.document.as_ref().map_or(0, |doc| doc.sections.len())
+    }
+
+    /// Get the text of a single section, one line per paragraph
+    #[wasm_bindgen]
+    pub fn get_section_text(&self, section: usize) -> Result<String, JsValue> {
+        Ok(self.section(section)?.get_text())
+    }
+
+    /// Get the text of one paragraph, numbered within its section
+    #[wasm_bindgen]
+    pub fn get_paragraph_text(&self, section: usize, paragraph: usize) -> Result<String, JsValue> {
+        Ok(self.paragraph(section, paragraph)?.text.clone())
+    }
+
+    /// Get the text of paragraphs `start..end`, numbered across all sections
+    ///
+    /// Bounds past the end of the document are clamped, so an oversized
+    /// window simply returns the remaining paragraphs.
+    #[wasm_bindgen]
+    pub fn get_paragraphs(&self, start: usize, end: usize) -> Result<String, JsValue> {
+        match &self.document {
+            Some(doc) => {
+                if start >= end {
+                    return Ok(String::new());
+                }
+                Ok(doc.extract_range(hwp_core::ParagraphRange::new(start, Some(end - 1))))
+            }
+            None => Err(JsValue::from_str("No document parsed yet")),
+        }
+    }
+
+    /// Search paragraph text, returning an array of `{section, paragraph, text}` matches
+    #[wasm_bindgen]
+    pub fn search(&self, query: &str, case_insensitive: bool) -> Result<JsValue, JsValue> {
+        let results = Array::new();
+        for m in self.search_matches(query, case_insensitive)? {
+            let item = Object::new();
+            Reflect::set(&item, &"section".into(), &(m.section as u32).into())?;
+            Reflect::set(&item, &"paragraph".into(), &(m.paragraph as u32).into())?;
+            Reflect::set(&item, &"text".into(), &m.text.into())?;
+            results.push(&item);
+        }
+
+        Ok(results.into())
+    }
+}
+
+impl HwpParser {
+    /// Search paragraph text, keeping the matches as Rust values
+    fn search_matches(&self, query: &str, case_insensitive: bool) -> Result<Vec<Match>, String> {
+        let doc = match &self.document {
+            Some(doc) => doc,
+            None => return Err("No document parsed yet".to_string()),
+        };
+
+        let options = SearchOptions {
+            case_insensitive,
+            ..Default::default()
+        };
+        let pattern = build_pattern(query, &options).map_err(|e| format!("Search error: {}", e))?;
+
+        Ok(search_document(doc, &pattern, &options))
+    }
+
+    /// Look up a section of the parsed document
+    fn section(&self, index: usize) -> Result<&hwp_core::Section, String> {
+        let doc = match &self.document {
+            Some(doc) => doc,
+            None => return Err("No document parsed yet".to_string()),
+        };
+
+        doc.sections.get(index).ok_or_else(|| {
+            format!(
+                "Section index {} out of range (document has {} sections)",
+                index,
+                doc.sections.len()
+            )
+        })
+    }
+
+    /// Look up a paragraph, numbered within its section
+    fn paragraph(&self, section: usize, paragraph: usize) -> Result<&hwp_core::Paragraph, String> {
+        let sec = self.section(section)?;
+        sec.paragraphs.get(paragraph).ok_or_else(|| {
+            format!(
+                "Paragraph index {} out of range (section {} has {} paragraphs)",
+                paragraph,
+                section,
+                sec.paragraphs.len()
+            )
+        })
+    }
+}
+
+/// Build the `{ code, message }` object thrown for a failed parse
+fn parse_error(error: &hwp_core::HwpError) -> JsValue {
+    let (code, message) = parse_failure(error);
+    let object = Object::new();
+    let _ = Reflect::set(&object, &"code".into(), &code.into());
+    let _ = Reflect::set(&object, &"message".into(), &message.into());
+    object.into()
+}
+
+/// The code and message reported for a failed parse
+fn parse_failure(error: &hwp_core::HwpError) -> (&'static str, String) {
+    (error.code(), format!("Parse error: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_core::models::header::HwpHeader;
+    use hwp_core::{HwpDocument, HwpVersion, Paragraph, Section};
+    use wasm_bindgen_test::*;
+
+    fn parser_with_paragraphs(texts: &[&str]) -> HwpParser {
+        let mut document = HwpDocument::new(HwpHeader::new(HwpVersion::new(5, 0, 0, 0)));
+        let mut section = Section::new();
+        for text in texts {
+            let mut paragraph = Paragraph::new();
+            paragraph.text = text.to_string();
+            section.paragraphs.push(paragraph);
+        }
+        document.sections.push(section);
+
+        HwpParser {
+            document: Some(document),
+        }
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_get_paragraphs_window() {
+        let parser = parser_with_paragraphs(&["First", "Second", "Third"]);
+
+        assert_eq!(parser.get_paragraphs(0, 2).unwrap(), "First\nSecond\n");
+        assert_eq!(parser.get_paragraphs(2, 100).unwrap(), "Third\n");
+        assert_eq!(parser.get_paragraphs(5, 10).unwrap(), "");
+        assert_eq!(parser.get_paragraphs(2, 1).unwrap(), "");
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_search_match_count() {
+        let parser = parser_with_paragraphs(&["Hello world", "hello again", "Goodbye"]);
+
+        let matches = parser.search_matches("hello", true).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].paragraph, 1);
+
+        assert_eq!(parser.search_matches("hello", false).unwrap().len(), 1);
+        assert!(HwpParser::new().search_matches("hello", true).is_err());
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_section_and_paragraph_access() {
+        let parser = parser_with_paragraphs(&["First", "Second"]);
+
+        assert_eq!(parser.get_section_count(), 1);
+        assert_eq!(parser.get_section_text(0).unwrap(), "First\nSecond\n");
+        assert_eq!(parser.get_paragraph_text(0, 1).unwrap(), "Second");
+        assert_eq!(HwpParser::new().get_section_count(), 0);
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_section_access_errors() {
+        let parser = parser_with_paragraphs(&["Only"]);
+
+        assert!(parser.section(1).is_err());
+        assert!(parser.paragraph(0, 1).is_err());
+        assert!(parser.paragraph(1, 0).is_err());
+        assert!(HwpParser::new().section(0).is_err());
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_parse_rejects_invalid_data() {
+        let error = hwp_parser::parse(&[0x20; 512]).unwrap_err();
+        let (code, message) = parse_failure(&error);
+        assert_eq!(code, "INVALID_SIGNATURE");
+        assert!(message.starts_with("Parse error"));
+    }
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test_inflate_with_rust_backend() {
+        // Raw deflate of "한글 HWP 한글 HWP", as stored in HWP streams
+        let compressed = [
+            0x7B, 0x3B, 0x75, 0xCE, 0xAB, 0x1D, 0x0D, 0x0A, 0x1E, 0xE1, 0x01, 0x0A, 0x6F, 0xE1,
+            0x4C, 0x00,
+        ];
+
+        let data = hwp_parser::compression::decompress_raw(&compressed).unwrap();
+        assert_eq!(String::from_utf8(data).unwrap(), "한글 HWP 한글 HWP");
+    }
 }